@@ -0,0 +1,273 @@
+//! Procedural macro front-end for [`const-decoder2`].
+//!
+//! The [`decode!`] macro exported by this crate performs decoding during macro expansion.
+//! Compared to the declarative `const_decoder::decode!` macro, this has the following benefits:
+//!
+//! - Compile errors point at the offending char in the input literal, rather than
+//!   at a generic constant evaluation failure. (Sub-spans of literals are only available
+//!   on some compiler versions; otherwise, errors point at the entire literal.)
+//! - Input can be specified as a string literal as well as a byte string literal.
+//!
+//! On the flip side, the input must be a literal, and the decoder must be one
+//! of the supported [`Decoder`](const_decoder::Decoder)s (see the [`decode!`] docs).
+//! The declarative macro remains the zero-dependency option supporting arbitrary const expressions.
+//!
+//! [`const-decoder2`]: https://docs.rs/const-decoder2/
+//!
+//! # Examples
+//!
+//! ```
+//! use const_decoder_macros::decode;
+//!
+//! const KEY: [u8; 4] = decode!(Hex, "c0ffee00");
+//! const TEXT: &[u8] = &decode!(Base64.skip_whitespace(), b"VGVz dCBz dHJp bmc=");
+//! assert_eq!(TEXT, b"Test string");
+//! ```
+
+// Documentation settings.
+#![doc(html_root_url = "https://docs.rs/const-decoder-macros/0.5.1")]
+// Linter settings.
+#![warn(missing_debug_implementations, missing_docs, bare_trait_objects)]
+#![warn(clippy::all, clippy::pedantic)]
+#![allow(clippy::must_use_candidate)]
+
+use const_decoder::{DecodeError, Decoder};
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, Expr, Lit, Token,
+};
+
+/// Decoder specification parsed from the first macro arg.
+#[derive(Debug, Clone, Copy)]
+enum DecoderSpec {
+    Plain(Decoder),
+    SkipWhitespace(Decoder),
+}
+
+impl DecoderSpec {
+    const SUPPORTED: &'static str = "`Hex`, `Base64`, `Base64Url` or `Base64Any`";
+
+    fn parse(expr: &Expr) -> syn::Result<Self> {
+        match expr {
+            Expr::Path(path) if path.qself.is_none() && path.attrs.is_empty() => {
+                let decoder = match path.path.segments.last() {
+                    Some(segment) if segment.arguments.is_none() => {
+                        Self::decoder_by_name(&segment.ident.to_string())
+                    }
+                    _ => None,
+                };
+                decoder.map(Self::Plain).ok_or_else(|| {
+                    let message =
+                        format!("unsupported decoder; expected one of {}", Self::SUPPORTED);
+                    syn::Error::new_spanned(expr, message)
+                })
+            }
+            Expr::MethodCall(call)
+                if call.method == "skip_whitespace"
+                    && call.args.is_empty()
+                    && call.turbofish.is_none() =>
+            {
+                match Self::parse(&call.receiver)? {
+                    Self::Plain(decoder) => Ok(Self::SkipWhitespace(decoder)),
+                    Self::SkipWhitespace(_) => Err(syn::Error::new_spanned(
+                        &call.method,
+                        "`skip_whitespace()` is specified multiple times",
+                    )),
+                }
+            }
+            _ => {
+                let message = format!(
+                    "expected a decoder ({}), optionally followed by `.skip_whitespace()`",
+                    Self::SUPPORTED
+                );
+                Err(syn::Error::new_spanned(expr, message))
+            }
+        }
+    }
+
+    fn decoder_by_name(name: &str) -> Option<Decoder> {
+        Some(match name {
+            "Hex" => Decoder::Hex,
+            "Base64" => Decoder::Base64,
+            "Base64Url" => Decoder::Base64Url,
+            "Base64Any" => Decoder::Base64Any,
+            _ => return None,
+        })
+    }
+
+    fn decode(self, input: &[u8]) -> Result<Vec<u8>, DecodeError> {
+        match self {
+            Self::Plain(decoder) => decoder.try_decode_to_vec(input),
+            Self::SkipWhitespace(decoder) => decoder.skip_whitespace().try_decode_to_vec(input),
+        }
+    }
+}
+
+/// Input literal together with its source representation.
+#[derive(Debug)]
+struct EncodedLiteral {
+    value: Vec<u8>,
+    token: proc_macro2::Literal,
+    /// Length of the literal prefix before the first char of the value (`"` or `b"`).
+    prefix_len: usize,
+}
+
+impl EncodedLiteral {
+    fn parse(lit: Lit) -> syn::Result<Self> {
+        match lit {
+            Lit::Str(lit) => Ok(Self {
+                value: lit.value().into_bytes(),
+                token: lit.token(),
+                prefix_len: 1,
+            }),
+            Lit::ByteStr(lit) => Ok(Self {
+                value: lit.value(),
+                token: lit.token(),
+                prefix_len: 2,
+            }),
+            _ => Err(syn::Error::new_spanned(
+                lit,
+                "expected a string or byte string literal",
+            )),
+        }
+    }
+
+    /// Returns the span of the char at the specified position in the decoded value,
+    /// falling back to the span of the entire literal if the sub-span cannot be computed.
+    fn symbol_span(&self, position: usize) -> Span {
+        let repr = self.token.to_string();
+        let value_range = self.prefix_len..self.prefix_len + self.value.len();
+        // Only verbatim literals (i.e., without escape sequences or raw string markers)
+        // allow mapping positions in the value to positions in the source.
+        let is_verbatim = repr.len() == value_range.end + 1
+            && repr.as_bytes().get(value_range) == Some(self.value.as_slice());
+        if !is_verbatim {
+            return self.token.span();
+        }
+
+        let start = self.prefix_len + position;
+        let end = (start + 1).min(repr.len());
+        self.token
+            .subspan(start..end)
+            .unwrap_or_else(|| self.token.span())
+    }
+}
+
+/// Parsed macro input.
+#[derive(Debug)]
+struct DecodeInput {
+    decoder: DecoderSpec,
+    literal: EncodedLiteral,
+}
+
+impl Parse for DecodeInput {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let decoder = DecoderSpec::parse(&input.parse()?)?;
+        input.parse::<Token![,]>()?;
+        let literal = EncodedLiteral::parse(input.parse()?)?;
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+        Ok(Self { decoder, literal })
+    }
+}
+
+impl DecodeInput {
+    fn expand(&self) -> syn::Result<TokenStream2> {
+        let bytes = self.decoder.decode(&self.literal.value).map_err(|err| {
+            let span = self.literal.symbol_span(err.position());
+            syn::Error::new(span, err)
+        })?;
+        Ok(quote!([#(#bytes),*]))
+    }
+}
+
+/// Decodes the input literal during macro expansion.
+///
+/// The macro accepts two comma-separated args:
+///
+/// 1. Decoder: one of `Hex`, `Base64`, `Base64Url` or `Base64Any`, optionally qualified
+///    (e.g., `Decoder::Hex`) and optionally followed by `.skip_whitespace()`.
+///    Semantics are the same as for the corresponding `const_decoder::Decoder` variants and
+///    `Decoder::skip_whitespace()`.
+/// 2. Input: a string or byte string literal.
+///
+/// The output of a macro is an array `[u8; N]` with the decoded bytes.
+///
+/// # Errors
+///
+/// If the input cannot be decoded, the macro raises a compile error pointing at the offending char
+/// (if the compiler version supports sub-spans of literals and the literal does not contain escape
+/// sequences). For example, the following code
+///
+/// ```compile_fail
+/// # use const_decoder_macros::decode;
+/// const KEY: [u8; 3] = decode!(Hex, "c0ffeX");
+/// ```
+///
+/// raises the "invalid symbol 'X' at position 5" error.
+///
+/// # Examples
+///
+/// ```
+/// use const_decoder_macros::decode;
+///
+/// const HEX: &[u8] = &decode!(Hex, "c0ffee");
+/// assert_eq!(HEX, [0xc0, 0xff, 0xee]);
+/// const BASE64: [u8; 11] = decode!(const_decoder::Decoder::Base64, b"VGVzdCBzdHJpbmc=");
+/// assert_eq!(BASE64, *b"Test string");
+/// ```
+#[proc_macro]
+pub fn decode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DecodeInput);
+    input
+        .expand()
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand(tokens: TokenStream2) -> syn::Result<TokenStream2> {
+        syn::parse2::<DecodeInput>(tokens)?.expand()
+    }
+
+    #[test]
+    fn expanding_macro() {
+        let output = expand(quote!(Hex, "c0ffee")).unwrap();
+        assert_eq!(
+            output.to_string(),
+            quote!([192u8, 255u8, 238u8]).to_string()
+        );
+        let output = expand(quote!(Decoder::Base64.skip_whitespace(), b"dGVz dA==")).unwrap();
+        assert_eq!(
+            output.to_string(),
+            quote!([116u8, 101u8, 115u8, 116u8]).to_string()
+        );
+    }
+
+    #[test]
+    fn invalid_macro_input() {
+        let err = expand(quote!(Base32, "c0ffee")).unwrap_err();
+        assert!(err.to_string().starts_with("unsupported decoder"), "{err}");
+        let err = expand(quote!(Hex.skip_whitespace().skip_whitespace(), "c0")).unwrap_err();
+        assert!(err.to_string().contains("multiple times"), "{err}");
+        let err = expand(quote!(Hex, 0xc0ffee)).unwrap_err();
+        assert_eq!(err.to_string(), "expected a string or byte string literal");
+    }
+
+    #[test]
+    fn decoding_errors() {
+        let err = expand(quote!(Hex, "c0ffeX")).unwrap_err();
+        assert_eq!(err.to_string(), "invalid symbol 'X' at position 5");
+        let err = expand(quote!(Hex, "c0ffe")).unwrap_err();
+        assert_eq!(err.to_string(), "incomplete input at position 5");
+        let err = expand(quote!(Base64.skip_whitespace(), b"dGVz\ndA?=")).unwrap_err();
+        assert_eq!(err.to_string(), "invalid symbol '?' at position 7");
+    }
+}
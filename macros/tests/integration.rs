@@ -0,0 +1,30 @@
+//! Integration tests for the `decode!` proc macro.
+
+use const_decoder::Decoder;
+use const_decoder_macros::decode;
+
+#[test]
+fn decoding_string_literals() {
+    const HEX: [u8; 4] = decode!(Hex, "c0ffee00");
+    const BASE64: &[u8] = &decode!(Decoder::Base64Url, "3q2-7w");
+
+    assert_eq!(HEX, const_decoder::decode!(Decoder::Hex, b"c0ffee00"));
+    assert_eq!(BASE64, [0xde, 0xad, 0xbe, 0xef]);
+}
+
+#[test]
+fn decoding_byte_string_literals() {
+    const BASE64: &[u8] = &decode!(Base64Any, br"3q2+7w==");
+    const HEX: &[u8] = &decode!(
+        Hex.skip_whitespace(),
+        b"9e55d1e1aa1f455b 8baad9fdf9755036
+          55f8b359d542fa7e 4ce84106d625b352",
+    );
+
+    assert_eq!(BASE64, [0xde, 0xad, 0xbe, 0xef]);
+    let expected = const_decoder::decode!(
+        Decoder::Hex,
+        b"9e55d1e1aa1f455b8baad9fdf975503655f8b359d542fa7e4ce84106d625b352",
+    );
+    assert_eq!(HEX, expected);
+}
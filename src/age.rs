@@ -0,0 +1,60 @@
+//! age key decoder.
+
+use crate::bech32::Bech32;
+
+/// Type of an [age] key encoded with Bech32. The decoder verifies the human-readable part,
+/// the length and the checksum, and outputs the raw 32-byte X25519 key.
+///
+/// [age]: https://age-encryption.org/v1
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, AgeKey};
+/// const RECIPIENT: [u8; 32] =
+///     AgeKey::Recipient.decode(b"age1ql3z7hjy54pw3hyww5ayyfg7zqgvc7w3j2elw8zmrj2kg5sfn9aqmcac8p");
+/// assert_eq!(RECIPIENT[..4], [0x07, 0xe2, 0x2f, 0x5e]);
+/// const IDENTITY: &[u8] = &decode!(
+///     AgeKey::Identity,
+///     b"AGE-SECRET-KEY-1YQSJYGEYY5NZW2PF9G4JCTFW9UCRZV3NXS6NVDEC8YARK0PA8CLSWXGG7Q",
+/// );
+/// assert_eq!(IDENTITY.len(), 32);
+/// ```
+///
+/// Keys of an unexpected type are caught in compile time:
+///
+/// ```compile_fail
+/// # use const_decoder::AgeKey;
+/// const RECIPIENT: [u8; 32] = AgeKey::Recipient
+///     .decode(b"AGE-SECRET-KEY-1YQSJYGEYY5NZW2PF9G4JCTFW9UCRZV3NXS6NVDEC8YARK0PA8CLSWXGG7Q");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AgeKey {
+    /// X25519 recipient, i.e. a public key (`age1...`).
+    Recipient,
+    /// X25519 identity, i.e. a secret key (`AGE-SECRET-KEY-1...`).
+    Identity,
+}
+
+impl AgeKey {
+    /// Length of the decoded key in bytes.
+    pub const DECODED_LEN: usize = 32;
+
+    const fn hrp(self) -> &'static str {
+        match self {
+            Self::Recipient => "age",
+            Self::Identity => "AGE-SECRET-KEY-",
+        }
+    }
+
+    /// Decodes `input` into the 32-byte key.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `input` has an unexpected human-readable part or length.
+    /// - Panics if `input` is not valid Bech32, e.g. has an invalid checksum.
+    pub const fn decode(self, input: &[u8]) -> [u8; 32] {
+        Bech32::new(self.hrp()).decode(input)
+    }
+}
@@ -0,0 +1,109 @@
+//! Algorand address decoder.
+
+use crate::{
+    decoder::{try_check_input_len, Decoder},
+    digest::sha512_256,
+    error::{DecodeError, DecodeErrorKind},
+    panics::{compile_assert, fmt},
+};
+
+/// Decoder for [Algorand addresses]: 58 chars of unpadded base32 encoding a 32-byte Ed25519
+/// public key followed by a 4-byte checksum (the last 4 bytes of the SHA-512/256 digest
+/// of the public key). The checksum is verified during decoding; the output is the public key.
+///
+/// [Algorand addresses]: https://developer.algorand.org/docs/get-details/encoding/#address
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, AlgorandAddress};
+/// const ZERO: [u8; 32] =
+///     AlgorandAddress::decode(b"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKQ");
+/// assert_eq!(ZERO, [0; 32]);
+/// const SAME: &[u8] = &decode!(
+///     AlgorandAddress,
+///     b"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKQ",
+/// );
+/// assert_eq!(SAME, ZERO);
+/// ```
+///
+/// Invalid checksums are caught in compile time:
+///
+/// ```compile_fail
+/// # use const_decoder::AlgorandAddress;
+/// const ZERO: [u8; 32] =
+///     AlgorandAddress::decode(b"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKA");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AlgorandAddress;
+
+impl AlgorandAddress {
+    /// Length of an address in its text form.
+    pub const ENCODED_LEN: usize = 58;
+    /// Length of the decoded public key in bytes.
+    pub const DECODED_LEN: usize = 32;
+
+    const BASE32: Decoder = Decoder::custom("ABCDEFGHIJKLMNOPQRSTUVWXYZ234567");
+
+    /// Decodes `input` into the 32-byte public key.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `input` does not have 58 chars, or contains chars outside the base32 alphabet.
+    /// - Panics if the checksum is invalid.
+    pub const fn decode(input: &[u8]) -> [u8; 32] {
+        compile_assert!(
+            input.len() == Self::ENCODED_LEN,
+            "Algorand address must have 58 chars, got ", input.len() => fmt::<usize>()
+        );
+        let raw: [u8; 36] = Self::BASE32.decode(input);
+        let digest = sha512_256(&raw, 32);
+        let mut i = 0;
+        while i < 4 {
+            compile_assert!(
+                digest[28 + i] == raw[32 + i],
+                "Algorand address checksum mismatch"
+            );
+            i += 1;
+        }
+        Self::public_key(&raw)
+    }
+
+    /// Decodes `input` into the 32-byte public key, returning an error on invalid input.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if `input` does not have 58 chars, or contains chars outside
+    ///   the base32 alphabet.
+    /// - Returns a [`ChecksumMismatch`](DecodeErrorKind::ChecksumMismatch) error if the checksum
+    ///   is invalid.
+    pub const fn try_decode(input: &[u8]) -> Result<[u8; 32], DecodeError> {
+        if let Err(err) = try_check_input_len(input, Self::ENCODED_LEN) {
+            return Err(err);
+        }
+        let raw: [u8; 36] = match Self::BASE32.try_decode(input) {
+            Ok(raw) => raw,
+            Err(err) => return Err(err),
+        };
+        let digest = sha512_256(&raw, 32);
+        let mut i = 0;
+        while i < 4 {
+            if digest[28 + i] != raw[32 + i] {
+                let kind = DecodeErrorKind::ChecksumMismatch;
+                return Err(DecodeError::new(kind, input.len()));
+            }
+            i += 1;
+        }
+        Ok(Self::public_key(&raw))
+    }
+
+    const fn public_key(raw: &[u8; 36]) -> [u8; 32] {
+        let mut public_key = [0_u8; 32];
+        let mut i = 0;
+        while i < 32 {
+            public_key[i] = raw[i];
+            i += 1;
+        }
+        public_key
+    }
+}
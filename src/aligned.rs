@@ -0,0 +1,88 @@
+//! Aligned byte arrays.
+
+use core::ops;
+
+/// Byte array `[u8; N]` aligned as the type `A`, e.g. one of alignment markers such as [`Align8`]
+/// or an integer type like `u64`. Aligned arrays can be safely reinterpreted as arrays of words
+/// with the same or smaller alignment, or used as buffers with alignment requirements
+/// (e.g., for DMA).
+///
+/// Use the [`decode_aligned!`](crate::decode_aligned) macro to decode directly into an aligned
+/// array.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Aligned, Decoder};
+/// static WORDS: Aligned<u32, 8> = Aligned::new(decode!(Decoder::Hex, b"0100000002000000"));
+/// // SAFETY: the array is aligned and has a length divisible by `size_of::<u32>()`.
+/// let words: &[u32; 2] = unsafe { &*WORDS.as_bytes().as_ptr().cast::<[u32; 2]>() };
+/// assert_eq!(words.map(u32::from_le), [1, 2]);
+/// # const _: () = assert!(core::mem::align_of::<Aligned<u32, 8>>() == 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct Aligned<A, const N: usize> {
+    _alignment: [A; 0],
+    bytes: [u8; N],
+}
+
+impl<A, const N: usize> Aligned<A, N> {
+    /// Wraps the provided bytes.
+    pub const fn new(bytes: [u8; N]) -> Self {
+        Self {
+            _alignment: [],
+            bytes,
+        }
+    }
+
+    /// Returns a reference to the wrapped bytes.
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        &self.bytes
+    }
+
+    /// Returns the wrapped bytes.
+    pub fn into_inner(self) -> [u8; N] {
+        self.bytes
+    }
+}
+
+impl<A, const N: usize> ops::Deref for Aligned<A, N> {
+    type Target = [u8; N];
+
+    fn deref(&self) -> &Self::Target {
+        &self.bytes
+    }
+}
+
+impl<A, const N: usize> AsRef<[u8]> for Aligned<A, N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+macro_rules! alignment_markers {
+    ($($name:ident = $align:tt,)+) => {
+        $(
+        #[doc = concat!("Zero-sized alignment marker for [`Aligned`] with ", stringify!($align), "-byte alignment.")]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[repr(align($align))]
+        pub struct $name;
+        )+
+    };
+}
+
+alignment_markers!(
+    Align2 = 2,
+    Align4 = 4,
+    Align8 = 8,
+    Align16 = 16,
+    Align32 = 32,
+    Align64 = 64,
+    Align128 = 128,
+    Align256 = 256,
+    Align512 = 512,
+    Align1024 = 1024,
+    Align2048 = 2048,
+    Align4096 = 4096,
+);
@@ -0,0 +1,371 @@
+//! Base58 decoder.
+
+use crate::{
+    decoder::check_output_len,
+    error::{DecodeError, DecodeErrorKind},
+    panics::{clip_ascii, compile_assert, compile_panic, fmt, Ascii},
+};
+
+/// Decoder for Base58 encodings. Unlike [`Decoder`](crate::Decoder)s, Base58 does not map chars
+/// to fixed bit groups; instead, the input is interpreted as a big-endian number in base 58.
+/// By default, each leading `'1'` char (i.e., a zero digit) is decoded to a leading zero byte,
+/// as per the Bitcoin convention; this is what makes e.g. Bitcoin addresses decode to a fixed
+/// length. This can be disabled with [`Self::preserve_leading_zeros()`] to treat the input
+/// as a generic big integer, in which case leading zero digits do not contribute to the output.
+///
+/// Decoding has quadratic complexity in the input length, and the decoded length is limited
+/// to [`Self::MAX_DECODED_LEN`] bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Base58};
+/// const DATA: [u8; 11] = Base58::BITCOIN.decode(b"StV1DL6CwTryKyV");
+/// assert_eq!(DATA, *b"hello world");
+/// const ZEROS: &[u8] = &decode!(Base58::BITCOIN, b"11233QC4");
+/// assert_eq!(ZEROS, [0, 0, 0x28, 0x7f, 0xb4, 0xcd]);
+///
+/// const NUMBER: &[u8] = &decode!(Base58::BITCOIN.preserve_leading_zeros(false), b"11233QC4");
+/// assert_eq!(NUMBER, [0x28, 0x7f, 0xb4, 0xcd]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Base58 {
+    alphabet: Ascii<'static>,
+    table: [u8; 128],
+    preserve_leading_zeros: bool,
+}
+
+impl Base58 {
+    const NO_MAPPING: u8 = u8::MAX;
+
+    /// Maximum supported decoded length in bytes.
+    pub const MAX_DECODED_LEN: usize = 256;
+
+    /// Base58 with the alphabet used in Bitcoin and IPFS (`base58btc`).
+    pub const BITCOIN: Self =
+        Self::new("123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz");
+    /// Base58 with the alphabet used in Flickr short URLs.
+    pub const FLICKR: Self =
+        Self::new("123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ");
+    /// Base58 with the alphabet used in the XRP Ledger (Ripple).
+    pub const RIPPLE: Self =
+        Self::new("rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz");
+
+    /// Creates a Base58 decoder with the specified alphabet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alphabet` does not consist of 58 distinct ASCII chars.
+    #[allow(clippy::cast_possible_truncation)] // `index < 58`
+    pub const fn new(alphabet: &'static str) -> Self {
+        compile_assert!(
+            alphabet.len() == 58,
+            "Invalid Base58 alphabet length ", alphabet.len() => fmt::<usize>()
+        );
+        let alphabet_bytes = alphabet.as_bytes();
+        let alphabet = Ascii::new(alphabet); // will panic if `alphabet` contains non-ASCII chars
+        let mut table = [Self::NO_MAPPING; 128];
+        let mut index = 0;
+        while index < alphabet_bytes.len() {
+            let byte = alphabet_bytes[index];
+            compile_assert!(
+                table[byte as usize] == Self::NO_MAPPING,
+                "Alphabet character '", byte as char => fmt::<char>(), "' is mentioned several times"
+            );
+            table[byte as usize] = index as u8;
+            index += 1;
+        }
+        Self {
+            alphabet,
+            table,
+            preserve_leading_zeros: true,
+        }
+    }
+
+    /// Specifies whether leading zero digits (`'1'` chars for the Bitcoin alphabet) should be
+    /// decoded to leading zero bytes. This is enabled by default.
+    #[must_use]
+    pub const fn preserve_leading_zeros(mut self, preserve: bool) -> Self {
+        self.preserve_leading_zeros = preserve;
+        self
+    }
+
+    /// Decodes `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` contains invalid chars, or decodes to more than
+    ///   [`Self::MAX_DECODED_LEN`] bytes.
+    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        check_output_len(self.decode_len(input), N);
+        self.do_decode::<N>(input).0
+    }
+
+    /// Decodes `input` into a byte array, returning an error on invalid input.
+    /// See [`Decoder::try_decode()`](crate::Decoder::try_decode()) for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` contains invalid chars, or decodes to a length other than `N`.
+    /// If `input` decodes to more than [`Self::MAX_DECODED_LEN`] bytes, the error has
+    /// the [`Overflow`](DecodeErrorKind::Overflow) kind.
+    pub const fn try_decode<const N: usize>(self, input: &[u8]) -> Result<[u8; N], DecodeError> {
+        let (bytes, out_len) = match self.try_decode_inner::<N>(input) {
+            Ok(output) => output,
+            Err(Base58Error::Overflow(pos)) => {
+                // Determine the full decoded length to report it in the error.
+                return match self.try_decode_inner::<{ Self::MAX_DECODED_LEN }>(input) {
+                    Ok((_, decoded_len)) => {
+                        let kind = DecodeErrorKind::InvalidLength {
+                            decoded_len,
+                            expected_len: N,
+                        };
+                        Err(DecodeError::new(kind, input.len()))
+                    }
+                    Err(_) => Err(Base58Error::Overflow(pos).into_public(input)),
+                };
+            }
+            Err(err) => return Err(err.into_public(input)),
+        };
+        if out_len == N {
+            Ok(bytes)
+        } else {
+            let kind = DecodeErrorKind::InvalidLength {
+                decoded_len: out_len,
+                expected_len: N,
+            };
+            Err(DecodeError::new(kind, input.len()))
+        }
+    }
+
+    pub(crate) const fn decode_len(self, input: &[u8]) -> usize {
+        self.do_decode::<{ Self::MAX_DECODED_LEN }>(input).1
+    }
+
+    const fn digit(&self, ch: u8) -> Option<u8> {
+        let digit = if ch.is_ascii() {
+            self.table[ch as usize]
+        } else {
+            Self::NO_MAPPING
+        };
+        if digit == Self::NO_MAPPING {
+            None
+        } else {
+            Some(digit)
+        }
+    }
+
+    const fn lookup(&self, input: &[u8], pos: usize) -> u8 {
+        match self.digit(input[pos]) {
+            Some(digit) => digit,
+            None => Base58Error::InvalidChar(pos).panic(self, input, 0),
+        }
+    }
+
+    /// Decodes `input` into the start of a byte buffer. Returns the buffer and the decoded length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the decoded length exceeds `N`.
+    pub(crate) const fn do_decode<const N: usize>(self, input: &[u8]) -> ([u8; N], usize) {
+        match self.try_decode_inner::<N>(input) {
+            Ok(output) => output,
+            Err(err) => err.panic(&self, input, N),
+        }
+    }
+
+    /// Same as [`Self::do_decode()`], but returns an error instead of panicking.
+    #[allow(clippy::cast_possible_truncation)] // intentional
+    pub(crate) const fn try_decode_inner<const N: usize>(
+        self,
+        input: &[u8],
+    ) -> Result<([u8; N], usize), Base58Error> {
+        let mut zero_count = 0;
+        while zero_count < input.len() {
+            match self.digit(input[zero_count]) {
+                Some(0) => zero_count += 1,
+                Some(_) => break,
+                None => return Err(Base58Error::InvalidChar(zero_count)),
+            }
+        }
+        let in_start = zero_count;
+        if !self.preserve_leading_zeros {
+            zero_count = 0;
+        }
+
+        // Little-endian representation of the number encoded by the remaining digits.
+        let mut number = [0_u8; N];
+        let mut number_len = 0;
+        let mut in_index = in_start;
+        while in_index < input.len() {
+            let Some(digit) = self.digit(input[in_index]) else {
+                return Err(Base58Error::InvalidChar(in_index));
+            };
+            let mut carry = digit as usize;
+            let mut i = 0;
+            while i < number_len {
+                carry += number[i] as usize * 58;
+                number[i] = carry as u8;
+                carry >>= 8;
+                i += 1;
+            }
+            while carry > 0 {
+                if zero_count + number_len >= N {
+                    return Err(Base58Error::Overflow(in_index));
+                }
+                number[number_len] = carry as u8;
+                number_len += 1;
+                carry >>= 8;
+            }
+            in_index += 1;
+        }
+        if zero_count + number_len > N {
+            return Err(Base58Error::Overflow(input.len()));
+        }
+
+        // Convert the number to big-endian and prepend zeros.
+        let mut bytes = [0_u8; N];
+        let mut i = 0;
+        while i < number_len {
+            bytes[zero_count + i] = number[number_len - 1 - i];
+            i += 1;
+        }
+        Ok((bytes, zero_count + number_len))
+    }
+}
+
+/// Error produced by [`Base58`].
+#[derive(Debug)]
+pub(crate) enum Base58Error {
+    InvalidChar(usize),
+    Overflow(usize),
+}
+
+impl Base58Error {
+    pub(crate) const fn into_public(self, input: &[u8]) -> DecodeError {
+        match self {
+            Self::InvalidChar(pos) => {
+                let kind = DecodeErrorKind::InvalidSymbol { symbol: input[pos] };
+                DecodeError::new(kind, pos)
+            }
+            Self::Overflow(pos) => DecodeError::new(DecodeErrorKind::Overflow, pos),
+        }
+    }
+
+    /// Panics with a message for this error; `max_len` is the maximum decoded length.
+    pub(crate) const fn panic(self, decoder: &Base58, input: &[u8], max_len: usize) -> ! {
+        match self {
+            Self::InvalidChar(pos) => compile_panic!(
+                "Character '", input[pos] as char => fmt::<char>(), "' at position ",
+                pos => fmt::<usize>(), " is not a part of the Base58 alphabet ",
+                decoder.alphabet => clip_ascii(58, "")
+            ),
+            Self::Overflow(_) => compile_panic!(
+                "Base58 input decodes to more than ", max_len => fmt::<usize>(), " bytes"
+            ),
+        }
+    }
+}
+
+/// Decoder for the block-based Base58 variant used in Monero addresses and keys. The input
+/// is split into 11-char blocks, each of which encodes 8 bytes as a big-endian number using
+/// the [Bitcoin alphabet](Base58::BITCOIN). The final block may be shorter; its length determines
+/// the number of decoded bytes (e.g., 2 chars for 1 byte, 7 chars for 5 bytes). Unlike
+/// [`Base58`], each block has a fixed length, so leading zero bytes need no special treatment.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, MoneroBase58};
+/// const ADDRESS: &[u8] = &decode!(
+///     MoneroBase58,
+///     b"44AFFq5kSiGBoZ4NMDwYtN18obc8AemS33DBLWs3H7otXft3XjrpDtQGv7SqSsaBYBb98uNbr2VBBEt7f2wfn3RVGQBEP3A",
+/// );
+/// // Network byte, public spend key, public view key, and a 4-byte checksum
+/// assert_eq!(ADDRESS.len(), 69);
+/// assert_eq!(ADDRESS[0], 0x12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MoneroBase58;
+
+impl MoneroBase58 {
+    /// Number of chars in a full encoded block.
+    const ENCODED_BLOCK_LEN: usize = 11;
+    /// Number of decoded bytes for encoded blocks of each length; `usize::MAX` marks invalid lengths.
+    const DECODED_BLOCK_LENS: [usize; 12] = [
+        0,
+        usize::MAX,
+        1,
+        2,
+        usize::MAX,
+        3,
+        4,
+        5,
+        usize::MAX,
+        6,
+        7,
+        8,
+    ];
+
+    /// Decodes `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` contains invalid chars, has an invalid length of the final block,
+    ///   or a block overflows its decoded length.
+    pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = Self::do_decode::<N>(input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        Self::do_decode::<0>(input).1
+    }
+
+    /// Returns decoded bytes (truncated to `N`) together with the full decoded length.
+    const fn do_decode<const N: usize>(input: &[u8]) -> ([u8; N], usize) {
+        let mut bytes = [0_u8; N];
+        let mut in_index = 0;
+        let mut out_index = 0;
+        while in_index < input.len() {
+            let block_len = if input.len() - in_index >= Self::ENCODED_BLOCK_LEN {
+                Self::ENCODED_BLOCK_LEN
+            } else {
+                input.len() - in_index
+            };
+            let decoded_len = Self::DECODED_BLOCK_LENS[block_len];
+            compile_assert!(
+                decoded_len != usize::MAX,
+                "Invalid length of the final Monero Base58 block: ", block_len => fmt::<usize>(),
+                " chars"
+            );
+
+            let mut value = 0_u128;
+            let mut i = 0;
+            while i < block_len {
+                value = value * 58 + Base58::BITCOIN.lookup(input, in_index + i) as u128;
+                i += 1;
+            }
+            compile_assert!(
+                value >> (8 * decoded_len) == 0,
+                "Monero Base58 block at position ", in_index => fmt::<usize>(),
+                " overflows ", decoded_len => fmt::<usize>(), " bytes"
+            );
+
+            let block = value.to_be_bytes();
+            let mut i = 0;
+            while i < decoded_len {
+                if out_index < N {
+                    bytes[out_index] = block[16 - decoded_len + i];
+                }
+                out_index += 1;
+                i += 1;
+            }
+            in_index += block_len;
+        }
+        (bytes, out_index)
+    }
+}
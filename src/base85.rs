@@ -0,0 +1,608 @@
+//! Base85 decoders.
+
+use crate::{
+    decoder::{check_output_len, try_check_output_len},
+    error::{DecodeError, DecodeErrorKind},
+    panics::{clip_ascii, compile_assert, compile_panic, fmt, Ascii},
+};
+
+/// Decoder for Base85 encodings, such as [Ascii85], [Z85] and the [RFC 1924] alphabet. Each group of 5 chars encodes
+/// 4 bytes as a big-endian base-85 number. The final group may be incomplete; a group
+/// of `k` chars (`2 <= k <= 4`) decodes to `k - 1` bytes.
+///
+/// Optionally, `z` and `y` shortcut chars are supported in place of complete groups, encoding
+/// 4 zero bytes and 4 spaces (`0x20` bytes) respectively. These shortcuts are used by
+/// the `btoa` tool and (for `z` only) by the Adobe variant of Ascii85. Shortcuts are
+/// disabled for the predefined alphabets and can be enabled with [`Self::zero_shortcut()`]
+/// and [`Self::space_shortcut()`], or by using [`Self::BTOA`].
+///
+/// [`Self::ADOBE`] supports the Adobe variant used in PostScript and PDF sources, with the encoded
+/// data framed by `<~` and `~>` delimiters and arbitrary whitespace; see
+/// [`Self::adobe_framing()`] and [`Self::skip_whitespace()`].
+///
+/// [Ascii85]: https://en.wikipedia.org/wiki/Ascii85
+/// [Z85]: https://rfc.zeromq.org/spec/32/
+/// [RFC 1924]: https://www.rfc-editor.org/rfc/rfc1924
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Base85};
+/// const DATA: [u8; 11] = Base85::ASCII85.decode(b"BOu!rD]j7BEbo7");
+/// assert_eq!(DATA, *b"hello world");
+/// const KEY: &[u8] = &decode!(Base85::Z85, b"HelloWorld");
+/// assert_eq!(KEY, [0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b]);
+/// const ZEROS: &[u8] = &decode!(Base85::BTOA, b"zy");
+/// assert_eq!(ZEROS, *b"\0\0\0\0    ");
+///
+/// const ADOBE: &[u8] = &decode!(Base85::ADOBE, b"<~BOu!rD]j7\n  BEbo7~>\n");
+/// assert_eq!(ADOBE, b"hello world");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(clippy::struct_excessive_bools)] // independent options
+pub struct Base85 {
+    alphabet: Ascii<'static>,
+    table: [u8; 128],
+    zero_shortcut: bool,
+    space_shortcut: bool,
+    adobe_framing: bool,
+    skip_whitespace: bool,
+}
+
+impl Base85 {
+    const NO_MAPPING: u8 = u8::MAX;
+    const ZERO_SHORTCUT: u8 = b'z';
+    const SPACE_SHORTCUT: u8 = b'y';
+
+    /// Ascii85 alphabet consisting of chars `!` to `u`, without shortcuts.
+    pub const ASCII85: Self = Self::new(
+        "!\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstu",
+    );
+    /// Ascii85 alphabet with `z` and `y` shortcuts, as used by the `btoa` tool.
+    pub const BTOA: Self = Self::ASCII85.zero_shortcut(true).space_shortcut(true);
+    /// Adobe variant of Ascii85, as used in PostScript and PDF: the encoded data is framed
+    /// with `<~` and `~>` delimiters, whitespace is ignored, and the `z` shortcut is supported.
+    pub const ADOBE: Self = Self::ASCII85
+        .zero_shortcut(true)
+        .adobe_framing(true)
+        .skip_whitespace(true);
+    /// Alphabet from [RFC 1924] (digits, uppercase and lowercase Latin letters, and 23 punctuation
+    /// chars), also used by Git binary patches, Mercurial and Python's `base64.b85encode()`. Use [`Self::decode_ipv6()`] to decode
+    /// IPv6 addresses in the RFC 1924 representation.
+    ///
+    /// [RFC 1924]: https://www.rfc-editor.org/rfc/rfc1924
+    pub const RFC1924: Self = Self::new(
+        "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~",
+    );
+    /// Z85 alphabet used in `ZeroMQ`.
+    pub const Z85: Self = Self::new(
+        "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#",
+    );
+
+    /// Creates a Base85 decoder with the specified alphabet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alphabet` does not consist of 85 distinct ASCII chars.
+    #[allow(clippy::cast_possible_truncation)] // `index < 85`
+    pub const fn new(alphabet: &'static str) -> Self {
+        compile_assert!(
+            alphabet.len() == 85,
+            "Invalid Base85 alphabet length ", alphabet.len() => fmt::<usize>()
+        );
+        let alphabet_bytes = alphabet.as_bytes();
+        let alphabet = Ascii::new(alphabet); // will panic if `alphabet` contains non-ASCII chars
+        let mut table = [Self::NO_MAPPING; 128];
+        let mut index = 0;
+        while index < alphabet_bytes.len() {
+            let byte = alphabet_bytes[index];
+            compile_assert!(
+                table[byte as usize] == Self::NO_MAPPING,
+                "Alphabet character '", byte as char => fmt::<char>(), "' is mentioned several times"
+            );
+            table[byte as usize] = index as u8;
+            index += 1;
+        }
+        Self {
+            alphabet,
+            table,
+            zero_shortcut: false,
+            space_shortcut: false,
+            adobe_framing: false,
+            skip_whitespace: false,
+        }
+    }
+
+    /// Specifies whether the `z` char should be decoded as 4 zero bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shortcut is enabled and `z` is a part of the alphabet.
+    #[must_use]
+    pub const fn zero_shortcut(mut self, enable: bool) -> Self {
+        if enable {
+            self.check_shortcut(Self::ZERO_SHORTCUT);
+        }
+        self.zero_shortcut = enable;
+        self
+    }
+
+    /// Specifies whether the `y` char should be decoded as 4 spaces.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shortcut is enabled and `y` is a part of the alphabet.
+    #[must_use]
+    pub const fn space_shortcut(mut self, enable: bool) -> Self {
+        if enable {
+            self.check_shortcut(Self::SPACE_SHORTCUT);
+        }
+        self.space_shortcut = enable;
+        self
+    }
+
+    /// Specifies whether the encoded data must be framed with `<~` and `~>` delimiters,
+    /// as in the Adobe variant of Ascii85.
+    #[must_use]
+    pub const fn adobe_framing(mut self, enable: bool) -> Self {
+        self.adobe_framing = enable;
+        self
+    }
+
+    /// Specifies whether ASCII whitespace in the input (including whitespace around
+    /// the Adobe framing delimiters) should be ignored.
+    #[must_use]
+    pub const fn skip_whitespace(mut self, skip: bool) -> Self {
+        self.skip_whitespace = skip;
+        self
+    }
+
+    const fn check_shortcut(&self, ch: u8) {
+        compile_assert!(
+            self.table[ch as usize] == Self::NO_MAPPING,
+            "Shortcut char '", ch as char => fmt::<char>(), "' is a part of the Base85 alphabet"
+        );
+    }
+
+    /// Decodes `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` contains invalid chars, a group overflows 32 bits, or the final
+    ///   group consists of a single char.
+    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = self.do_decode::<N>(input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    /// Decodes `input` into a byte array, returning an error on invalid input.
+    /// See [`Decoder::try_decode()`](crate::Decoder::try_decode()) for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` contains invalid chars, the final group consists of a single
+    /// char, or `input` decodes to a length other than `N`. A group overflowing 32 bits
+    /// is reported as [`Overflow`](DecodeErrorKind::Overflow). With [Adobe framing](Self::ADOBE),
+    /// a missing `<~` delimiter is reported as [`MissingHeader`](DecodeErrorKind::MissingHeader),
+    /// and a missing `~>` delimiter as [`IncompleteInput`](DecodeErrorKind::IncompleteInput).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::{Base85, DecodeErrorKind};
+    /// let err = Base85::ASCII85.try_decode::<4>(b"uuuuu").unwrap_err();
+    /// assert_eq!(err.kind(), DecodeErrorKind::Overflow);
+    /// assert_eq!(err.position(), 0);
+    /// ```
+    pub const fn try_decode<const N: usize>(self, input: &[u8]) -> Result<[u8; N], DecodeError> {
+        let (bytes, out_len) = match self.try_decode_inner::<N>(input) {
+            Ok(output) => output,
+            Err(err) => return Err(err.into_public(input)),
+        };
+        const_try!(try_check_output_len(input, out_len, N));
+        Ok(bytes)
+    }
+
+    /// Decodes a 20-char IPv6 address in the [RFC 1924] representation. Unlike [`Self::decode()`],
+    /// the address is treated as a single 128-bit big-endian number, rather than split into
+    /// 4-byte groups. Shortcuts, framing and whitespace options do not apply.
+    ///
+    /// [RFC 1924]: https://www.rfc-editor.org/rfc/rfc1924
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `input` does not have 20 chars or contains invalid chars.
+    /// - Panics if the encoded value overflows 128 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::Base85;
+    /// // 1080:0:0:0:8:800:200C:417A
+    /// const ADDRESS: [u8; 16] = Base85::RFC1924.decode_ipv6(b"4)+k&C#VzJ4br>0wv%Yp");
+    /// assert_eq!(ADDRESS[..2], [0x10, 0x80]);
+    /// assert_eq!(ADDRESS[14..], [0x41, 0x7a]);
+    /// ```
+    #[allow(clippy::cast_possible_truncation)] // intentional
+    pub const fn decode_ipv6(self, input: &[u8]) -> [u8; 16] {
+        compile_assert!(
+            input.len() == 20,
+            "IPv6 address in RFC 1924 representation must have 20 chars, got ",
+            input.len() => fmt::<usize>()
+        );
+
+        // Big-endian representation of the number encoded by the processed digits.
+        let mut bytes = [0_u8; 16];
+        let mut in_index = 0;
+        while in_index < input.len() {
+            let mut carry = self.lookup(input, in_index) as usize;
+            let mut i = bytes.len();
+            while i > 0 {
+                i -= 1;
+                carry += bytes[i] as usize * 85;
+                bytes[i] = carry as u8;
+                carry >>= 8;
+            }
+            compile_assert!(carry == 0, "IPv6 address overflows 128 bits");
+            in_index += 1;
+        }
+        bytes
+    }
+
+    pub(crate) const fn decode_len(self, input: &[u8]) -> usize {
+        self.do_decode::<0>(input).1
+    }
+
+    const fn digit(&self, input: &[u8], pos: usize) -> Result<u8, Base85Error> {
+        let ch = input[pos];
+        let digit = if ch.is_ascii() {
+            self.table[ch as usize]
+        } else {
+            Self::NO_MAPPING
+        };
+        if digit == Self::NO_MAPPING {
+            Err(Base85Error::InvalidChar(pos))
+        } else {
+            Ok(digit)
+        }
+    }
+
+    const fn lookup(&self, input: &[u8], pos: usize) -> u8 {
+        match self.digit(input, pos) {
+            Ok(digit) => digit,
+            Err(err) => err.panic(self, input),
+        }
+    }
+
+    /// Returns the shortcut group for the char at `pos`, if any.
+    const fn shortcut(&self, input: &[u8], pos: usize) -> Option<[u8; 4]> {
+        match input[pos] {
+            Self::ZERO_SHORTCUT if self.zero_shortcut => Some([0; 4]),
+            Self::SPACE_SHORTCUT if self.space_shortcut => Some([b' '; 4]),
+            _ => None,
+        }
+    }
+
+    const fn skip_whitespace_at(&self, input: &[u8], mut pos: usize, end: usize) -> usize {
+        if self.skip_whitespace {
+            while pos < end && input[pos].is_ascii_whitespace() {
+                pos += 1;
+            }
+        }
+        pos
+    }
+
+    /// Returns the range of `input` containing encoded data, checking framing if necessary.
+    const fn data_range(&self, input: &[u8]) -> Result<(usize, usize), Base85Error> {
+        if !self.adobe_framing {
+            return Ok((0, input.len()));
+        }
+
+        let start = self.skip_whitespace_at(input, 0, input.len());
+        if start + 2 > input.len() || input[start] != b'<' || input[start + 1] != b'~' {
+            return Err(Base85Error::NoAdobeStart(start));
+        }
+        let mut end = input.len();
+        if self.skip_whitespace {
+            while end > start + 2 && input[end - 1].is_ascii_whitespace() {
+                end -= 1;
+            }
+        }
+        if end < start + 4 || input[end - 2] != b'~' || input[end - 1] != b'>' {
+            return Err(Base85Error::NoAdobeEnd);
+        }
+        Ok((start + 2, end - 2))
+    }
+
+    /// Returns decoded bytes (truncated to `N`) together with the full decoded length.
+    const fn do_decode<const N: usize>(self, input: &[u8]) -> ([u8; N], usize) {
+        match self.try_decode_inner::<N>(input) {
+            Ok(output) => output,
+            Err(err) => err.panic(&self, input),
+        }
+    }
+
+    /// Same as [`Self::do_decode()`], but returns an error instead of panicking.
+    const fn try_decode_inner<const N: usize>(
+        self,
+        input: &[u8],
+    ) -> Result<([u8; N], usize), Base85Error> {
+        let mut bytes = [0_u8; N];
+        let (mut in_index, end) = const_try!(self.data_range(input));
+        let mut out_index = 0;
+        in_index = self.skip_whitespace_at(input, in_index, end);
+        while in_index < end {
+            let (group, group_len, consumed) = if let Some(group) = self.shortcut(input, in_index) {
+                (group, 4, 1)
+            } else {
+                const_try!(self.decode_group(input, in_index, end))
+            };
+
+            let mut i = 0;
+            while i < group_len {
+                if out_index < N {
+                    bytes[out_index] = group[i];
+                }
+                out_index += 1;
+                i += 1;
+            }
+            in_index = self.skip_whitespace_at(input, in_index + consumed, end);
+        }
+        Ok((bytes, out_index))
+    }
+
+    /// Decodes a (possibly incomplete) group starting at `pos`. Returns the decoded group,
+    /// the number of decoded bytes and the number of consumed chars (including whitespace
+    /// within the group).
+    #[allow(clippy::cast_possible_truncation)] // checked
+    const fn decode_group(
+        &self,
+        input: &[u8],
+        pos: usize,
+        end: usize,
+    ) -> Result<([u8; 4], usize, usize), Base85Error> {
+        let mut value = 0_u64;
+        let mut digit_count = 0;
+        let mut in_index = pos;
+        while digit_count < 5 {
+            in_index = self.skip_whitespace_at(input, in_index, end);
+            if in_index == end {
+                break;
+            }
+            value = value * 85 + const_try!(self.digit(input, in_index)) as u64;
+            in_index += 1;
+            digit_count += 1;
+        }
+        if digit_count <= 1 {
+            return Err(Base85Error::SingleChar(pos));
+        }
+
+        let mut padding = digit_count;
+        while padding < 5 {
+            value = value * 85 + 84; // incomplete groups are padded with the highest digit
+            padding += 1;
+        }
+        if value > u32::MAX as u64 {
+            return Err(Base85Error::Overflow(pos));
+        }
+        let group = (value as u32).to_be_bytes();
+        Ok((group, digit_count - 1, in_index - pos))
+    }
+}
+
+/// Error produced by [`Base85`] and [`GitBase85`].
+#[derive(Debug, Clone, Copy)]
+enum Base85Error {
+    InvalidChar(usize),
+    SingleChar(usize),
+    Overflow(usize),
+    NoAdobeStart(usize),
+    NoAdobeEnd,
+    InvalidLineLenChar(usize),
+    LineLenMismatch {
+        pos: usize,
+        line_len: usize,
+        expected_chars: usize,
+        actual_chars: usize,
+    },
+}
+
+impl Base85Error {
+    const fn into_public(self, input: &[u8]) -> DecodeError {
+        let (kind, pos) = match self {
+            Self::InvalidChar(pos) | Self::InvalidLineLenChar(pos) => {
+                let kind = DecodeErrorKind::InvalidSymbol { symbol: input[pos] };
+                (kind, pos)
+            }
+            Self::SingleChar(_) | Self::NoAdobeEnd => {
+                (DecodeErrorKind::IncompleteInput, input.len())
+            }
+            Self::Overflow(pos) => (DecodeErrorKind::Overflow, pos),
+            Self::NoAdobeStart(pos) => (DecodeErrorKind::MissingHeader, pos),
+            Self::LineLenMismatch {
+                pos,
+                expected_chars,
+                actual_chars,
+                ..
+            } => {
+                // `pos` is the position of the length char.
+                if actual_chars < expected_chars {
+                    (DecodeErrorKind::IncompleteInput, pos + 1 + actual_chars)
+                } else {
+                    (DecodeErrorKind::TrailingData, pos + 1 + expected_chars)
+                }
+            }
+        };
+        DecodeError::new(kind, pos)
+    }
+
+    const fn panic(self, decoder: &Base85, input: &[u8]) -> ! {
+        match self {
+            Self::InvalidChar(pos) => compile_panic!(
+                "Character '", input[pos] as char => fmt::<char>(), "' at position ",
+                pos => fmt::<usize>(), " is not a part of the Base85 alphabet ",
+                decoder.alphabet => clip_ascii(85, "")
+            ),
+            Self::SingleChar(pos) => compile_panic!(
+                "Base85 input ends with a single char at position ", pos => fmt::<usize>()
+            ),
+            Self::Overflow(pos) => compile_panic!(
+                "Base85 group at position ", pos => fmt::<usize>(), " overflows 32 bits"
+            ),
+            Self::NoAdobeStart(_) => compile_panic!("Adobe Ascii85 input must start with '<~'"),
+            Self::NoAdobeEnd => compile_panic!("Adobe Ascii85 input must end with '~>'"),
+            Self::InvalidLineLenChar(pos) => compile_panic!(
+                "Invalid line length char '", input[pos] as char => fmt::<char>(),
+                "' at position ", pos => fmt::<usize>()
+            ),
+            Self::LineLenMismatch {
+                pos,
+                line_len,
+                expected_chars,
+                actual_chars,
+            } => compile_panic!(
+                "Line at position ", pos => fmt::<usize>(), " encodes ",
+                line_len => fmt::<usize>(), " bytes and must have ", expected_chars => fmt::<usize>(),
+                " Base85 chars, got ", actual_chars => fmt::<usize>()
+            ),
+        }
+    }
+}
+
+/// Decoder for the Base85 encoding used in [Git binary patches] (`GIT binary patch` hunks,
+/// e.g. in the `git format-patch --binary` output).
+///
+/// The input consists of lines separated by `\n`. Each line starts with a char encoding
+/// the number of bytes on the line (`A`..=`Z` for 1..=26 bytes, `a`..=`z` for 27..=52 bytes),
+/// followed by the bytes encoded with the [RFC 1924](Base85::RFC1924) alphabet, zero-padded
+/// to complete 4-byte groups. Empty lines (e.g., a trailing newline) are ignored.
+/// Decoded bytes from all lines are concatenated.
+///
+/// Git compresses hunk data with zlib; use [`Inflate::Zlib`](crate::Inflate::Zlib)
+/// to decompress it. The hunk header (e.g., `literal 14`) must not be included in the input.
+///
+/// [Git binary patches]: https://git-scm.com/docs/git-apply
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, GitBase85, Inflate};
+/// const DATA: &[u8] = &decode!(GitBase85, b"KXk~0{Zy<MXa%^M(\n");
+/// assert_eq!(DATA, b"hello world");
+///
+/// // Compressed hunk data
+/// const TEXT: &[u8] = &decode!(
+///     Inflate::Zlib,
+///     &decode!(GitBase85, b"Yc$`bl&nqs`O-W78Pf0CONTxXt0A+V7Q2+n{"),
+/// );
+/// assert_eq!(TEXT, b"const-decoder ".repeat(8));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GitBase85;
+
+impl GitBase85 {
+    const BASE85: Base85 = Base85::RFC1924;
+
+    /// Decodes `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` contains invalid chars, or a line length does not match its
+    ///   length char.
+    pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = Self::do_decode::<N>(input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    /// Decodes `input` into a byte array, returning an error on invalid input.
+    /// See [`Decoder::try_decode()`](crate::Decoder::try_decode()) for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` contains invalid chars, decodes to a length other than `N`,
+    /// or a line has fewer ([`IncompleteInput`](DecodeErrorKind::IncompleteInput)) or more
+    /// ([`TrailingData`](DecodeErrorKind::TrailingData)) chars than implied by its length char.
+    pub const fn try_decode<const N: usize>(input: &[u8]) -> Result<[u8; N], DecodeError> {
+        let (bytes, out_len) = match Self::try_decode_inner::<N>(input) {
+            Ok(output) => output,
+            Err(err) => return Err(err.into_public(input)),
+        };
+        const_try!(try_check_output_len(input, out_len, N));
+        Ok(bytes)
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        Self::do_decode::<0>(input).1
+    }
+
+    const fn line_len(input: &[u8], pos: usize) -> Result<usize, Base85Error> {
+        let ch = input[pos];
+        match ch {
+            b'A'..=b'Z' => Ok((ch - b'A') as usize + 1),
+            b'a'..=b'z' => Ok((ch - b'a') as usize + 27),
+            _ => Err(Base85Error::InvalidLineLenChar(pos)),
+        }
+    }
+
+    /// Returns decoded bytes (truncated to `N`) together with the full decoded length.
+    const fn do_decode<const N: usize>(input: &[u8]) -> ([u8; N], usize) {
+        match Self::try_decode_inner::<N>(input) {
+            Ok(output) => output,
+            Err(err) => err.panic(&Self::BASE85, input),
+        }
+    }
+
+    /// Same as [`Self::do_decode()`], but returns an error instead of panicking.
+    const fn try_decode_inner<const N: usize>(
+        input: &[u8],
+    ) -> Result<([u8; N], usize), Base85Error> {
+        let mut bytes = [0_u8; N];
+        let mut in_index = 0;
+        let mut out_index = 0;
+        while in_index < input.len() {
+            let mut line_end = in_index;
+            while line_end < input.len() && input[line_end] != b'\n' {
+                line_end += 1;
+            }
+            if line_end == in_index {
+                in_index += 1;
+                continue;
+            }
+
+            let line_len = const_try!(Self::line_len(input, in_index));
+            let expected_chars = (line_len + 3) / 4 * 5;
+            let actual_chars = line_end - in_index - 1;
+            if actual_chars != expected_chars {
+                return Err(Base85Error::LineLenMismatch {
+                    pos: in_index,
+                    line_len,
+                    expected_chars,
+                    actual_chars,
+                });
+            }
+
+            let mut decoded_on_line = 0;
+            let mut pos = in_index + 1;
+            while pos < line_end {
+                let (group, _, consumed) =
+                    const_try!(Self::BASE85.decode_group(input, pos, line_end));
+                let mut i = 0;
+                while i < 4 && decoded_on_line < line_len {
+                    if out_index < N {
+                        bytes[out_index] = group[i];
+                    }
+                    out_index += 1;
+                    decoded_on_line += 1;
+                    i += 1;
+                }
+                pos += consumed;
+            }
+            in_index = line_end + 1;
+        }
+        Ok((bytes, out_index))
+    }
+}
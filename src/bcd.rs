@@ -0,0 +1,145 @@
+//! Binary-coded decimal (BCD) decoder.
+
+use crate::{
+    decoder::check_output_len,
+    panics::{compile_assert, compile_panic, fmt},
+};
+
+/// Decoder packing decimal digit strings into [binary-coded decimal][BCD] (BCD) bytes, with
+/// 2 digits per byte (the first digit is stored in the high nibble). If the input has an odd
+/// number of digits, the output is padded with a `0xF` nibble, either at the end
+/// ([`Self::RIGHT_PADDED`], e.g. for SIM card and ISO 8583 fields) or at the start
+/// ([`Self::LEFT_PADDED`]). Use [`Self::encode()`] to unpack BCD bytes into digits.
+///
+/// [BCD]: https://en.wikipedia.org/wiki/Binary-coded_decimal
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Bcd};
+/// const AMOUNT: [u8; 3] = Bcd::RIGHT_PADDED.decode(b"012345");
+/// assert_eq!(AMOUNT, [0x01, 0x23, 0x45]);
+/// const PAN: &[u8] = &decode!(Bcd::RIGHT_PADDED, b"12345");
+/// assert_eq!(PAN, [0x12, 0x34, 0x5f]);
+/// const LEFT: &[u8] = &decode!(Bcd::LEFT_PADDED, b"12345");
+/// assert_eq!(LEFT, [0xf1, 0x23, 0x45]);
+///
+/// const DIGITS: [u8; 5] = Bcd::RIGHT_PADDED.encode(&[0x12, 0x34, 0x5f]);
+/// assert_eq!(DIGITS, *b"12345");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bcd {
+    pad_left: bool,
+}
+
+impl Bcd {
+    /// Nibble used for padding.
+    pub const PADDING: u8 = 0xf;
+    /// BCD with the padding nibble (if any) at the end of the output.
+    pub const RIGHT_PADDED: Self = Self { pad_left: false };
+    /// BCD with the padding nibble (if any) at the start of the output.
+    pub const LEFT_PADDED: Self = Self { pad_left: true };
+
+    /// Decodes a string of decimal digits into BCD bytes.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` contains non-digit chars.
+    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        check_output_len(Self::decode_len(input), N);
+
+        let padding_len = input.len() % 2;
+        let mut bytes = [0_u8; N];
+        let mut nibble_index = 0;
+        if self.pad_left && padding_len == 1 {
+            bytes[0] = Self::PADDING << 4;
+            nibble_index = 1;
+        }
+        let mut in_index = 0;
+        while in_index < input.len() {
+            let ch = input[in_index];
+            compile_assert!(
+                ch.is_ascii_digit(),
+                "Character '", ch as char => fmt::<char>(), "' at position ",
+                in_index => fmt::<usize>(), " is not a decimal digit"
+            );
+            let digit = ch - b'0';
+            if nibble_index % 2 == 0 {
+                bytes[nibble_index / 2] = digit << 4;
+            } else {
+                bytes[nibble_index / 2] |= digit;
+            }
+            nibble_index += 1;
+            in_index += 1;
+        }
+        if !self.pad_left && padding_len == 1 {
+            bytes[N - 1] |= Self::PADDING;
+        }
+        bytes
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        (input.len() + 1) / 2
+    }
+
+    /// Returns the number of digits packed into the specified BCD bytes, i.e., the output length
+    /// of [`Self::encode()`].
+    pub const fn digit_count(self, bytes: &[u8]) -> usize {
+        let Some(padding_byte) = (if self.pad_left {
+            bytes.first()
+        } else {
+            bytes.last()
+        }) else {
+            return 0;
+        };
+        let padding_nibble = if self.pad_left {
+            *padding_byte >> 4
+        } else {
+            *padding_byte & 0xf
+        };
+        bytes.len() * 2 - (padding_nibble == Self::PADDING) as usize
+    }
+
+    /// Unpacks BCD bytes into a string of decimal digits, skipping the padding nibble (if any).
+    /// The output length can be computed using [`Self::digit_count()`].
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length does not match the encoded length of `bytes`.
+    /// - Panics if `bytes` contain nibbles greater than 9 (other than the padding nibble).
+    pub const fn encode<const N: usize>(self, bytes: &[u8]) -> [u8; N] {
+        let expected_len = self.digit_count(bytes);
+        compile_assert!(
+            expected_len == N,
+            "Output length mismatch: the input encodes to ", expected_len => fmt::<usize>(),
+            " digits, while type inference implies ", N => fmt::<usize>()
+        );
+
+        let skipped_nibbles = if self.pad_left {
+            bytes.len() * 2 - N
+        } else {
+            0
+        };
+        let mut digits = [0_u8; N];
+        let mut i = 0;
+        while i < N {
+            let nibble_index = i + skipped_nibbles;
+            let byte = bytes[nibble_index / 2];
+            let nibble = if nibble_index % 2 == 0 {
+                byte >> 4
+            } else {
+                byte & 0xf
+            };
+            if nibble > 9 {
+                compile_panic!(
+                    "Invalid BCD nibble ", nibble => fmt::<u8>(), " in byte at position ",
+                    nibble_index / 2 => fmt::<usize>()
+                );
+            }
+            digits[i] = b'0' + nibble;
+            i += 1;
+        }
+        digits
+    }
+}
@@ -0,0 +1,337 @@
+//! Bech32 and Bech32m decoders.
+
+use crate::{
+    decoder::check_output_len,
+    error::{DecodeError, DecodeErrorKind},
+    panics::{clip, compile_assert, compile_panic, fmt},
+};
+
+/// Decoder for [Bech32] and [Bech32m] strings with a fixed human-readable part (HRP).
+///
+/// The decoder verifies the HRP (case-insensitively), that the input does not mix lowercase
+/// and uppercase chars, and the checksum. The data part is converted from 5-bit groups to bytes;
+/// padding bits must be zero. The 90-char length limit from the spec is not enforced, since
+/// it is routinely exceeded by Bech32 applications (e.g., Nostr and age).
+///
+/// [Bech32]: https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki
+/// [Bech32m]: https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Bech32};
+/// const DATA: &[u8] = &decode!(
+///     Bech32::new("note"),
+///     b"note1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0s675dzg",
+/// );
+/// assert_eq!(DATA.len(), 32);
+/// assert_eq!(DATA[..4], [0, 1, 2, 3]);
+/// ```
+///
+/// Checksum mismatch is caught in compile time:
+///
+/// ```compile_fail
+/// # use const_decoder::{decode, Bech32};
+/// const DATA: &[u8] = &decode!(
+///     Bech32::new("note"),
+///     b"note1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0s675dzq",
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bech32 {
+    hrp: &'static str,
+    checksum_const: u32,
+}
+
+impl Bech32 {
+    const ALPHABET: &'static [u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+    const NO_MAPPING: u8 = u8::MAX;
+    const TABLE: [u8; 128] = Self::table();
+    const CHECKSUM_LEN: usize = 6;
+    const BECH32_CONST: u32 = 1;
+    const BECH32M_CONST: u32 = 0x2bc8_30a3;
+
+    #[allow(clippy::cast_possible_truncation)] // `index < 32`
+    const fn table() -> [u8; 128] {
+        let mut table = [Self::NO_MAPPING; 128];
+        let mut index = 0;
+        while index < Self::ALPHABET.len() {
+            let ch = Self::ALPHABET[index];
+            table[ch as usize] = index as u8;
+            table[ch.to_ascii_uppercase() as usize] = index as u8;
+            index += 1;
+        }
+        table
+    }
+
+    /// Creates a Bech32 decoder expecting the specified human-readable part.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hrp` is empty or contains chars outside the `'!'..='~'` range.
+    pub const fn new(hrp: &'static str) -> Self {
+        let hrp_bytes = hrp.as_bytes();
+        compile_assert!(!hrp_bytes.is_empty(), "Bech32 human-readable part is empty");
+        let mut i = 0;
+        while i < hrp_bytes.len() {
+            compile_assert!(
+                hrp_bytes[i] >= b'!' && hrp_bytes[i] <= b'~',
+                "Invalid char at position ", i => fmt::<usize>(),
+                " in the Bech32 human-readable part"
+            );
+            i += 1;
+        }
+        Self {
+            hrp,
+            checksum_const: Self::BECH32_CONST,
+        }
+    }
+
+    /// Switches the decoder to the Bech32m checksum (BIP 350), e.g. for Taproot addresses.
+    #[must_use]
+    pub const fn bech32m(mut self) -> Self {
+        self.checksum_const = Self::BECH32M_CONST;
+        self
+    }
+
+    /// Decodes `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` has an unexpected human-readable part, mixes lowercase
+    ///   and uppercase chars, or contains invalid chars.
+    /// - Panics if the checksum is invalid.
+    /// - Panics if padding bits are non-zero.
+    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        check_output_len(self.decode_len(input), N);
+        match self.decode_inner::<N>(input) {
+            Ok((bytes, _)) => bytes,
+            Err(err) => err.panic(self, input),
+        }
+    }
+
+    /// Decodes `input` into a byte array, returning an error on invalid input.
+    /// See [`Decoder::try_decode()`](crate::Decoder::try_decode()) for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is invalid or decodes to a length other than `N`.
+    /// An invalid checksum is reported as [`ChecksumMismatch`](DecodeErrorKind::ChecksumMismatch).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::{Bech32, DecodeErrorKind};
+    /// let err = Bech32::new("note")
+    ///     .try_decode::<32>(b"note1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0s675dzq")
+    ///     .unwrap_err();
+    /// assert_eq!(err.kind(), DecodeErrorKind::ChecksumMismatch);
+    /// ```
+    pub const fn try_decode<const N: usize>(self, input: &[u8]) -> Result<[u8; N], DecodeError> {
+        let (bytes, out_len) = match self.decode_inner::<N>(input) {
+            Ok(output) => output,
+            Err(err) => return Err(err.into_public(input)),
+        };
+        if out_len == N {
+            Ok(bytes)
+        } else {
+            let kind = DecodeErrorKind::InvalidLength {
+                decoded_len: out_len,
+                expected_len: N,
+            };
+            Err(DecodeError::new(kind, input.len()))
+        }
+    }
+
+    pub(crate) const fn decode_len(self, input: &[u8]) -> usize {
+        let data_start = match self.data_start(input) {
+            Ok(data_start) => data_start,
+            Err(err) => err.panic(self, input),
+        };
+        let data_len = input.len() - data_start - Self::CHECKSUM_LEN;
+        data_len * 5 / 8
+    }
+
+    /// Verifies the human-readable part and the letter case of `input`, and returns the start
+    /// of the data part.
+    const fn data_start(self, input: &[u8]) -> Result<usize, Bech32Error> {
+        let hrp = self.hrp.as_bytes();
+        if input.len() <= hrp.len() || input[hrp.len()] != b'1' {
+            return Err(Bech32Error::NoSeparator);
+        }
+        let mut i = 0;
+        while i < hrp.len() {
+            if !input[i].eq_ignore_ascii_case(&hrp[i]) {
+                return Err(Bech32Error::HrpMismatch(i));
+            }
+            i += 1;
+        }
+
+        let data_start = hrp.len() + 1;
+        if input.len() < data_start + Self::CHECKSUM_LEN {
+            return Err(Bech32Error::NoChecksum);
+        }
+        let (mut has_lowercase, mut has_uppercase) = (false, false);
+        i = 0;
+        while i < input.len() {
+            has_lowercase = has_lowercase || input[i].is_ascii_lowercase();
+            has_uppercase = has_uppercase || input[i].is_ascii_uppercase();
+            if has_lowercase && has_uppercase {
+                return Err(Bech32Error::MixedCase(i));
+            }
+            i += 1;
+        }
+        Ok(data_start)
+    }
+
+    const fn lookup(input: &[u8], pos: usize) -> Option<u8> {
+        let ch = input[pos];
+        let value = if ch.is_ascii() {
+            Self::TABLE[ch as usize]
+        } else {
+            Self::NO_MAPPING
+        };
+        if value == Self::NO_MAPPING {
+            None
+        } else {
+            Some(value)
+        }
+    }
+
+    const fn polymod_step(checksum: u32, value: u8) -> u32 {
+        const GENERATOR: [u32; 5] = [
+            0x3b6a_57b2,
+            0x2650_8e6d,
+            0x1ea1_19fa,
+            0x3d42_33dd,
+            0x2a14_62b3,
+        ];
+
+        let top = checksum >> 25;
+        let mut checksum = ((checksum & 0x01ff_ffff) << 5) ^ value as u32;
+        let mut i = 0;
+        while i < 5 {
+            if (top >> i) & 1 != 0 {
+                checksum ^= GENERATOR[i];
+            }
+            i += 1;
+        }
+        checksum
+    }
+
+    /// Returns decoded bytes (truncated to `N`) and the full decoded length.
+    #[allow(clippy::cast_possible_truncation)] // intentional
+    const fn decode_inner<const N: usize>(
+        self,
+        input: &[u8],
+    ) -> Result<([u8; N], usize), Bech32Error> {
+        let data_start = match self.data_start(input) {
+            Ok(data_start) => data_start,
+            Err(err) => return Err(err),
+        };
+
+        let hrp = self.hrp.as_bytes();
+        let mut checksum = 1;
+        let mut i = 0;
+        while i < hrp.len() {
+            checksum = Self::polymod_step(checksum, hrp[i].to_ascii_lowercase() >> 5);
+            i += 1;
+        }
+        checksum = Self::polymod_step(checksum, 0);
+        i = 0;
+        while i < hrp.len() {
+            checksum = Self::polymod_step(checksum, hrp[i].to_ascii_lowercase() & 31);
+            i += 1;
+        }
+
+        let data_end = input.len() - Self::CHECKSUM_LEN;
+        let mut bytes = [0_u8; N];
+        let mut out_index = 0;
+        let (mut acc, mut bits) = (0_u16, 0);
+        i = data_start;
+        while i < input.len() {
+            let Some(value) = Self::lookup(input, i) else {
+                return Err(Bech32Error::InvalidChar(i));
+            };
+            checksum = Self::polymod_step(checksum, value);
+            if i < data_end {
+                acc = ((acc << 5) | value as u16) & 0x0fff;
+                bits += 5;
+                if bits >= 8 {
+                    bits -= 8;
+                    if out_index < N {
+                        bytes[out_index] = (acc >> bits) as u8;
+                    }
+                    out_index += 1;
+                }
+            }
+            i += 1;
+        }
+        if checksum != self.checksum_const {
+            Err(Bech32Error::ChecksumMismatch)
+        } else if bits >= 5 {
+            Err(Bech32Error::ExcessivePadding)
+        } else if acc & ((1 << bits) - 1) != 0 {
+            Err(Bech32Error::NonZeroPadding)
+        } else {
+            Ok((bytes, out_index))
+        }
+    }
+}
+
+/// Error produced by [`Bech32`].
+#[derive(Debug)]
+enum Bech32Error {
+    NoSeparator,
+    HrpMismatch(usize),
+    NoChecksum,
+    MixedCase(usize),
+    InvalidChar(usize),
+    ChecksumMismatch,
+    ExcessivePadding,
+    NonZeroPadding,
+}
+
+impl Bech32Error {
+    const fn into_public(self, input: &[u8]) -> DecodeError {
+        let (kind, pos) = match self {
+            Self::NoSeparator | Self::NoChecksum => (DecodeErrorKind::IncompleteInput, input.len()),
+            Self::HrpMismatch(pos) | Self::MixedCase(pos) | Self::InvalidChar(pos) => {
+                let kind = DecodeErrorKind::InvalidSymbol { symbol: input[pos] };
+                (kind, pos)
+            }
+            Self::ChecksumMismatch => (DecodeErrorKind::ChecksumMismatch, input.len()),
+            Self::ExcessivePadding | Self::NonZeroPadding => (
+                DecodeErrorKind::InvalidPadding,
+                input.len() - Bech32::CHECKSUM_LEN,
+            ),
+        };
+        DecodeError::new(kind, pos)
+    }
+
+    const fn panic(self, decoder: Bech32, input: &[u8]) -> ! {
+        match self {
+            Self::NoSeparator => compile_panic!(
+                "Bech32 string must start with '", decoder.hrp => clip(32, ""), "1'"
+            ),
+            Self::HrpMismatch(pos) => compile_panic!(
+                "Unexpected char '", input[pos] as char => fmt::<char>(), "' at position ",
+                pos => fmt::<usize>(), " in the Bech32 human-readable part; expected '",
+                decoder.hrp => clip(32, ""), "'"
+            ),
+            Self::NoChecksum => compile_panic!(
+                "Bech32 data part must have at least 6 chars (the checksum), got ",
+                input.len() - decoder.hrp.len() - 1 => fmt::<usize>()
+            ),
+            Self::MixedCase(_) => panic!("Bech32 string mixes lowercase and uppercase chars"),
+            Self::InvalidChar(pos) => compile_panic!(
+                "Character '", input[pos] as char => fmt::<char>(), "' at position ",
+                pos => fmt::<usize>(), " is not a part of the Bech32 alphabet"
+            ),
+            Self::ChecksumMismatch => panic!("Bech32 checksum mismatch"),
+            Self::ExcessivePadding => panic!("Bech32 data part has excessive padding"),
+            Self::NonZeroPadding => panic!("Bech32 data part has non-zero padding bits"),
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! BubbleBabble decoder.
+
+use crate::{
+    decoder::check_output_len,
+    panics::{compile_assert, compile_panic, fmt},
+};
+
+const VOWELS: &[u8; 6] = b"aeiouy";
+const CONSONANTS: &[u8; 17] = b"bcdfghklmnprstvzx";
+
+/// Decoder for the [BubbleBabble] binary data encoding, which is used e.g. by legacy
+/// SSH fingerprints.
+///
+/// The input must be a complete BubbleBabble string, i.e., start and end with `x`, and have
+/// 5-char groups separated by dashes. Besides checking the input structure, the decoder
+/// validates the checksum embedded into vowels of each group.
+///
+/// [BubbleBabble]: https://web.mit.edu/kenta/www/one/bubblebabble/spec/jrtrjwzi/draft-huima-01.txt
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::BubbleBabble;
+/// const DATA: [u8; 9] = BubbleBabble::decode(b"xigak-nyryk-humil-bosek-sonax");
+/// assert_eq!(DATA, *b"Pineapple");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BubbleBabble;
+
+impl BubbleBabble {
+    /// Decodes `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` is not a well-formed BubbleBabble string, or its checksum is invalid.
+    pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = Self::do_decode::<N>(input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    /// Returns the length of the BubbleBabble encoding of `input_len` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::BubbleBabble;
+    /// assert_eq!(BubbleBabble::encoded_len(0), 5); // "xexax"
+    /// assert_eq!(BubbleBabble::encoded_len(9), 29);
+    /// ```
+    pub const fn encoded_len(input_len: usize) -> usize {
+        input_len / 2 * 6 + 5
+    }
+
+    /// Returns the maximum number of bytes that an input with the specified length
+    /// can decode to.
+    pub const fn max_decoded_len(encoded_len: usize) -> usize {
+        if encoded_len < 5 {
+            0
+        } else {
+            (encoded_len - 5) / 6 * 2 + 1
+        }
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        Self::do_decode::<0>(input).1
+    }
+
+    const fn vowel(input: &[u8], pos: usize) -> usize {
+        let ch = input[pos];
+        let mut i = 0;
+        while i < VOWELS.len() {
+            if VOWELS[i] == ch {
+                return i;
+            }
+            i += 1;
+        }
+        compile_panic!(
+            "Character '", ch as char => fmt::<char>(), "' at position ",
+            pos => fmt::<usize>(), " is not a BubbleBabble vowel"
+        );
+    }
+
+    const fn consonant(input: &[u8], pos: usize) -> usize {
+        let ch = input[pos];
+        let mut i = 0;
+        while i < CONSONANTS.len() {
+            if CONSONANTS[i] == ch {
+                return i;
+            }
+            i += 1;
+        }
+        compile_panic!(
+            "Character '", ch as char => fmt::<char>(), "' at position ",
+            pos => fmt::<usize>(), " is not a BubbleBabble consonant"
+        );
+    }
+
+    /// Decodes a byte from the `vowel, consonant, vowel` triple starting at `pos`.
+    #[allow(clippy::cast_possible_truncation)] // all values are checked to fit into a byte
+    const fn decode_triple(input: &[u8], pos: usize, seed: usize) -> u8 {
+        let high = (Self::vowel(input, pos) + 6 - seed % 6) % 6;
+        let mid = Self::consonant(input, pos + 1);
+        let low = (Self::vowel(input, pos + 2) + 6 - seed / 6) % 6;
+        compile_assert!(
+            high < 4 && mid < 16 && low < 4,
+            "Invalid BubbleBabble checksum in group at position ", pos => fmt::<usize>()
+        );
+        ((high << 6) + (mid << 2) + low) as u8
+    }
+
+    /// Returns decoded bytes (truncated to `N`) together with the full decoded length.
+    #[allow(clippy::cast_possible_truncation)] // `second` is checked to fit into a byte
+    const fn do_decode<const N: usize>(input: &[u8]) -> ([u8; N], usize) {
+        let mut bytes = [0_u8; N];
+        compile_assert!(
+            input.len() >= 5 && input.len() % 6 == 5,
+            "Invalid BubbleBabble string length ", input.len() => fmt::<usize>()
+        );
+        compile_assert!(
+            input[0] == b'x' && input[input.len() - 1] == b'x',
+            "BubbleBabble string must start and end with 'x'"
+        );
+
+        let mut seed = 1;
+        let mut in_index = 1;
+        let mut out_index = 0;
+        while in_index + 4 <= input.len() {
+            let is_final = in_index + 4 == input.len();
+            if is_final && input[in_index + 1] == b'x' {
+                // Even-length input: the last group only contains the checksum.
+                compile_assert!(
+                    Self::vowel(input, in_index) == seed % 6
+                        && Self::vowel(input, in_index + 2) == seed / 6,
+                    "Invalid BubbleBabble checksum in group at position ", in_index => fmt::<usize>()
+                );
+                break;
+            }
+            let first = Self::decode_triple(input, in_index, seed);
+            if out_index < N {
+                bytes[out_index] = first;
+            }
+            out_index += 1;
+            if is_final {
+                break;
+            }
+
+            compile_assert!(
+                input[in_index + 4] == b'-',
+                "Expected '-' at position ", in_index + 4 => fmt::<usize>()
+            );
+            let high = Self::consonant(input, in_index + 3);
+            let low = Self::consonant(input, in_index + 5);
+            compile_assert!(
+                high < 16 && low < 16,
+                "Unexpected 'x' in group at position ", in_index => fmt::<usize>()
+            );
+            let second = (high << 4) + low;
+            if out_index < N {
+                bytes[out_index] = second as u8;
+            }
+            out_index += 1;
+
+            seed = (seed * 5 + first as usize * 7 + second) % 36;
+            in_index += 6;
+        }
+        (bytes, out_index)
+    }
+}
@@ -0,0 +1,150 @@
+//! Runtime decoding of `bytes` buffers.
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+use crate::{
+    decoder::{DecodeCursor, Decoder},
+    error::DecodeError,
+    wrappers::{SkipWhitespace, Skipper},
+};
+
+/// Calls `f` on the remaining `input` as a contiguous slice and advances `input` past it.
+/// The input is only copied if it is split into several chunks.
+fn with_contiguous<T>(mut input: impl Buf, f: impl FnOnce(&[u8]) -> T) -> T {
+    let len = input.remaining();
+    if input.chunk().len() == len {
+        let output = f(input.chunk());
+        input.advance(len);
+        output
+    } else {
+        f(&input.copy_to_bytes(len))
+    }
+}
+
+fn put_decoded(
+    mut cursor: DecodeCursor,
+    input: &[u8],
+    output: &mut BytesMut,
+) -> Result<(), DecodeError> {
+    loop {
+        let (new_cursor, byte) = cursor.try_next_byte(input)?;
+        cursor = new_cursor;
+        let Some(byte) = byte else {
+            break;
+        };
+        output.put_u8(byte);
+    }
+    cursor.try_finish()
+}
+
+fn try_decode_into_bytes_mut(
+    cursor: DecodeCursor,
+    input: &[u8],
+    output: &mut BytesMut,
+) -> Result<(), DecodeError> {
+    let start_len = output.len();
+    let result = put_decoded(cursor, input, output);
+    if result.is_err() {
+        output.truncate(start_len);
+    }
+    result
+}
+
+impl Decoder {
+    /// Decodes `input` into [`Bytes`]. The input is consumed in full, even if an error occurs.
+    ///
+    /// If `input` is contiguous (e.g., is [`Bytes`] or a slice), it is decoded without copying;
+    /// otherwise, it is copied into a contiguous buffer first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bytes::{Buf, Bytes};
+    /// # use const_decoder::Decoder;
+    /// let payload = Bytes::from_static(b"dGVz").chain(&b"dA=="[..]);
+    /// let bytes = Decoder::Base64.decode_to_bytes(payload)?;
+    /// assert_eq!(bytes, b"test"[..]);
+    /// # Ok::<_, const_decoder::DecodeError>(())
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+    pub fn decode_to_bytes(self, input: impl Buf) -> Result<Bytes, DecodeError> {
+        let mut output = BytesMut::new();
+        self.decode_into_bytes_mut(input, &mut output)?;
+        Ok(output.freeze())
+    }
+
+    /// Decodes `input` and appends the decoded bytes to `output`, reserving the necessary
+    /// capacity upfront. The input is consumed in full, even if an error occurs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is invalid. In this case, `output` is truncated
+    /// to its original length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bytes::BytesMut;
+    /// # use const_decoder::{DecodeErrorKind, Decoder};
+    /// let mut output = BytesMut::from(&b"key="[..]);
+    /// Decoder::Hex.decode_into_bytes_mut(&b"c0ffee"[..], &mut output)?;
+    /// assert_eq!(output, b"key=\xc0\xff\xee"[..]);
+    ///
+    /// let err = Decoder::Hex
+    ///     .decode_into_bytes_mut(&b"c0ffe"[..], &mut output)
+    ///     .unwrap_err();
+    /// assert_eq!(err.kind(), DecodeErrorKind::IncompleteInput);
+    /// assert_eq!(output, b"key=\xc0\xff\xee"[..]);
+    /// # Ok::<_, const_decoder::DecodeError>(())
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+    pub fn decode_into_bytes_mut(
+        self,
+        input: impl Buf,
+        output: &mut BytesMut,
+    ) -> Result<(), DecodeError> {
+        with_contiguous(input, |input| {
+            output.reserve(self.max_decoded_len(input.len()));
+            let cursor = DecodeCursor::new(self, input, None, false);
+            try_decode_into_bytes_mut(cursor, input, output)
+        })
+    }
+}
+
+impl SkipWhitespace {
+    /// Decodes `input` into [`Bytes`]. See [`Decoder::decode_to_bytes()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is invalid.
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+    pub fn decode_to_bytes(self, input: impl Buf) -> Result<Bytes, DecodeError> {
+        let mut output = BytesMut::new();
+        self.decode_into_bytes_mut(input, &mut output)?;
+        Ok(output.freeze())
+    }
+
+    /// Decodes `input` and appends the decoded bytes to `output`.
+    /// See [`Decoder::decode_into_bytes_mut()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is invalid. In this case, `output` is truncated
+    /// to its original length.
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+    pub fn decode_into_bytes_mut(
+        self,
+        input: impl Buf,
+        output: &mut BytesMut,
+    ) -> Result<(), DecodeError> {
+        with_contiguous(input, |input| {
+            output.reserve(self.0.max_decoded_len(input.len()));
+            let cursor = DecodeCursor::new(self.0, input, Some(Skipper::Whitespace), false);
+            try_decode_into_bytes_mut(cursor, input, output)
+        })
+    }
+}
@@ -0,0 +1,117 @@
+//! Helpers for build scripts.
+//!
+//! Const evaluation of large inputs (e.g., multi-megabyte assets) can noticeably slow down
+//! compilation. In this case, the input can be decoded in a build script using runtime methods
+//! such as [`Decoder::decode_to_vec()`](crate::Decoder::decode_to_vec()) with the same decoder
+//! definitions as in the main code. The decoded bytes can then be written to a sidecar binary file
+//! and embedded with [`include_bytes!`] using the code produced by [`generate_const()`].
+//!
+//! # Examples
+//!
+//! In `build.rs`:
+//!
+//! ```no_run
+//! use const_decoder::{build::generate_const, Decoder};
+//! use std::{env, fs, path::PathBuf};
+//!
+//! let input = fs::read("assets/font.b64").unwrap();
+//! let bytes = Decoder::Base64.skip_whitespace().decode_to_vec(&input);
+//! let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+//! let bin_path = out_dir.join("font.bin");
+//! fs::write(&bin_path, &bytes).unwrap();
+//! let code = generate_const("FONT", bin_path.to_str().unwrap(), bytes.len()).unwrap();
+//! fs::write(out_dir.join("font.rs"), code).unwrap();
+//! ```
+//!
+//! In the main code:
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/font.rs"));
+//! // `FONT` is now defined as `pub static FONT: &[u8; _] = include_bytes!(...);`
+//! ```
+
+use alloc::{borrow::ToOwned, format, string::String};
+use core::fmt;
+
+/// Strict and reserved Rust keywords, which cannot be used as identifiers.
+const KEYWORDS: &[&str] = &[
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Error returned by [`generate_const()`] if the provided name is not a valid Rust identifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidIdentifier {
+    name: String,
+}
+
+impl InvalidIdentifier {
+    /// Returns the offending name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for InvalidIdentifier {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "`{}` is not a valid Rust identifier", self.name)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for InvalidIdentifier {}
+
+/// Checks whether `name` is an ASCII Rust identifier other than a keyword or `_`.
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    let starts_correctly = chars
+        .next()
+        .map_or(false, |ch| ch == '_' || ch.is_ascii_alphabetic());
+    starts_correctly
+        && chars.all(|ch| ch == '_' || ch.is_ascii_alphanumeric())
+        && name != "_"
+        && !KEYWORDS.contains(&name)
+}
+
+/// Generates Rust code defining a public static with the specified `name`, which embeds
+/// a byte array of length `len` from the file at `bin_path` using [`include_bytes!`], e.g.
+/// `pub static NAME: &[u8; 3] = include_bytes!("/path/to/name.bin");`. The file is expected
+/// to be written by the caller; `bin_path` should be absolute (e.g., located in `OUT_DIR`),
+/// since relative paths are resolved relative to the file containing the generated code.
+///
+/// Unlike a constant, the static is embedded into the compiled binary once, regardless
+/// of the number of its uses.
+///
+/// # Errors
+///
+/// Returns an error if `name` is not an ASCII Rust identifier, or is a keyword.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::build::generate_const;
+/// assert_eq!(
+///     generate_const("KEY", "/out/key.bin", 3)?,
+///     "pub static KEY: &[u8; 3] = include_bytes!(\"/out/key.bin\");\n"
+/// );
+///
+/// let err = generate_const("fn", "/out/key.bin", 3).unwrap_err();
+/// assert_eq!(err.to_string(), "`fn` is not a valid Rust identifier");
+/// # Ok::<_, const_decoder::build::InvalidIdentifier>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn generate_const(name: &str, bin_path: &str, len: usize) -> Result<String, InvalidIdentifier> {
+    if !is_identifier(name) {
+        return Err(InvalidIdentifier {
+            name: name.to_owned(),
+        });
+    }
+    // `Debug` output of a string is a valid Rust string literal.
+    Ok(format!(
+        "pub static {name}: &[u8; {len}] = include_bytes!({bin_path:?});\n"
+    ))
+}
@@ -0,0 +1,72 @@
+//! Caesar cipher, including ROT13.
+
+use crate::{
+    decoder::check_output_len,
+    panics::{compile_assert, fmt},
+};
+
+/// Decoder for text obfuscated with the Caesar cipher, i.e., with each ASCII letter rotated
+/// forward by a fixed number of positions in the alphabet. The letter case is preserved,
+/// and all other bytes (including non-ASCII ones) are copied as is.
+///
+/// The most common variant is [ROT13](Self::ROT13), which is its own inverse. Since the output
+/// is an array of bytes, the decoder can be nested with other decoders, e.g. to embed
+/// a base64-encoded value that was additionally obfuscated.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Caesar, Decoder};
+/// const TEXT: &[u8] = &decode!(Caesar::ROT13, b"Uryyb, jbeyq!");
+/// assert_eq!(TEXT, b"Hello, world!");
+/// const SHIFTED: [u8; 8] = Caesar::new(3).decode(b"Fdhvdu 3");
+/// assert_eq!(SHIFTED, *b"Caesar 3");
+///
+/// // Obfuscated base64
+/// const BYTES: &[u8] = &decode!(Decoder::Base64, &decode!(Caesar::ROT13, b"nTIfoT8="));
+/// assert_eq!(BYTES, b"hello");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Caesar {
+    shift: u8,
+}
+
+impl Caesar {
+    /// ROT13 cipher (rotation by 13 positions).
+    pub const ROT13: Self = Self::new(13);
+
+    /// Creates a decoder for text encoded with the specified rotation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shift` is not in the `1..26` range.
+    pub const fn new(shift: u8) -> Self {
+        compile_assert!(
+            shift > 0 && shift < 26,
+            "Caesar shift must be in 1..26 range; got ", shift => fmt::<u8>()
+        );
+        Self { shift }
+    }
+
+    /// Decodes `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the provided length is insufficient or too large for `input`.
+    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        check_output_len(input.len(), N);
+        let back_shift = 26 - self.shift;
+        let mut bytes = [0_u8; N];
+        let mut i = 0;
+        while i < N {
+            let ch = input[i];
+            bytes[i] = match ch {
+                b'a'..=b'z' => b'a' + (ch - b'a' + back_shift) % 26,
+                b'A'..=b'Z' => b'A' + (ch - b'A' + back_shift) % 26,
+                _ => ch,
+            };
+            i += 1;
+        }
+        bytes
+    }
+}
@@ -0,0 +1,161 @@
+//! CBOR byte string extraction.
+
+use crate::{
+    decoder::check_output_len,
+    panics::{compile_assert, compile_panic, fmt},
+};
+
+/// Extractor of the payload of a top-level [CBOR] byte string (major type 2). Both definite-length
+/// byte strings (with any length encoding) and indefinite-length ones (i.e., a sequence of
+/// definite-length chunks) are supported. Semantic tags before the byte string are skipped.
+///
+/// The extractor works with raw bytes; to extract data from CBOR encoded in hex or another
+/// supported encoding, nest [`decode!`](crate::decode) invocations.
+///
+/// [CBOR]: https://www.rfc-editor.org/rfc/rfc8949
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, CborBytes, Decoder};
+/// const PAYLOAD: &[u8] = &decode!(
+///     CborBytes,
+///     &decode!(
+///         Decoder::Hex,
+///         b"5820c0ffee0000000000000000000000000000000000000000000000000000000000",
+///     ),
+/// );
+/// assert_eq!(PAYLOAD.len(), 32);
+/// assert_eq!(PAYLOAD[..3], [0xc0, 0xff, 0xee]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CborBytes;
+
+impl CborBytes {
+    const BYTE_STRING: u8 = 2;
+    const TAG: u8 = 6;
+    const INDEFINITE_LEN: u8 = 31;
+    const BREAK: u8 = 0xff;
+
+    /// Extracts the byte string payload from `input`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for the payload.
+    /// - Panics if `input` is not a valid CBOR byte string, or has data after it.
+    pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = Self::do_decode::<N>(input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        Self::do_decode::<0>(input).1
+    }
+
+    /// Reads a CBOR data item head at `pos`. Returns the major type, the argument
+    /// (or `None` for indefinite length), and the position after the head.
+    const fn read_head(input: &[u8], pos: usize) -> (u8, Option<u64>, usize) {
+        compile_assert!(
+            pos < input.len(),
+            "Unexpected end of CBOR input at position ", pos => fmt::<usize>()
+        );
+        let major_type = input[pos] >> 5;
+        let additional_info = input[pos] & 0x1f;
+        let arg_len = match additional_info {
+            0..=23 => return (major_type, Some(additional_info as u64), pos + 1),
+            24 => 1,
+            25 => 2,
+            26 => 4,
+            27 => 8,
+            Self::INDEFINITE_LEN => return (major_type, None, pos + 1),
+            _ => compile_panic!(
+                "Invalid additional info in CBOR item at position ", pos => fmt::<usize>()
+            ),
+        };
+        compile_assert!(
+            pos + 1 + arg_len <= input.len(),
+            "Unexpected end of CBOR input at position ", pos => fmt::<usize>()
+        );
+        let mut arg = 0_u64;
+        let mut i = 0;
+        while i < arg_len {
+            arg = (arg << 8) + input[pos + 1 + i] as u64;
+            i += 1;
+        }
+        (major_type, Some(arg), pos + 1 + arg_len)
+    }
+
+    /// Reads the head of a definite-length byte string at `pos`. Returns the payload position
+    /// and length.
+    #[allow(clippy::cast_possible_truncation)] // checked
+    const fn read_chunk(input: &[u8], pos: usize) -> (usize, usize) {
+        let (major_type, len, payload_pos) = Self::read_head(input, pos);
+        compile_assert!(
+            major_type == Self::BYTE_STRING,
+            "Expected CBOR byte string at position ", pos => fmt::<usize>(), ", got major type ",
+            major_type => fmt::<u8>()
+        );
+        let Some(len) = len else {
+            compile_panic!(
+                "Nested indefinite-length CBOR byte string at position ", pos => fmt::<usize>()
+            );
+        };
+        compile_assert!(
+            len <= (input.len() - payload_pos) as u64,
+            "CBOR byte string at position ", pos => fmt::<usize>(), " declares ",
+            len => fmt::<u64>(), " bytes, but the input is shorter"
+        );
+        (payload_pos, len as usize)
+    }
+
+    /// Returns decoded bytes (truncated to `N`) together with the full decoded length.
+    const fn do_decode<const N: usize>(input: &[u8]) -> ([u8; N], usize) {
+        let mut bytes = [0_u8; N];
+        let mut out_index = 0;
+
+        let mut pos = 0;
+        let (mut major_type, mut len, mut next_pos) = Self::read_head(input, pos);
+        while major_type == Self::TAG {
+            pos = next_pos;
+            (major_type, len, next_pos) = Self::read_head(input, pos);
+        }
+
+        let is_indefinite = major_type == Self::BYTE_STRING && len.is_none();
+        if is_indefinite {
+            pos = next_pos;
+        }
+        loop {
+            if is_indefinite {
+                compile_assert!(
+                    pos < input.len(),
+                    "Unterminated indefinite-length CBOR byte string"
+                );
+                if input[pos] == Self::BREAK {
+                    pos += 1;
+                    break;
+                }
+            }
+
+            let (payload_pos, chunk_len) = Self::read_chunk(input, pos);
+            let mut i = 0;
+            while i < chunk_len {
+                if out_index < N {
+                    bytes[out_index] = input[payload_pos + i];
+                }
+                out_index += 1;
+                i += 1;
+            }
+            pos = payload_pos + chunk_len;
+            if !is_indefinite {
+                break;
+            }
+        }
+
+        compile_assert!(
+            pos == input.len(),
+            "Unexpected data after CBOR byte string at position ", pos => fmt::<usize>()
+        );
+        (bytes, out_index)
+    }
+}
@@ -0,0 +1,167 @@
+//! `ChaCha20` stream cipher.
+
+use crate::{
+    decoder::{check_output_len, try_check_output_len},
+    error::DecodeError,
+};
+
+/// Initial state words: `"expand 32-byte k"` in little-endian encoding.
+const CONSTANTS: [u32; 4] = [0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574];
+
+const fn read_u32_le(bytes: &[u8], pos: usize) -> u32 {
+    u32::from_le_bytes([bytes[pos], bytes[pos + 1], bytes[pos + 2], bytes[pos + 3]])
+}
+
+const fn quarter_round(mut state: [u32; 16], a: usize, b: usize, c: usize, d: usize) -> [u32; 16] {
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(12);
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(7);
+    state
+}
+
+/// Computes a 64-byte keystream block as per RFC 8439, section 2.3.
+const fn keystream_block(key: &[u8; 32], nonce: &[u8; 12], counter: u32) -> [u8; 64] {
+    let mut initial = [0_u32; 16];
+    let mut i = 0;
+    while i < 4 {
+        initial[i] = CONSTANTS[i];
+        i += 1;
+    }
+    i = 0;
+    while i < 8 {
+        initial[4 + i] = read_u32_le(key, 4 * i);
+        i += 1;
+    }
+    initial[12] = counter;
+    i = 0;
+    while i < 3 {
+        initial[13 + i] = read_u32_le(nonce, 4 * i);
+        i += 1;
+    }
+
+    let mut state = initial;
+    let mut round = 0;
+    while round < 10 {
+        state = quarter_round(state, 0, 4, 8, 12);
+        state = quarter_round(state, 1, 5, 9, 13);
+        state = quarter_round(state, 2, 6, 10, 14);
+        state = quarter_round(state, 3, 7, 11, 15);
+        state = quarter_round(state, 0, 5, 10, 15);
+        state = quarter_round(state, 1, 6, 11, 12);
+        state = quarter_round(state, 2, 7, 8, 13);
+        state = quarter_round(state, 3, 4, 9, 14);
+        round += 1;
+    }
+
+    let mut block = [0_u8; 64];
+    i = 0;
+    while i < 64 {
+        let word = state[i / 4].wrapping_add(initial[i / 4]);
+        block[i] = word.to_le_bytes()[i % 4];
+        i += 1;
+    }
+    block
+}
+
+/// Decoder decrypting the input bytes with the [ChaCha20] stream cipher. This allows to store
+/// secrets encrypted in the source code (or in `include_bytes!` files) and decrypt them
+/// into constants during compilation, with the key provided by the build environment
+/// (e.g., via an environment variable read with [`env!`] and decoded with [`decode!`](crate::decode)).
+///
+/// The cipher follows [RFC 8439]: it uses a 256-bit key, a 96-bit nonce and a 32-bit block
+/// counter, which starts from 0 unless specified otherwise with [`Self::with_counter()`].
+/// No authentication is performed; a wrong key results in garbage output rather than
+/// a compilation error. Since encryption and decryption are the same operation, encrypted data
+/// can be produced by applying the decoder to the plaintext.
+///
+/// The input is raw bytes, so the decoder can be nested with other decoders, e.g. to decrypt
+/// a base64-encoded ciphertext.
+///
+/// [ChaCha20]: https://en.wikipedia.org/wiki/Salsa20#ChaCha_variant
+/// [RFC 8439]: https://www.rfc-editor.org/rfc/rfc8439
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, ChaCha20, Decoder};
+/// // In real code, the key would be provided by the build environment, e.g. as
+/// // `decode!(Decoder::Hex, env!("SECRET_KEY").as_bytes())`.
+/// const KEY: [u8; 32] = decode!(
+///     Decoder::Hex,
+///     b"c0ffeec0ffeec0ffeec0ffeec0ffeec0ffeec0ffeec0ffeec0ffeec0ffeec0ff",
+/// );
+/// const PASSWORD: &[u8] = &decode!(
+///     ChaCha20::new(KEY, [0; 12]),
+///     &decode!(Decoder::Base64, b"nTby1rHDrHFbu8G7dyWkbGxwGA/shSX2SAk="),
+/// );
+/// assert_eq!(PASSWORD, b"database password: hunter2");
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "chacha20")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChaCha20 {
+    key: [u8; 32],
+    nonce: [u8; 12],
+    counter: u32,
+}
+
+impl ChaCha20 {
+    /// Creates a decoder decrypting the input with the specified key and nonce.
+    pub const fn new(key: [u8; 32], nonce: [u8; 12]) -> Self {
+        Self {
+            key,
+            nonce,
+            counter: 0,
+        }
+    }
+
+    /// Sets the initial block counter. For example, the counter starts from 1 for the ciphertext
+    /// produced by the `ChaCha20-Poly1305` AEAD construction.
+    #[must_use]
+    pub const fn with_counter(mut self, counter: u32) -> Self {
+        self.counter = counter;
+        self
+    }
+
+    /// Decrypts `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the provided length is insufficient or too large for `input`.
+    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        check_output_len(input.len(), N);
+        self.apply(input)
+    }
+
+    /// Decrypts `input` into a byte array, returning an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidLength`](crate::DecodeErrorKind::InvalidLength) error if `input`
+    /// has a length other than `N`.
+    pub const fn try_decode<const N: usize>(self, input: &[u8]) -> Result<[u8; N], DecodeError> {
+        const_try!(try_check_output_len(input, input.len(), N));
+        Ok(self.apply(input))
+    }
+
+    /// XORs the first `N` bytes of `input` with the keystream.
+    const fn apply<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        let mut bytes = [0_u8; N];
+        let mut block = [0_u8; 64];
+        let mut i = 0;
+        while i < N {
+            if i % 64 == 0 {
+                #[allow(clippy::cast_possible_truncation)] // counter overflow is intended
+                let counter = self.counter.wrapping_add((i / 64) as u32);
+                block = keystream_block(&self.key, &self.nonce, counter);
+            }
+            bytes[i] = input[i] ^ block[i % 64];
+            i += 1;
+        }
+        bytes
+    }
+}
@@ -0,0 +1,186 @@
+//! IPFS content identifiers (CIDs).
+
+use crate::{
+    base58::Base58,
+    decoder::Decoder,
+    panics::{compile_assert, compile_panic, fmt},
+    varint::Varint,
+};
+
+/// Maximum supported length of a binary CID.
+const MAX_BINARY_LEN: usize = 128;
+
+/// Multicodec code of the `dag-pb` codec implied by CIDv0.
+const DAG_PB_CODEC: u64 = 0x70;
+/// Multicodec code of the `sha2-256` hash function implied by CIDv0.
+const SHA2_256_CODE: u64 = 0x12;
+
+/// Reads a varint from `bytes[pos..end]`. Returns the value and the position after the varint.
+const fn read_varint(bytes: &[u8], pos: usize, end: usize) -> (u64, usize) {
+    let (value, len) = Varint::read_u64(bytes, pos);
+    compile_assert!(
+        pos + len <= end,
+        "Unexpected end of CID while reading varint"
+    );
+    (value, pos + len)
+}
+
+/// Binary CID together with its parsed components.
+#[derive(Debug, Clone, Copy)]
+struct RawCid {
+    bytes: [u8; MAX_BINARY_LEN],
+    version: u8,
+    codec: u64,
+    hash_code: u64,
+    digest_start: usize,
+    digest_len: usize,
+}
+
+impl RawCid {
+    const fn parse(input: &[u8]) -> Self {
+        // CIDv0 is a base58btc-encoded SHA2-256 multihash.
+        if input.len() == 46 && input[0] == b'Q' && input[1] == b'm' {
+            let (bytes, len) = Base58::BITCOIN.do_decode::<MAX_BINARY_LEN>(input);
+            compile_assert!(
+                len == 34 && bytes[0] == 0x12 && bytes[1] == 32,
+                "CIDv0 must encode a SHA2-256 multihash"
+            );
+            return Self {
+                bytes,
+                version: 0,
+                codec: DAG_PB_CODEC,
+                hash_code: SHA2_256_CODE,
+                digest_start: 2,
+                digest_len: 32,
+            };
+        }
+
+        let Some((&prefix, encoded)) = input.split_first() else {
+            compile_panic!("CID is empty");
+        };
+        let (bytes, len) = match prefix {
+            b'z' => Base58::BITCOIN.do_decode::<MAX_BINARY_LEN>(encoded),
+            b'b' => Decoder::custom("abcdefghijklmnopqrstuvwxyz234567").decode_prefix(encoded),
+            b'B' => Decoder::custom("ABCDEFGHIJKLMNOPQRSTUVWXYZ234567").decode_prefix(encoded),
+            b'f' | b'F' => Decoder::Hex.decode_prefix(encoded),
+            b'm' => Decoder::Base64.decode_prefix(encoded),
+            b'u' => Decoder::Base64Url.decode_prefix(encoded),
+            _ => compile_panic!(
+                "Unsupported multibase prefix '", prefix as char => fmt::<char>(), "' in CID"
+            ),
+        };
+
+        let (version, pos) = read_varint(&bytes, 0, len);
+        compile_assert!(
+            version == 1,
+            "Unsupported CID version ", version => fmt::<u64>()
+        );
+        let (codec, pos) = read_varint(&bytes, pos, len);
+        let (hash_code, pos) = read_varint(&bytes, pos, len);
+        let (digest_len, digest_start) = read_varint(&bytes, pos, len);
+        #[allow(clippy::cast_possible_truncation)] // compared to the CID length below
+        let digest_len = digest_len as usize;
+        compile_assert!(
+            digest_start + digest_len == len,
+            "CID multihash declares ", digest_len => fmt::<usize>(), "-byte digest, but has ",
+            len - digest_start => fmt::<usize>(), " bytes"
+        );
+        Self {
+            bytes,
+            version: 1,
+            codec,
+            hash_code,
+            digest_start,
+            digest_len,
+        }
+    }
+}
+
+/// Parsed [IPFS content identifier][CID] (CID), either v0 or v1. `N` is the digest length
+/// in bytes.
+///
+/// CIDv1 can be encoded with base32 (`b` / `B` multibase prefixes), base58btc (`z`),
+/// hex (`f` / `F`), base64 (`m`) or base64url (`u`). Positions in panic messages
+/// related to CIDv1 encodings do not take the multibase prefix into account.
+///
+/// Use the [`decode_cid!`](crate::decode_cid) macro to infer the digest length.
+///
+/// [CID]: https://github.com/multiformats/cid
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::Cid;
+/// const CID: Cid<32> = Cid::parse(b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi");
+/// const CODEC: u64 = CID.codec();
+/// assert_eq!(CID.version(), 1);
+/// assert_eq!(CODEC, 0x70); // dag-pb
+/// assert_eq!(CID.hash_code(), 0x12); // sha2-256
+/// assert_eq!(CID.digest()[..4], [0xc3, 0xc4, 0x73, 0x3e]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cid<const N: usize> {
+    version: u8,
+    codec: u64,
+    hash_code: u64,
+    digest: [u8; N],
+}
+
+impl<const N: usize> Cid<N> {
+    /// Parses a CID from its string presentation.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `input` is not a valid CID, or uses an unsupported multibase encoding.
+    /// - Panics if the digest length is not equal to `N`.
+    pub const fn parse(input: &[u8]) -> Self {
+        let raw = RawCid::parse(input);
+        compile_assert!(
+            raw.digest_len == N,
+            "CID digest has ", raw.digest_len => fmt::<usize>(),
+            " bytes, while type inference implies ", N => fmt::<usize>()
+        );
+        let mut digest = [0_u8; N];
+        let mut i = 0;
+        while i < N {
+            digest[i] = raw.bytes[raw.digest_start + i];
+            i += 1;
+        }
+        Self {
+            version: raw.version,
+            codec: raw.codec,
+            hash_code: raw.hash_code,
+            digest,
+        }
+    }
+
+    /// Returns the digest length of the CID with the specified string presentation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is not a valid CID.
+    pub const fn digest_len(input: &[u8]) -> usize {
+        RawCid::parse(input).digest_len
+    }
+
+    /// Returns the CID version (0 or 1).
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns the multicodec code of the content type, e.g. `0x70` for `dag-pb`
+    /// or `0x55` for `raw`.
+    pub const fn codec(&self) -> u64 {
+        self.codec
+    }
+
+    /// Returns the multicodec code of the hash function, e.g. `0x12` for `sha2-256`.
+    pub const fn hash_code(&self) -> u64 {
+        self.hash_code
+    }
+
+    /// Returns the hash digest.
+    pub const fn digest(&self) -> [u8; N] {
+        self.digest
+    }
+}
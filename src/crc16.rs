@@ -0,0 +1,195 @@
+//! CRC-16 checksum verification.
+
+use crate::{
+    decoder::{copy_to_array, try_check_output_len},
+    error::{DecodeError, DecodeErrorKind},
+    panics::{clip, compile_assert, fmt},
+};
+
+/// CRC-16 algorithm used by [`Crc16Checked`].
+///
+/// For reflected algorithms ([`Self::Kermit`], [`Self::Arc`] and [`Self::Modbus`]), the checksum
+/// is expected to be stored in the little-endian byte order, as is conventional for these
+/// protocols. For other algorithms, the checksum is expected to be stored in the big-endian order.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::Crc16;
+/// assert_eq!(Crc16::Ccitt.checksum(b"123456789"), 0x29b1);
+/// assert_eq!(Crc16::Arc.checksum(b"123456789"), 0xbb3d);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Crc16 {
+    /// CRC-16/CCITT-FALSE (also known as CRC-16/IBM-3740): polynomial `0x1021`,
+    /// initial value `0xffff`, not reflected.
+    Ccitt,
+    /// CRC-16/KERMIT (also known as CRC-16/CCITT-TRUE): polynomial `0x1021`, initial value `0`,
+    /// reflected.
+    Kermit,
+    /// CRC-16/XMODEM: polynomial `0x1021`, initial value `0`, not reflected.
+    Xmodem,
+    /// CRC-16/ARC (also known as CRC-16/IBM): polynomial `0x8005`, initial value `0`, reflected.
+    Arc,
+    /// CRC-16/MODBUS: polynomial `0x8005`, initial value `0xffff`, reflected.
+    Modbus,
+}
+
+impl Crc16 {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Ccitt => "CCITT-FALSE",
+            Self::Kermit => "KERMIT",
+            Self::Xmodem => "XMODEM",
+            Self::Arc => "ARC",
+            Self::Modbus => "MODBUS",
+        }
+    }
+
+    const fn is_reflected(self) -> bool {
+        matches!(self, Self::Kermit | Self::Arc | Self::Modbus)
+    }
+
+    /// Returns the polynomial (bit-reversed for reflected algorithms) and the initial value.
+    const fn params(self) -> (u16, u16) {
+        match self {
+            Self::Ccitt => (0x1021, 0xffff),
+            Self::Kermit => (0x8408, 0),
+            Self::Xmodem => (0x1021, 0),
+            Self::Arc => (0xa001, 0),
+            Self::Modbus => (0xa001, 0xffff),
+        }
+    }
+
+    /// Computes the checksum of `bytes`.
+    pub const fn checksum(self, bytes: &[u8]) -> u16 {
+        self.checksum_of_prefix(bytes, bytes.len())
+    }
+
+    /// Computes the checksum of `bytes[..len]`.
+    pub(crate) const fn checksum_of_prefix(self, bytes: &[u8], len: usize) -> u16 {
+        let (poly, mut crc) = self.params();
+        let reflected = self.is_reflected();
+        let mut i = 0;
+        while i < len {
+            if reflected {
+                crc ^= bytes[i] as u16;
+            } else {
+                crc ^= (bytes[i] as u16) << 8;
+            }
+            let mut bit = 0;
+            while bit < 8 {
+                crc = match (reflected, crc & 1 != 0, crc & 0x8000 != 0) {
+                    (true, true, _) => (crc >> 1) ^ poly,
+                    (true, false, _) => crc >> 1,
+                    (false, _, true) => (crc << 1) ^ poly,
+                    (false, _, false) => crc << 1,
+                };
+                bit += 1;
+            }
+            i += 1;
+        }
+        crc
+    }
+
+    /// Reads the checksum trailer from the last 2 bytes.
+    const fn read_trailer(self, bytes: &[u8]) -> u16 {
+        let len = bytes.len();
+        let trailer = [bytes[len - 2], bytes[len - 1]];
+        if self.is_reflected() {
+            u16::from_le_bytes(trailer)
+        } else {
+            u16::from_be_bytes(trailer)
+        }
+    }
+}
+
+/// Decoder verifying that the input bytes end with a valid CRC-16 checksum of the preceding
+/// bytes, as is common in serial and radio protocol frames. The bytes (including the checksum
+/// trailer) are returned as is.
+///
+/// The input is raw bytes, so the decoder can be nested with other decoders to check
+/// their output, or applied to a raw frame directly.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Crc16, Crc16Checked, Decoder};
+/// // Modbus RTU request: read 1 holding register at address 0 from device 1
+/// const FRAME: &[u8] = &decode!(
+///     Crc16Checked::new(Crc16::Modbus),
+///     &decode!(Decoder::Hex, b"01:03:00:00:00:01:84:0a", skip = custom(b":")),
+/// );
+/// assert_eq!(FRAME.len(), 8);
+/// const RAW_FRAME: [u8; 8] =
+///     Crc16Checked::new(Crc16::Modbus).decode(b"\x01\x03\0\0\0\x01\x84\x0a");
+/// assert_eq!(RAW_FRAME, *FRAME);
+/// ```
+///
+/// Checksum mismatch is caught in compile time:
+///
+/// ```compile_fail
+/// # use const_decoder::{decode, Crc16, Crc16Checked, Decoder};
+/// const FRAME: &[u8] = &decode!(
+///     Crc16Checked::new(Crc16::Modbus),
+///     &decode!(Decoder::Hex, b"01:03:00:00:00:02:84:0a", skip = custom(b":")),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Crc16Checked {
+    algorithm: Crc16,
+}
+
+impl Crc16Checked {
+    /// Creates a decoder expecting the input bytes to end with a checksum computed
+    /// using the specified algorithm.
+    pub const fn new(algorithm: Crc16) -> Self {
+        Self { algorithm }
+    }
+
+    /// Checks the CRC-16 trailer of `input` and copies it into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` does not end with a valid checksum.
+    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        compile_assert!(
+            input.len() >= 2,
+            "Input has ", input.len() => fmt::<usize>(),
+            " bytes, which is insufficient for a CRC-16 trailer"
+        );
+        let crc = self.algorithm.checksum_of_prefix(input, input.len() - 2);
+        let trailer = self.algorithm.read_trailer(input);
+        compile_assert!(
+            crc == trailer,
+            "CRC-16/", self.algorithm.name() => clip(12, ""), " checksum mismatch: computed ",
+            crc => fmt::<u16>(), ", while the trailer contains ", trailer => fmt::<u16>()
+        );
+        copy_to_array(input)
+    }
+
+    /// Checks the CRC-16 trailer of `input` and copies it into a byte array, returning
+    /// an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an [`InvalidLength`](DecodeErrorKind::InvalidLength) error if `input`
+    ///   has a length other than `N`.
+    /// - Returns a [`ChecksumMismatch`](DecodeErrorKind::ChecksumMismatch) error if `input`
+    ///   does not end with a valid checksum.
+    pub const fn try_decode<const N: usize>(self, input: &[u8]) -> Result<[u8; N], DecodeError> {
+        const_try!(try_check_output_len(input, input.len(), N));
+        let len = input.len();
+        if len >= 2
+            && self.algorithm.checksum_of_prefix(input, len - 2)
+                == self.algorithm.read_trailer(input)
+        {
+            Ok(copy_to_array(input))
+        } else {
+            let kind = DecodeErrorKind::ChecksumMismatch;
+            Err(DecodeError::new(kind, len))
+        }
+    }
+}
@@ -1,36 +1,56 @@
 //! `Decoder` and closely related types.
 
-use compile_fmt::{clip_ascii, compile_assert, compile_panic, fmt, Ascii};
-
-use crate::wrappers::{SkipWhitespace, Skipper};
-
-// Since `?` is not allowed in `const fn`s, we use its simplified version.
-macro_rules! const_try {
-    ($result:expr) => {
-        match $result {
-            Ok(value) => value,
-            Err(err) => return Err(err),
-        }
-    };
-}
+use crate::{
+    error::{DecodeError, DecodeErrorKind},
+    panics::{clip, clip_ascii, compile_assert, compile_panic, fmt, Ascii},
+    wrappers::{RejectTrailingData, SkipChars, SkipLineContinuations, SkipWhitespace, Skipper},
+};
 
 #[derive(Debug)]
-struct DecodeError {
+struct CharError {
     invalid_char: u8,
     // `None` for hex encoding
     alphabet: Option<Ascii<'static>>,
+    // Set if the char is a valid hex digit violating the case policy
+    violated_case: Option<HexCase>,
 }
 
-impl DecodeError {
+impl CharError {
     const fn invalid_char(invalid_char: u8, alphabet: Option<Ascii<'static>>) -> Self {
         Self {
             invalid_char,
             alphabet,
+            violated_case: None,
+        }
+    }
+
+    const fn invalid_case(invalid_char: u8, case: HexCase) -> Self {
+        Self {
+            invalid_char,
+            alphabet: None,
+            violated_case: Some(case),
+        }
+    }
+
+    const fn kind(&self) -> DecodeErrorKind {
+        DecodeErrorKind::InvalidSymbol {
+            symbol: self.invalid_char,
         }
     }
 
     const fn panic(self, input_pos: usize) -> ! {
-        if self.invalid_char.is_ascii() {
+        if let Some(case) = self.violated_case {
+            let policy = match case {
+                HexCase::Lower => "lowercase",
+                HexCase::Upper => "uppercase",
+                HexCase::Uniform => "uniform case",
+            };
+            compile_panic!(
+                "Hex digit '", self.invalid_char as char => fmt::<char>(), "' at position ",
+                input_pos => fmt::<usize>(), " violates the ", policy => clip(16, ""),
+                " policy"
+            );
+        } else if self.invalid_char.is_ascii() {
             if let Some(alphabet) = self.alphabet {
                 compile_panic!(
                     "Character '", self.invalid_char as char => fmt::<char>(), "' at position ",
@@ -52,9 +72,79 @@ impl DecodeError {
     }
 }
 
+/// Checks that the actual decoded length matches the output buffer length `N`.
+pub(crate) const fn check_output_len(out_len: usize, n: usize) {
+    compile_assert!(
+        out_len <= n,
+        "Output overflow: the input decodes to ", out_len => fmt::<usize>(),
+        " bytes, while type inference implies ",  n => fmt::<usize>(), ". \
+        Either fix the input or change the output buffer length correspondingly"
+    );
+    compile_assert!(
+        out_len == n,
+        "Output underflow: the input decodes to ", out_len => fmt::<usize>(),
+        " bytes, while type inference implies ", n => fmt::<usize>(), ". \
+        Either fix the input or change the output buffer length correspondingly"
+    );
+}
+
+/// Fallible version of [`check_output_len()`]. The error is reported at the end of `input`.
+pub(crate) const fn try_check_output_len(
+    input: &[u8],
+    out_len: usize,
+    n: usize,
+) -> Result<(), DecodeError> {
+    if out_len == n {
+        Ok(())
+    } else {
+        let kind = DecodeErrorKind::InvalidLength {
+            decoded_len: out_len,
+            expected_len: n,
+        };
+        Err(DecodeError::new(kind, input.len()))
+    }
+}
+
+/// Copies `input` into a byte array, checking that the lengths match.
+pub(crate) const fn copy_to_array<const N: usize>(input: &[u8]) -> [u8; N] {
+    check_output_len(input.len(), N);
+    let mut bytes = [0_u8; N];
+    let mut i = 0;
+    while i < N {
+        bytes[i] = input[i];
+        i += 1;
+    }
+    bytes
+}
+
+/// Checks that a fixed-length input has the expected length in chars. A shorter input
+/// is reported as incomplete; a longer one as having trailing data.
+pub(crate) const fn try_check_input_len(
+    input: &[u8],
+    expected_len: usize,
+) -> Result<(), DecodeError> {
+    if input.len() < expected_len {
+        Err(DecodeError::new(
+            DecodeErrorKind::IncompleteInput,
+            input.len(),
+        ))
+    } else if input.len() > expected_len {
+        Err(DecodeError::new(
+            DecodeErrorKind::TrailingData,
+            expected_len,
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 /// Custom encoding scheme based on a certain alphabet (mapping between a subset of ASCII chars
 /// and digits in `0..P`, where `P` is a power of 2).
 ///
+/// Each char encodes from 1 to 7 bits, depending on the alphabet length. For example, base4
+/// and base8 encodings correspond to alphabets with 4 and 8 chars respectively, and a 128-char
+/// alphabet covering all ASCII chars can be used to decode 7-bit packed data.
+///
 /// # Examples
 ///
 /// ```
@@ -68,11 +158,14 @@ impl DecodeError {
 /// const SAMPLE_ADDR: [u8; 32] =
 ///     BECH32.decode(b"rp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q");
 /// ```
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Encoding {
     alphabet: Ascii<'static>,
+    alphabet_bytes: &'static [u8],
     table: [u8; 128],
     bits_per_char: u8,
+    lsb_first: bool,
+    skip_padding: bool,
 }
 
 impl Encoding {
@@ -82,6 +175,7 @@ impl Encoding {
         Self::new("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/");
     const BASE64_URL: Self =
         Self::new("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_");
+    const BASE64_ANY: Self = Self::BASE64.with_alias(b'-', 62).with_alias(b'_', 63);
 
     /// Creates an encoding based on the provided `alphabet`: a sequence of ASCII chars
     /// that correspond to digits 0, 1, 2, etc.
@@ -89,7 +183,7 @@ impl Encoding {
     /// # Panics
     ///
     /// - Panics if `alphabet` does not consist of distinct ASCII chars.
-    /// - Panics if `alphabet` length is not 2, 4, 8, 16, 32 or 64.
+    /// - Panics if `alphabet` length is not 2, 4, 8, 16, 32, 64 or 128.
     #[allow(clippy::cast_possible_truncation)]
     pub const fn new(alphabet: &'static str) -> Self {
         let bits_per_char = match alphabet.len() {
@@ -99,9 +193,10 @@ impl Encoding {
             16 => 4,
             32 => 5,
             64 => 6,
+            128 => 7,
             other => compile_panic!(
                 "Invalid alphabet length ", other => fmt::<usize>(),
-                "; must be one of 2, 4, 8, 16, 32, or 64"
+                "; must be one of 2, 4, 8, 16, 32, 64, or 128"
             ),
         };
 
@@ -122,57 +217,348 @@ impl Encoding {
 
         Self {
             alphabet,
+            alphabet_bytes,
             table,
             bits_per_char,
+            lsb_first: false,
+            skip_padding: false,
         }
     }
 
-    const fn lookup(&self, ascii_char: u8) -> Result<u8, DecodeError> {
+    /// Makes this encoding pack bits starting from the least significant bit of each byte,
+    /// rather than from the most significant one. This bit order is used, e.g., in the base32
+    /// encoding from [DNSCurve].
+    ///
+    /// [DNSCurve]: https://dnscurve.org/in-implement.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::{Decoder, Encoding};
+    /// const DNSCURVE: Decoder =
+    ///     Decoder::Custom(Encoding::new("0123456789bcdfghjklmnpqrstuvwxyz").lsb_first());
+    /// assert_eq!(DNSCURVE.decode::<2>(b"4321"), [0x64, 0x88]);
+    /// ```
+    #[must_use]
+    pub const fn lsb_first(mut self) -> Self {
+        self.lsb_first = true;
+        self
+    }
+
+    /// Makes this encoding accept ASCII letters in either case. Each letter in the alphabet
+    /// gets its counterpart in the other case mapped to the same digit. Encoding still uses
+    /// the original alphabet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the alphabet contains the same letter in both cases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::{Decoder, Encoding};
+    /// const BASE32: Decoder =
+    ///     Decoder::Custom(Encoding::new("ABCDEFGHIJKLMNOPQRSTUVWXYZ234567").case_insensitive());
+    /// assert_eq!(BASE32.decode::<5>(b"MZxw6YtB"), *b"fooba");
+    /// ```
+    #[must_use]
+    pub const fn case_insensitive(mut self) -> Self {
+        let mut index = 0;
+        while index < self.alphabet_bytes.len() {
+            let byte = self.alphabet_bytes[index];
+            let other_case = if byte.is_ascii_lowercase() {
+                byte.to_ascii_uppercase()
+            } else {
+                byte.to_ascii_lowercase()
+            };
+            if other_case != byte {
+                let digit = self.table[byte as usize];
+                let other_digit = self.table[other_case as usize];
+                compile_assert!(
+                    other_digit == Self::NO_MAPPING || other_digit == digit,
+                    "Alphabet contains both '", byte as char => fmt::<char>(), "' and '",
+                    other_case as char => fmt::<char>(), "'; it cannot be made case-insensitive"
+                );
+                self.table[other_case as usize] = digit;
+            }
+            index += 1;
+        }
+        self
+    }
+
+    /// Makes this encoding ignore padding chars (`=`) during decoding, similarly
+    /// to [`Decoder::Base64`]. Encoding does not output padding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the alphabet contains `=`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::{Decoder, Encoding};
+    /// const BASE32: Decoder =
+    ///     Decoder::Custom(Encoding::new("ABCDEFGHIJKLMNOPQRSTUVWXYZ234567").allow_padding());
+    /// assert_eq!(BASE32.decode::<6>(b"JBSWY3DPEE======"), *b"Hello!");
+    /// ```
+    #[must_use]
+    pub const fn allow_padding(mut self) -> Self {
+        compile_assert!(
+            self.table[b'=' as usize] == Self::NO_MAPPING,
+            "Alphabet contains padding char '='"
+        );
+        self.skip_padding = true;
+        self
+    }
+
+    /// Makes this encoding fold ASCII letters in the input to the specified case before
+    /// decoding. Unlike [`Self::case_insensitive()`], this works for alphabets containing
+    /// the same letter in both cases; the letter in the other case is then ignored.
+    /// Encoding still uses the original alphabet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::{AsciiCase, Decoder, Encoding};
+    /// const BASE32: Decoder = Decoder::Custom(
+    ///     Encoding::new("abcdefghijklmnopqrstuvwxyz234567").fold_case(AsciiCase::Lower),
+    /// );
+    /// assert_eq!(BASE32.decode::<5>(b"MZxw6YtB"), *b"fooba");
+    /// ```
+    #[must_use]
+    pub const fn fold_case(mut self, case: AsciiCase) -> Self {
+        let mut byte = 0_u8;
+        while byte < 128 {
+            let folded = case.apply(byte);
+            if folded != byte {
+                self.table[byte as usize] = self.table[folded as usize];
+            }
+            byte += 1;
+        }
+        self
+    }
+
+    /// Maps an additional char to the specified digit.
+    const fn with_alias(mut self, ascii_char: u8, digit: u8) -> Self {
+        self.table[ascii_char as usize] = digit;
+        self
+    }
+
+    const fn lookup(&self, ascii_char: u8) -> Result<u8, CharError> {
         if !ascii_char.is_ascii() {
-            return Err(DecodeError::invalid_char(ascii_char, Some(self.alphabet)));
+            return Err(CharError::invalid_char(ascii_char, Some(self.alphabet)));
         }
         let mapping = self.table[ascii_char as usize];
         if mapping == Self::NO_MAPPING {
-            Err(DecodeError::invalid_char(ascii_char, Some(self.alphabet)))
+            Err(CharError::invalid_char(ascii_char, Some(self.alphabet)))
         } else {
             Ok(mapping)
         }
     }
 }
 
+/// ASCII letter case, used in [`Decoder::fold_case()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AsciiCase {
+    /// Lowercase letters.
+    Lower,
+    /// Uppercase letters.
+    Upper,
+}
+
+impl AsciiCase {
+    const fn apply(self, byte: u8) -> u8 {
+        match self {
+            Self::Lower => byte.to_ascii_lowercase(),
+            Self::Upper => byte.to_ascii_uppercase(),
+        }
+    }
+}
+
+/// Case policy for hex digits, used in [`HexOptions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexCase {
+    /// Only lowercase digits (`a`–`f`) are allowed.
+    Lower,
+    /// Only uppercase digits (`A`–`F`) are allowed.
+    Upper,
+    /// Digits may be either lowercase or uppercase, but not both in the same input.
+    Uniform,
+}
+
+/// Options for the hexadecimal decoder, used in [`Decoder::CustomHex`].
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{Decoder, HexCase};
+/// const DIGEST: [u8; 4] = Decoder::Hex.with_case(HexCase::Lower).decode(b"c0ffee00");
+/// ```
+///
+/// ```compile_fail
+/// # use const_decoder::{Decoder, HexCase};
+/// // Mixed case is rejected
+/// const DIGEST: [u8; 4] = Decoder::Hex.with_case(HexCase::Uniform).decode(b"C0FFee00");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HexOptions {
+    case: Option<HexCase>,
+    swap_nibbles: bool,
+    allow_underscores: bool,
+}
+
+impl HexOptions {
+    const DEFAULT: Self = Self {
+        case: None,
+        swap_nibbles: false,
+        allow_underscores: false,
+    };
+}
+
+/// Maps ASCII hex digits to their values; other bytes are mapped to `0xff`.
+const HEX_DIGITS: [u8; 256] = {
+    let mut table = [0xff_u8; 256];
+    let mut i = 0_u8;
+    while i < 16 {
+        table[b"0123456789abcdef"[i as usize] as usize] = i;
+        table[b"0123456789ABCDEF"[i as usize] as usize] = i;
+        i += 1;
+    }
+    table
+};
+
+/// Fast path for [`Decoder::Hex`] without skipped chars. Decodes `input` (truncated to `N`
+/// bytes), returning `None` if `input` is invalid, so that errors are reported by the generic
+/// decoding logic.
+///
+/// The loop is unrolled to process 16 chars per iteration, which significantly reduces
+/// the number of const evaluation steps for large inputs.
+const fn decode_hex_fast<const N: usize>(input: &[u8]) -> Option<([u8; N], usize)> {
+    if input.len() % 2 != 0 {
+        return None;
+    }
+
+    let mut bytes = [0_u8; N];
+    let mut in_index = 0;
+    while in_index + 16 <= input.len() {
+        let mut digits = [0_u8; 16];
+        let mut all_digits = 0;
+        let mut i = 0;
+        while i < 16 {
+            digits[i] = HEX_DIGITS[input[in_index + i] as usize];
+            all_digits |= digits[i];
+            i += 1;
+        }
+        if all_digits > 0xf {
+            return None;
+        }
+
+        let out_index = in_index / 2;
+        if out_index + 8 <= N {
+            bytes[out_index] = (digits[0] << 4) | digits[1];
+            bytes[out_index + 1] = (digits[2] << 4) | digits[3];
+            bytes[out_index + 2] = (digits[4] << 4) | digits[5];
+            bytes[out_index + 3] = (digits[6] << 4) | digits[7];
+            bytes[out_index + 4] = (digits[8] << 4) | digits[9];
+            bytes[out_index + 5] = (digits[10] << 4) | digits[11];
+            bytes[out_index + 6] = (digits[12] << 4) | digits[13];
+            bytes[out_index + 7] = (digits[14] << 4) | digits[15];
+        } else {
+            i = 0;
+            while i < 8 && out_index + i < N {
+                bytes[out_index + i] = (digits[2 * i] << 4) | digits[2 * i + 1];
+                i += 1;
+            }
+        }
+        in_index += 16;
+    }
+
+    while in_index < input.len() {
+        let hi = HEX_DIGITS[input[in_index] as usize];
+        let lo = HEX_DIGITS[input[in_index + 1] as usize];
+        if (hi | lo) > 0xf {
+            return None;
+        }
+        if in_index / 2 < N {
+            bytes[in_index / 2] = (hi << 4) | lo;
+        }
+        in_index += 2;
+    }
+    Some((bytes, input.len() / 2))
+}
+
 /// Internal state of the hexadecimal decoder.
 #[derive(Debug, Clone, Copy)]
-struct HexDecoderState(Option<u8>);
+struct HexDecoderState {
+    options: HexOptions,
+    partial_byte: Option<u8>,
+    // `Some(true)` if an uppercase digit has been encountered, `Some(false)` for lowercase
+    seen_uppercase: Option<bool>,
+}
 
 impl HexDecoderState {
-    const fn byte_value(val: u8) -> Result<u8, DecodeError> {
+    /// Returns the digit value and, for letters, whether the digit is uppercase.
+    const fn byte_value(val: u8) -> Result<(u8, Option<bool>), CharError> {
         Ok(match val {
-            b'0'..=b'9' => val - b'0',
-            b'A'..=b'F' => val - b'A' + 10,
-            b'a'..=b'f' => val - b'a' + 10,
-            _ => return Err(DecodeError::invalid_char(val, None)),
+            b'0'..=b'9' => (val - b'0', None),
+            b'A'..=b'F' => (val - b'A' + 10, Some(true)),
+            b'a'..=b'f' => (val - b'a' + 10, Some(false)),
+            _ => return Err(CharError::invalid_char(val, None)),
         })
     }
 
-    const fn new() -> Self {
-        Self(None)
+    const fn new(options: HexOptions) -> Self {
+        Self {
+            options,
+            partial_byte: None,
+            seen_uppercase: None,
+        }
+    }
+
+    const fn check_case(mut self, val: u8, is_uppercase: bool) -> Result<Self, CharError> {
+        let is_valid = match self.options.case {
+            None => true,
+            Some(HexCase::Lower) => !is_uppercase,
+            Some(HexCase::Upper) => is_uppercase,
+            Some(HexCase::Uniform) => match self.seen_uppercase {
+                None => true,
+                Some(seen_uppercase) => seen_uppercase == is_uppercase,
+            },
+        };
+        if let Some(case) = self.options.case {
+            if !is_valid {
+                return Err(CharError::invalid_case(val, case));
+            }
+        }
+        self.seen_uppercase = Some(is_uppercase);
+        Ok(self)
     }
 
     #[allow(clippy::option_if_let_else)] // `Option::map_or_else` cannot be used in const fns
-    const fn update(mut self, byte: u8) -> Result<(Self, Option<u8>), DecodeError> {
-        let byte = const_try!(Self::byte_value(byte));
-        let output = if let Some(b) = self.0 {
-            self.0 = None;
-            Some((b << 4) + byte)
+    const fn update(mut self, val: u8) -> Result<(Self, Option<u8>), CharError> {
+        if val == b'_' && self.options.allow_underscores {
+            return Ok((self, None));
+        }
+        let (byte, is_uppercase) = const_try!(Self::byte_value(val));
+        if let Some(is_uppercase) = is_uppercase {
+            self = const_try!(self.check_case(val, is_uppercase));
+        }
+        let output = if let Some(b) = self.partial_byte {
+            self.partial_byte = None;
+            Some(if self.options.swap_nibbles {
+                (byte << 4) + b
+            } else {
+                (b << 4) + byte
+            })
         } else {
-            self.0 = Some(byte);
+            self.partial_byte = Some(byte);
             None
         };
         Ok((self, output))
     }
 
     const fn is_final(self) -> bool {
-        self.0.is_none()
+        self.partial_byte.is_none()
     }
 }
 
@@ -194,8 +580,12 @@ impl CustomDecoderState {
     }
 
     #[allow(clippy::comparison_chain)] // not feasible in const context
-    const fn update(mut self, byte: u8) -> Result<(Self, Option<u8>), DecodeError> {
+    const fn update(mut self, byte: u8) -> Result<(Self, Option<u8>), CharError> {
         let byte = const_try!(self.table.lookup(byte));
+        if self.table.lsb_first {
+            return Ok(self.update_lsb_first(byte));
+        }
+
         let output = if self.filled_bits < 8 - self.table.bits_per_char {
             self.partial_byte = (self.partial_byte << self.table.bits_per_char) + byte;
             self.filled_bits += self.table.bits_per_char;
@@ -216,6 +606,21 @@ impl CustomDecoderState {
         Ok((self, output))
     }
 
+    #[allow(clippy::cast_possible_truncation)] // intentional
+    const fn update_lsb_first(mut self, byte: u8) -> (Self, Option<u8>) {
+        let acc = self.partial_byte as u16 + ((byte as u16) << self.filled_bits);
+        self.filled_bits += self.table.bits_per_char;
+        let output = if self.filled_bits >= 8 {
+            self.filled_bits -= 8;
+            self.partial_byte = (acc >> 8) as u8;
+            Some(acc as u8)
+        } else {
+            self.partial_byte = acc as u8;
+            None
+        };
+        (self, output)
+    }
+
     const fn is_final(&self) -> bool {
         // We don't check `self.filled_bits` because padding may be implicit
         self.partial_byte == 0
@@ -231,7 +636,7 @@ enum DecoderState {
 }
 
 impl DecoderState {
-    const fn update(self, byte: u8) -> Result<(Self, Option<u8>), DecodeError> {
+    const fn update(self, byte: u8) -> Result<(Self, Option<u8>), CharError> {
         Ok(match self {
             Self::Hex(state) => {
                 let (updated_state, output) = const_try!(state.update(byte));
@@ -246,8 +651,12 @@ impl DecoderState {
                 }
             }
             Self::Custom(state) => {
-                let (updated_state, output) = const_try!(state.update(byte));
-                (Self::Custom(updated_state), output)
+                if byte == b'=' && state.table.skip_padding {
+                    (self, None)
+                } else {
+                    let (updated_state, output) = const_try!(state.update(byte));
+                    (Self::Custom(updated_state), output)
+                }
             }
         })
     }
@@ -265,7 +674,7 @@ impl DecoderState {
 /// # Examples
 ///
 /// See the [crate docs](index.html) for examples of usage.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub enum Decoder {
     /// Hexadecimal decoder. Supports uppercase and lowercase digits.
@@ -280,11 +689,43 @@ pub enum Decoder {
     ///
     /// [RFC 3548]: https://datatracker.ietf.org/doc/html/rfc3548.html
     Base64Url,
+    /// Base64 decoder accepting both standard and URL / filesystem-safe encodings,
+    /// i.e., both `+` and `-` for digit 62, and both `/` and `_` for digit 63. The chars
+    /// may be mixed within the same input. Does not require padding, but works fine with it.
+    /// Encoding uses the standard alphabet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::Decoder;
+    /// const STANDARD: [u8; 6] = Decoder::Base64Any.decode(b"Pj4+Pz8/");
+    /// const URL_SAFE: [u8; 6] = Decoder::Base64Any.decode(b"Pj4-Pz8_");
+    /// assert_eq!(STANDARD, *b">>>???");
+    /// assert_eq!(URL_SAFE, STANDARD);
+    /// ```
+    Base64Any,
     /// Decoder based on a custom [`Encoding`].
     Custom(Encoding),
+    /// Hexadecimal decoder with custom [`HexOptions`].
+    CustomHex(HexOptions),
 }
 
 impl Decoder {
+    /// Modified hex ([ModHex]) decoder used in one-time passwords generated by Yubico security keys.
+    /// The encoding maps hex digits 0 to f to the `cbdefghijklnrtuv` chars, which are located
+    /// at the same keys on most keyboard layouts. Decoding is case-insensitive.
+    ///
+    /// [ModHex]: https://developers.yubico.com/yubico-c/Manuals/modhex.1.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::Decoder;
+    /// const BYTES: [u8; 4] = Decoder::MODHEX.decode(b"rcvvuucc");
+    /// assert_eq!(BYTES, [0xc0, 0xff, 0xee, 0x00]);
+    /// ```
+    pub const MODHEX: Self = Self::Custom(Encoding::new("cbdefghijklnrtuv").case_insensitive());
+
     /// Creates a new decoder with a custom alphabet.
     ///
     /// # Panics
@@ -294,16 +735,225 @@ impl Decoder {
         Self::Custom(Encoding::new(alphabet))
     }
 
+    /// Sets the case policy for a hex decoder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this is not a hex decoder.
+    #[must_use]
+    pub const fn with_case(self, case: HexCase) -> Self {
+        let mut options = self.hex_options();
+        options.case = Some(case);
+        Self::CustomHex(options)
+    }
+
+    /// Makes a hex decoder use the swapped nibble order, in which the low nibble of each byte
+    /// comes first. This order is used, e.g., in GSM / SIM card data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this is not a hex decoder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::Decoder;
+    /// // ICCID (SIM card number) as stored on the card
+    /// const ICCID: [u8; 4] = Decoder::Hex.swap_nibbles().decode(b"98103254");
+    /// assert_eq!(ICCID, [0x89, 0x01, 0x23, 0x45]);
+    /// ```
+    #[must_use]
+    pub const fn swap_nibbles(self) -> Self {
+        let mut options = self.hex_options();
+        options.swap_nibbles = true;
+        Self::CustomHex(options)
+    }
+
+    /// Makes a hex decoder skip underscores, similarly to Rust numeric literals
+    /// (e.g., `dead_beef_0123`). Underscores may occur anywhere in the input.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this is not a hex decoder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::{decode, Decoder};
+    /// const BYTES: &[u8] = &decode!(Decoder::Hex.allow_underscores(), b"dead_beef_0123");
+    /// assert_eq!(BYTES, [0xde, 0xad, 0xbe, 0xef, 0x01, 0x23]);
+    /// ```
+    #[must_use]
+    pub const fn allow_underscores(self) -> Self {
+        let mut options = self.hex_options();
+        options.allow_underscores = true;
+        Self::CustomHex(options)
+    }
+
+    /// Makes this decoder fold ASCII letters in the input to the specified case before decoding.
+    /// This is useful for decoders with a single-case alphabet, such as base32 or lowercase-only
+    /// hex, with real-world inputs arriving in mixed case.
+    ///
+    /// - For custom decoders, this is equivalent to [`Encoding::fold_case()`].
+    /// - For hex decoders, this removes the [`HexCase`] policy (if any), since after folding,
+    ///   all digits have the same case.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if this is a base64 decoder, since folding would corrupt the input.
+    /// - Panics if this is a hex decoder with a case policy contradicting `case`,
+    ///   e.g. [`HexCase::Lower`] and [`AsciiCase::Upper`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::{decode, AsciiCase, Decoder, HexCase};
+    /// const DIGEST: &[u8] = &decode!(
+    ///     Decoder::Hex.with_case(HexCase::Lower).fold_case(AsciiCase::Lower),
+    ///     b"C0FFee00",
+    /// );
+    /// assert_eq!(DIGEST, [0xc0, 0xff, 0xee, 0]);
+    /// ```
+    #[must_use]
+    pub const fn fold_case(self, case: AsciiCase) -> Self {
+        match self {
+            Self::Hex => self,
+            Self::CustomHex(mut options) => {
+                let is_contradicting = matches!(
+                    (options.case, case),
+                    (Some(HexCase::Lower), AsciiCase::Upper)
+                        | (Some(HexCase::Upper), AsciiCase::Lower)
+                );
+                assert!(
+                    !is_contradicting,
+                    "Folding case contradicts the case policy of the hex decoder"
+                );
+                options.case = None;
+                Self::CustomHex(options)
+            }
+            Self::Custom(encoding) => Self::Custom(encoding.fold_case(case)),
+            Self::Base64 | Self::Base64Url | Self::Base64Any => {
+                panic!("Case folding is not supported for base64 decoders")
+            }
+        }
+    }
+
+    const fn hex_options(self) -> HexOptions {
+        match self {
+            Self::Hex => HexOptions::DEFAULT,
+            Self::CustomHex(options) => options,
+            _ => panic!("Hex options can only be set for hex decoders"),
+        }
+    }
+
     /// Makes this decoder skip whitespace chars rather than panicking on encountering them.
     pub const fn skip_whitespace(self) -> SkipWhitespace {
         SkipWhitespace(self)
     }
 
+    /// Makes this decoder skip the specified chars rather than panicking on encountering them.
+    /// See [`SkipChars`] for details.
+    pub const fn skip_chars(self, chars: &'static [u8]) -> SkipChars {
+        SkipChars(self, chars)
+    }
+
+    /// Makes this decoder skip backslash-newline sequences rather than panicking on encountering
+    /// them. See [`SkipLineContinuations`] for details.
+    pub const fn skip_line_continuations(self) -> SkipLineContinuations {
+        SkipLineContinuations(self)
+    }
+
+    /// Returns the number of bits encoded by a single input char.
+    const fn bits_per_char(self) -> usize {
+        match self {
+            Self::Hex | Self::CustomHex(_) => 4,
+            Self::Base64 | Self::Base64Url | Self::Base64Any => 6,
+            Self::Custom(encoding) => encoding.bits_per_char as usize,
+        }
+    }
+
+    /// Returns the number of chars in a group decoded to whole bytes, if the decoder state
+    /// is reset after each group, so that inputs split at group boundaries can be decoded
+    /// independently (provided that the parts do not contain skipped padding chars).
+    #[cfg(feature = "rayon")]
+    pub(crate) const fn independent_group_len(self) -> Option<usize> {
+        if let Self::CustomHex(options) = self {
+            // Uniform case is checked across the entire input, and skipped underscores
+            // break group alignment.
+            if matches!(options.case, Some(HexCase::Uniform)) || options.allow_underscores {
+                return None;
+            }
+        }
+        let bits_per_char = self.bits_per_char();
+        let mut group_bits = bits_per_char;
+        while group_bits % 8 != 0 {
+            group_bits += bits_per_char;
+        }
+        Some(group_bits / bits_per_char)
+    }
+
+    /// Returns the length of the canonical encoding of `input_len` bytes. For base64 decoders
+    /// (e.g., [`Self::Base64`]), the length includes padding; custom encodings are not padded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::Decoder;
+    /// assert_eq!(Decoder::Hex.encoded_len(32), 64);
+    /// assert_eq!(Decoder::Base64.encoded_len(32), 44);
+    /// assert_eq!(Decoder::custom("01234567").encoded_len(2), 6);
+    /// ```
+    pub const fn encoded_len(self, input_len: usize) -> usize {
+        match self {
+            Self::Base64 | Self::Base64Url | Self::Base64Any => (input_len + 2) / 3 * 4,
+            _ => {
+                let bits_per_char = self.bits_per_char();
+                (input_len * 8 + bits_per_char - 1) / bits_per_char
+            }
+        }
+    }
+
+    /// Returns the maximum number of bytes that an input with the specified length
+    /// can decode to. The actual decoded length may be smaller, e.g. because of padding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::Decoder;
+    /// assert_eq!(Decoder::Hex.max_decoded_len(64), 32);
+    /// assert_eq!(Decoder::Base64.max_decoded_len(44), 33);
+    /// ```
+    pub const fn max_decoded_len(self, encoded_len: usize) -> usize {
+        encoded_len * self.bits_per_char() / 8
+    }
+
+    /// Checks whether `input` can be decoded, without materializing the output. Unlike
+    /// [`Self::decode()`], this method does not panic on invalid input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::Decoder;
+    /// const IS_VALID: bool = Decoder::Hex.is_valid(b"c0ffee");
+    /// assert!(IS_VALID);
+    /// assert!(!Decoder::Hex.is_valid(b"c0ffe")); // odd number of digits
+    /// assert!(!Decoder::Base64.is_valid(b"Pj4-Pz8_")); // invalid chars
+    /// ```
+    pub const fn is_valid(self, input: &[u8]) -> bool {
+        self.do_is_valid(input, None)
+    }
+
+    pub(crate) const fn do_is_valid(self, input: &[u8], skipper: Option<Skipper>) -> bool {
+        self.try_decode_inner::<0>(input, skipper, false).is_ok()
+    }
+
     const fn new_state(self) -> DecoderState {
         match self {
-            Self::Hex => DecoderState::Hex(HexDecoderState::new()),
+            Self::Hex => DecoderState::Hex(HexDecoderState::new(HexOptions::DEFAULT)),
+            Self::CustomHex(options) => DecoderState::Hex(HexDecoderState::new(options)),
             Self::Base64 => DecoderState::Base64(CustomDecoderState::new(Encoding::BASE64)),
             Self::Base64Url => DecoderState::Base64(CustomDecoderState::new(Encoding::BASE64_URL)),
+            Self::Base64Any => DecoderState::Base64(CustomDecoderState::new(Encoding::BASE64_ANY)),
             Self::Custom(encoding) => DecoderState::Custom(CustomDecoderState::new(encoding)),
         }
     }
@@ -315,87 +965,446 @@ impl Decoder {
     /// - Panics if the provided length is insufficient or too large for `input`.
     /// - Panics if `input` contains invalid chars.
     pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
-        self.do_decode(input, None)
+        self.do_decode(input, None, false)
+    }
+
+    /// Makes this decoder panic on encountering data after base64 padding, rather than
+    /// treating padding chars as optional separators.
+    pub const fn reject_trailing_data(self) -> RejectTrailingData<Self> {
+        RejectTrailingData(self)
+    }
+
+    /// Encodes `input` into a byte array using the canonical form of this encoding:
+    /// lowercase hex digits (uppercase if required by [`HexCase::Upper`]), base64 with padding,
+    /// custom encodings without padding.
+    /// The output length can be computed using [`Self::encoded_len()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the provided length does not match the encoded length of `input`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::Decoder;
+    /// const HEX: [u8; 6] = Decoder::Hex.encode(&[0xc0, 0xff, 0xee]);
+    /// assert_eq!(HEX, *b"c0ffee");
+    /// const BASE64: [u8; 8] = Decoder::Base64.encode(b"test");
+    /// assert_eq!(BASE64, *b"dGVzdA==");
+    /// ```
+    #[allow(clippy::cast_possible_truncation)] // bit masks ensure that digits fit into a byte
+    pub const fn encode<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        let expected_len = self.encoded_len(input.len());
+        compile_assert!(
+            expected_len == N,
+            "Output length mismatch: the input encodes to ", expected_len => fmt::<usize>(),
+            " chars, while type inference implies ", N => fmt::<usize>(), ". \
+            Either fix the input or change the output buffer length correspondingly"
+        );
+
+        if let Self::Custom(encoding) = self {
+            if encoding.lsb_first {
+                return Self::encode_lsb_first(encoding, input);
+            }
+        }
+
+        let alphabet = match self {
+            Self::CustomHex(HexOptions {
+                case: Some(HexCase::Upper),
+                ..
+            }) => b"0123456789ABCDEF",
+            Self::Hex | Self::CustomHex(_) => b"0123456789abcdef",
+            Self::Base64 | Self::Base64Any => Encoding::BASE64.alphabet_bytes,
+            Self::Base64Url => Encoding::BASE64_URL.alphabet_bytes,
+            Self::Custom(encoding) => encoding.alphabet_bytes,
+        };
+        let bits_per_char = self.bits_per_char();
+        let mask = (1_u16 << bits_per_char) - 1;
+
+        let mut chars = [b'='; N];
+        let mut acc = 0_u16;
+        let mut filled_bits = 0;
+        let mut in_index = 0;
+        let mut out_index = 0;
+        while in_index < input.len() {
+            acc = ((acc << 8) + input[in_index] as u16) & 0x_3fff;
+            filled_bits += 8;
+            while filled_bits >= bits_per_char {
+                filled_bits -= bits_per_char;
+                chars[out_index] = alphabet[((acc >> filled_bits) & mask) as usize];
+                out_index += 1;
+            }
+            in_index += 1;
+        }
+        if filled_bits > 0 {
+            chars[out_index] = alphabet[((acc << (bits_per_char - filled_bits)) & mask) as usize];
+        }
+        // The remaining chars (if any) are base64 padding.
+
+        if let Self::CustomHex(HexOptions {
+            swap_nibbles: true, ..
+        }) = self
+        {
+            let mut i = 0;
+            while i < N {
+                (chars[i], chars[i + 1]) = (chars[i + 1], chars[i]);
+                i += 2;
+            }
+        }
+        chars
+    }
+
+    #[allow(clippy::cast_possible_truncation)] // bit masks ensure that digits fit into a byte
+    const fn encode_lsb_first<const N: usize>(encoding: Encoding, input: &[u8]) -> [u8; N] {
+        let bits_per_char = encoding.bits_per_char;
+        let mask = (1_u16 << bits_per_char) - 1;
+        let mut chars = [0_u8; N];
+        let mut acc = 0_u16;
+        let mut filled_bits = 0;
+        let mut in_index = 0;
+        let mut out_index = 0;
+        while in_index < input.len() {
+            acc += (input[in_index] as u16) << filled_bits;
+            filled_bits += 8;
+            while filled_bits >= bits_per_char {
+                chars[out_index] = encoding.alphabet_bytes[(acc & mask) as usize];
+                acc >>= bits_per_char;
+                filled_bits -= bits_per_char;
+                out_index += 1;
+            }
+            in_index += 1;
+        }
+        if filled_bits > 0 {
+            chars[out_index] = encoding.alphabet_bytes[(acc & mask) as usize];
+        }
+        chars
+    }
+
+    /// Decodes `input` into a byte array. Unlike [`Self::decode()`], returns an error
+    /// on invalid input instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is invalid, or decodes to a length other than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::{DecodeErrorKind, Decoder};
+    /// assert_eq!(Decoder::Hex.try_decode::<2>(b"c0de"), Ok([0xc0, 0xde]));
+    /// let err = Decoder::Hex.try_decode::<2>(b"c0d").unwrap_err();
+    /// assert_eq!(err.kind(), DecodeErrorKind::IncompleteInput);
+    /// let err = Decoder::Hex.try_decode::<3>(b"c0de").unwrap_err();
+    /// assert_eq!(
+    ///     err.kind(),
+    ///     DecodeErrorKind::InvalidLength { decoded_len: 2, expected_len: 3 }
+    /// );
+    /// ```
+    pub const fn try_decode<const N: usize>(self, input: &[u8]) -> Result<[u8; N], DecodeError> {
+        self.do_try_decode(input, None, false)
+    }
+
+    pub(crate) const fn do_try_decode<const N: usize>(
+        self,
+        input: &[u8],
+        skipper: Option<Skipper>,
+        reject_trailing_data: bool,
+    ) -> Result<[u8; N], DecodeError> {
+        let (bytes, out_len) =
+            match self.try_decode_inner::<N>(input, skipper, reject_trailing_data) {
+                Ok(output) => output,
+                Err(err) => return Err(err),
+            };
+        if out_len == N {
+            Ok(bytes)
+        } else {
+            let kind = DecodeErrorKind::InvalidLength {
+                decoded_len: out_len,
+                expected_len: N,
+            };
+            Err(DecodeError::new(kind, input.len()))
+        }
+    }
+
+    /// Returns decoded bytes (truncated to `N`) and the full decoded length.
+    pub(crate) const fn try_decode_inner<const N: usize>(
+        self,
+        input: &[u8],
+        skipper: Option<Skipper>,
+        reject_trailing_data: bool,
+    ) -> Result<([u8; N], usize), DecodeError> {
+        if self.can_use_fast_path(skipper, reject_trailing_data) {
+            if let Some(output) = decode_hex_fast::<N>(input) {
+                return Ok(output);
+            }
+        }
+
+        let mut bytes = [0_u8; N];
+        let mut out_index = 0;
+        let mut cursor = match DecodeCursor::try_new(self, input, skipper, reject_trailing_data) {
+            Ok(cursor) => cursor,
+            Err(err) => return Err(err),
+        };
+        loop {
+            let (new_cursor, byte) = match cursor.try_next_byte(input) {
+                Ok(output) => output,
+                Err(err) => return Err(err),
+            };
+            cursor = new_cursor;
+            let Some(byte) = byte else {
+                break;
+            };
+            if out_index < N {
+                bytes[out_index] = byte;
+            }
+            out_index += 1;
+        }
+        match cursor.try_finish() {
+            Ok(()) => Ok((bytes, out_index)),
+            Err(err) => Err(err),
+        }
     }
 
     pub(crate) const fn do_decode<const N: usize>(
         self,
         input: &[u8],
         skipper: Option<Skipper>,
+        reject_trailing_data: bool,
     ) -> [u8; N] {
+        let (bytes, out_len, cursor) = self.decode_inner::<N>(input, skipper, reject_trailing_data);
+        check_output_len(out_len, N);
+        cursor.finish();
+        bytes
+    }
+
+    pub(crate) const fn do_decode_len(
+        self,
+        input: &[u8],
+        skipper: Option<Skipper>,
+        reject_trailing_data: bool,
+    ) -> usize {
+        self.decode_inner::<0>(input, skipper, reject_trailing_data)
+            .1
+    }
+
+    /// Decodes `input` into the start of a byte buffer. Returns the buffer and the decoded length.
+    pub(crate) const fn decode_prefix<const N: usize>(self, input: &[u8]) -> ([u8; N], usize) {
+        let (bytes, out_len, cursor) = self.decode_inner::<N>(input, None, false);
+        compile_assert!(
+            out_len <= N,
+            "Input decodes to more than ", N => fmt::<usize>(), " bytes"
+        );
+        cursor.finish();
+        (bytes, out_len)
+    }
+
+    /// Returns decoded bytes (truncated to `N`), the full decoded length and the final cursor.
+    const fn decode_inner<const N: usize>(
+        self,
+        input: &[u8],
+        skipper: Option<Skipper>,
+        reject_trailing_data: bool,
+    ) -> ([u8; N], usize, DecodeCursor) {
+        let mut cursor = DecodeCursor::new(self, input, skipper, reject_trailing_data);
+        if self.can_use_fast_path(skipper, reject_trailing_data) {
+            if let Some((bytes, out_len)) = decode_hex_fast::<N>(input) {
+                // The initial cursor state is final, so it can be returned as is.
+                return (bytes, out_len, cursor);
+            }
+        }
+
         let mut bytes = [0_u8; N];
-        let mut in_index = 0;
         let mut out_index = 0;
-        let mut state = self.new_state();
+        loop {
+            let (new_cursor, byte) = cursor.next_byte(input);
+            cursor = new_cursor;
+            let Some(byte) = byte else {
+                break;
+            };
+            if out_index < N {
+                bytes[out_index] = byte;
+            }
+            out_index += 1;
+        }
+        (bytes, out_index, cursor)
+    }
 
+    const fn can_use_fast_path(self, skipper: Option<Skipper>, reject_trailing_data: bool) -> bool {
+        matches!(self, Self::Hex) && skipper.is_none() && !reject_trailing_data
+    }
+
+    /// Returns the position of the first non-whitespace char in `input` starting from `in_index`.
+    const fn find_trailing_data(input: &[u8], mut in_index: usize) -> Option<usize> {
         while in_index < input.len() {
-            if let Some(skipper) = skipper {
+            if !input[in_index].is_ascii_whitespace() {
+                return Some(in_index);
+            }
+            in_index += 1;
+        }
+        None
+    }
+}
+
+/// Position of a decoder in the input, together with the decoder state.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DecodeCursor {
+    in_index: usize,
+    state: DecoderState,
+    skipper: Option<Skipper>,
+    reject_trailing_data: bool,
+    padding_pos: Option<usize>,
+}
+
+impl DecodeCursor {
+    pub(crate) const fn new(
+        decoder: Decoder,
+        input: &[u8],
+        skipper: Option<Skipper>,
+        reject_trailing_data: bool,
+    ) -> Self {
+        Self {
+            in_index: Skipper::start_index(skipper, input),
+            state: decoder.new_state(),
+            skipper,
+            reject_trailing_data,
+            padding_pos: None,
+        }
+    }
+
+    /// Same as [`Self::new()`], but returns an error instead of panicking if a PEM header line
+    /// is required, but is not present in `input`.
+    pub(crate) const fn try_new(
+        decoder: Decoder,
+        input: &[u8],
+        skipper: Option<Skipper>,
+        reject_trailing_data: bool,
+    ) -> Result<Self, DecodeError> {
+        let Some(in_index) = Skipper::try_start_index(skipper, input) else {
+            let kind = DecodeErrorKind::MissingHeader;
+            return Err(DecodeError::new(kind, input.len()));
+        };
+        Ok(Self {
+            in_index,
+            state: decoder.new_state(),
+            skipper,
+            reject_trailing_data,
+            padding_pos: None,
+        })
+    }
+
+    /// Returns the index of the next input char to be processed.
+    #[cfg(any(feature = "heapless", feature = "rayon"))]
+    pub(crate) const fn in_index(&self) -> usize {
+        self.in_index
+    }
+
+    /// Processes `input` until the next decoded byte is produced. Returns `None` in place
+    /// of the byte if `input` has ended.
+    pub(crate) const fn next_byte(self, input: &[u8]) -> (Self, Option<u8>) {
+        match self.next_byte_inner(input) {
+            Ok(output) => output,
+            Err(err) => err.panic(),
+        }
+    }
+
+    /// Same as [`Self::next_byte()`], but returns an error instead of panicking.
+    pub(crate) const fn try_next_byte(
+        self,
+        input: &[u8],
+    ) -> Result<(Self, Option<u8>), DecodeError> {
+        match self.next_byte_inner(input) {
+            Ok(output) => Ok(output),
+            Err(err) => Err(err.into_public()),
+        }
+    }
+
+    const fn next_byte_inner(mut self, input: &[u8]) -> Result<(Self, Option<u8>), CursorError> {
+        while self.in_index < input.len() {
+            let in_index = self.in_index;
+            if let Some(skipper) = self.skipper {
                 let new_in_index = skipper.skip(input, in_index);
                 if new_in_index != in_index {
-                    in_index = new_in_index;
+                    if self.reject_trailing_data && skipper.is_pem_trailer(input, in_index) {
+                        if let Some(pos) = Decoder::find_trailing_data(input, new_in_index) {
+                            return Err(CursorError::DataAfterPemTrailer(pos));
+                        }
+                    }
+                    self.in_index = new_in_index;
                     continue;
                 }
             }
 
-            let update = match state.update(input[in_index]) {
+            if self.reject_trailing_data {
+                if input[in_index] == b'=' {
+                    if self.padding_pos.is_none() {
+                        self.padding_pos = Some(in_index);
+                    }
+                } else if self.padding_pos.is_some() {
+                    return Err(CursorError::DataAfterPadding(in_index));
+                }
+            }
+
+            let update = match self.state.update(input[in_index]) {
                 Ok(update) => update,
-                Err(err) => err.panic(in_index),
+                Err(err) => return Err(CursorError::Char(err, in_index)),
             };
-            state = update.0;
-            if let Some(byte) = update.1 {
-                if out_index < N {
-                    bytes[out_index] = byte;
-                }
-                out_index += 1;
+            self.state = update.0;
+            self.in_index += 1;
+            if update.1.is_some() {
+                return Ok((self, update.1));
             }
-            in_index += 1;
         }
+        Ok((self, None))
+    }
 
-        compile_assert!(
-            out_index <= N,
-            "Output overflow: the input decodes to ", out_index => fmt::<usize>(),
-            " bytes, while type inference implies ",  N => fmt::<usize>(), ". \
-            Either fix the input or change the output buffer length correspondingly"
-        );
-        compile_assert!(
-            out_index == N,
-            "Output underflow: the input decodes to ", out_index => fmt::<usize>(),
-            " bytes, while type inference implies ", N => fmt::<usize>(), ". \
-            Either fix the input or change the output buffer length correspondingly"
-        );
+    /// Same as [`Self::finish()`], but returns an error instead of panicking.
+    pub(crate) const fn try_finish(self) -> Result<(), DecodeError> {
+        if self.state.is_final() {
+            Ok(())
+        } else {
+            Err(DecodeError::new(
+                DecodeErrorKind::IncompleteInput,
+                self.in_index,
+            ))
+        }
+    }
 
+    /// Panics if the decoder state is not final.
+    pub(crate) const fn finish(self) {
         assert!(
-            state.is_final(),
+            self.state.is_final(),
             "Left-over state after processing input. This usually means that the input \
              is incorrect (e.g., an odd number of hex digits)."
         );
-        bytes
     }
+}
 
-    pub(crate) const fn do_decode_len(self, input: &[u8], skipper: Option<Skipper>) -> usize {
-        let mut in_index = 0;
-        let mut out_index = 0;
-        let mut state = self.new_state();
+/// Error produced by [`DecodeCursor`].
+#[derive(Debug)]
+enum CursorError {
+    Char(CharError, usize),
+    DataAfterPadding(usize),
+    DataAfterPemTrailer(usize),
+}
 
-        while in_index < input.len() {
-            if let Some(skipper) = skipper {
-                let new_in_index = skipper.skip(input, in_index);
-                if new_in_index != in_index {
-                    in_index = new_in_index;
-                    continue;
-                }
-            }
+impl CursorError {
+    const fn into_public(self) -> DecodeError {
+        match self {
+            Self::Char(err, pos) => DecodeError::new(err.kind(), pos),
+            Self::DataAfterPadding(pos) => DecodeError::new(DecodeErrorKind::InvalidPadding, pos),
+            Self::DataAfterPemTrailer(pos) => DecodeError::new(DecodeErrorKind::TrailingData, pos),
+        }
+    }
 
-            let update = match state.update(input[in_index]) {
-                Ok(update) => update,
-                Err(err) => err.panic(in_index),
-            };
-            state = update.0;
-            if update.1.is_some() {
-                out_index += 1;
-            }
-            in_index += 1;
+    const fn panic(self) -> ! {
+        match self {
+            Self::Char(err, pos) => err.panic(pos),
+            Self::DataAfterPadding(pos) => compile_panic!(
+                "Unexpected data after padding at position ", pos => fmt::<usize>()
+            ),
+            Self::DataAfterPemTrailer(pos) => compile_panic!(
+                "Unexpected data after PEM trailer at position ", pos => fmt::<usize>()
+            ),
         }
-        out_index
     }
 }
@@ -0,0 +1,449 @@
+//! Digest functions (SHA-256, SHA-512/256 and Keccak-256) and digest verification.
+
+use crate::{
+    decoder::{copy_to_array, try_check_output_len},
+    error::{DecodeError, DecodeErrorKind},
+    panics::compile_assert,
+};
+
+/// SHA-256 round constants.
+#[rustfmt::skip]
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a_2f98, 0x7137_4491, 0xb5c0_fbcf, 0xe9b5_dba5, 0x3956_c25b, 0x59f1_11f1, 0x923f_82a4,
+    0xab1c_5ed5, 0xd807_aa98, 0x1283_5b01, 0x2431_85be, 0x550c_7dc3, 0x72be_5d74, 0x80de_b1fe,
+    0x9bdc_06a7, 0xc19b_f174, 0xe49b_69c1, 0xefbe_4786, 0x0fc1_9dc6, 0x240c_a1cc, 0x2de9_2c6f,
+    0x4a74_84aa, 0x5cb0_a9dc, 0x76f9_88da, 0x983e_5152, 0xa831_c66d, 0xb003_27c8, 0xbf59_7fc7,
+    0xc6e0_0bf3, 0xd5a7_9147, 0x06ca_6351, 0x1429_2967, 0x27b7_0a85, 0x2e1b_2138, 0x4d2c_6dfc,
+    0x5338_0d13, 0x650a_7354, 0x766a_0abb, 0x81c2_c92e, 0x9272_2c85, 0xa2bf_e8a1, 0xa81a_664b,
+    0xc24b_8b70, 0xc76c_51a3, 0xd192_e819, 0xd699_0624, 0xf40e_3585, 0x106a_a070, 0x19a4_c116,
+    0x1e37_6c08, 0x2748_774c, 0x34b0_bcb5, 0x391c_0cb3, 0x4ed8_aa4a, 0x5b9c_ca4f, 0x682e_6ff3,
+    0x748f_82ee, 0x78a5_636f, 0x84c8_7814, 0x8cc7_0208, 0x90be_fffa, 0xa450_6ceb, 0xbef9_a3f7,
+    0xc671_78f2,
+];
+
+/// Initial SHA-256 state.
+#[rustfmt::skip]
+const INITIAL_STATE: [u32; 8] = [
+    0x6a09_e667, 0xbb67_ae85, 0x3c6e_f372, 0xa54f_f53a, 0x510e_527f, 0x9b05_688c, 0x1f83_d9ab,
+    0x5be0_cd19,
+];
+
+/// Processes a 64-byte block `bytes[offset..offset + 64]`.
+#[allow(clippy::many_single_char_names)] // follows the naming in the SHA-256 spec
+const fn compress(state: [u32; 8], bytes: &[u8], offset: usize) -> [u32; 8] {
+    let mut w = [0_u32; 64];
+    let mut i = 0;
+    while i < 16 {
+        let pos = offset + 4 * i;
+        w[i] = u32::from_be_bytes([bytes[pos], bytes[pos + 1], bytes[pos + 2], bytes[pos + 3]]);
+        i += 1;
+    }
+    while i < 64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+        w[i] = w[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1);
+        i += 1;
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+    i = 0;
+    while i < 64 {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ (!e & g);
+        let t1 = h
+            .wrapping_add(s1)
+            .wrapping_add(ch)
+            .wrapping_add(ROUND_CONSTANTS[i])
+            .wrapping_add(w[i]);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let t2 = s0.wrapping_add(maj);
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(t1);
+        d = c;
+        c = b;
+        b = a;
+        a = t1.wrapping_add(t2);
+        i += 1;
+    }
+
+    let mut new_state = [a, b, c, d, e, f, g, h];
+    i = 0;
+    while i < 8 {
+        new_state[i] = new_state[i].wrapping_add(state[i]);
+        i += 1;
+    }
+    new_state
+}
+
+/// Computes the SHA-256 digest of `bytes`.
+pub(crate) const fn sha256(bytes: &[u8]) -> [u8; 32] {
+    sha256_of_prefix(bytes, bytes.len())
+}
+
+/// Computes the SHA-256 digest of `bytes[..len]`.
+pub(crate) const fn sha256_of_prefix(bytes: &[u8], len: usize) -> [u8; 32] {
+    let mut state = INITIAL_STATE;
+    let full_blocks = len / 64;
+    let mut i = 0;
+    while i < full_blocks {
+        state = compress(state, bytes, i * 64);
+        i += 1;
+    }
+
+    // Pad the remaining bytes with `0x80`, zeros and the message length in bits.
+    let mut tail = [0_u8; 128];
+    let remainder = len % 64;
+    i = 0;
+    while i < remainder {
+        tail[i] = bytes[full_blocks * 64 + i];
+        i += 1;
+    }
+    tail[remainder] = 0x80;
+    let tail_len = if remainder < 56 { 64 } else { 128 };
+    let bit_len = (len as u64).wrapping_mul(8).to_be_bytes();
+    i = 0;
+    while i < 8 {
+        tail[tail_len - 8 + i] = bit_len[i];
+        i += 1;
+    }
+    state = compress(state, &tail, 0);
+    if tail_len == 128 {
+        state = compress(state, &tail, 64);
+    }
+
+    let mut digest = [0_u8; 32];
+    i = 0;
+    while i < 8 {
+        let word = state[i].to_be_bytes();
+        digest[4 * i] = word[0];
+        digest[4 * i + 1] = word[1];
+        digest[4 * i + 2] = word[2];
+        digest[4 * i + 3] = word[3];
+        i += 1;
+    }
+    digest
+}
+
+/// SHA-512 round constants.
+#[rustfmt::skip]
+const SHA512_ROUND_CONSTANTS: [u64; 80] = [
+    0x428a_2f98_d728_ae22, 0x7137_4491_23ef_65cd, 0xb5c0_fbcf_ec4d_3b2f, 0xe9b5_dba5_8189_dbbc,
+    0x3956_c25b_f348_b538, 0x59f1_11f1_b605_d019, 0x923f_82a4_af19_4f9b, 0xab1c_5ed5_da6d_8118,
+    0xd807_aa98_a303_0242, 0x1283_5b01_4570_6fbe, 0x2431_85be_4ee4_b28c, 0x550c_7dc3_d5ff_b4e2,
+    0x72be_5d74_f27b_896f, 0x80de_b1fe_3b16_96b1, 0x9bdc_06a7_25c7_1235, 0xc19b_f174_cf69_2694,
+    0xe49b_69c1_9ef1_4ad2, 0xefbe_4786_384f_25e3, 0x0fc1_9dc6_8b8c_d5b5, 0x240c_a1cc_77ac_9c65,
+    0x2de9_2c6f_592b_0275, 0x4a74_84aa_6ea6_e483, 0x5cb0_a9dc_bd41_fbd4, 0x76f9_88da_8311_53b5,
+    0x983e_5152_ee66_dfab, 0xa831_c66d_2db4_3210, 0xb003_27c8_98fb_213f, 0xbf59_7fc7_beef_0ee4,
+    0xc6e0_0bf3_3da8_8fc2, 0xd5a7_9147_930a_a725, 0x06ca_6351_e003_826f, 0x1429_2967_0a0e_6e70,
+    0x27b7_0a85_46d2_2ffc, 0x2e1b_2138_5c26_c926, 0x4d2c_6dfc_5ac4_2aed, 0x5338_0d13_9d95_b3df,
+    0x650a_7354_8baf_63de, 0x766a_0abb_3c77_b2a8, 0x81c2_c92e_47ed_aee6, 0x9272_2c85_1482_353b,
+    0xa2bf_e8a1_4cf1_0364, 0xa81a_664b_bc42_3001, 0xc24b_8b70_d0f8_9791, 0xc76c_51a3_0654_be30,
+    0xd192_e819_d6ef_5218, 0xd699_0624_5565_a910, 0xf40e_3585_5771_202a, 0x106a_a070_32bb_d1b8,
+    0x19a4_c116_b8d2_d0c8, 0x1e37_6c08_5141_ab53, 0x2748_774c_df8e_eb99, 0x34b0_bcb5_e19b_48a8,
+    0x391c_0cb3_c5c9_5a63, 0x4ed8_aa4a_e341_8acb, 0x5b9c_ca4f_7763_e373, 0x682e_6ff3_d6b2_b8a3,
+    0x748f_82ee_5def_b2fc, 0x78a5_636f_4317_2f60, 0x84c8_7814_a1f0_ab72, 0x8cc7_0208_1a64_39ec,
+    0x90be_fffa_2363_1e28, 0xa450_6ceb_de82_bde9, 0xbef9_a3f7_b2c6_7915, 0xc671_78f2_e372_532b,
+    0xca27_3ece_ea26_619c, 0xd186_b8c7_21c0_c207, 0xeada_7dd6_cde0_eb1e, 0xf57d_4f7f_ee6e_d178,
+    0x06f0_67aa_7217_6fba, 0x0a63_7dc5_a2c8_98a6, 0x113f_9804_bef9_0dae, 0x1b71_0b35_131c_471b,
+    0x28db_77f5_2304_7d84, 0x32ca_ab7b_40c7_2493, 0x3c9e_be0a_15c9_bebc, 0x431d_67c4_9c10_0d4c,
+    0x4cc5_d4be_cb3e_42b6, 0x597f_299c_fc65_7e2a, 0x5fcb_6fab_3ad6_faec, 0x6c44_198c_4a47_5817,
+];
+
+/// Initial state for SHA-512/256.
+#[rustfmt::skip]
+const SHA512_256_INITIAL_STATE: [u64; 8] = [
+    0x2231_2194_fc2b_f72c, 0x9f55_5fa3_c84c_64c2, 0x2393_b86b_6f53_b151, 0x9638_7719_5940_eabd,
+    0x9628_3ee2_a88e_ffe3, 0xbe5e_1e25_5386_3992, 0x2b01_99fc_2c85_b8aa, 0x0eb7_2ddc_81c5_2ca2,
+];
+
+/// Processes a single 128-byte block starting at `offset` with the SHA-512 compression function.
+#[allow(clippy::many_single_char_names)] // follows the naming in the SHA-512 spec
+const fn sha512_compress(state: [u64; 8], bytes: &[u8], offset: usize) -> [u64; 8] {
+    let mut w = [0_u64; 80];
+    let mut i = 0;
+    while i < 16 {
+        let mut word = [0_u8; 8];
+        let mut j = 0;
+        while j < 8 {
+            word[j] = bytes[offset + 8 * i + j];
+            j += 1;
+        }
+        w[i] = u64::from_be_bytes(word);
+        i += 1;
+    }
+    while i < 80 {
+        let s0 = w[i - 15].rotate_right(1) ^ w[i - 15].rotate_right(8) ^ (w[i - 15] >> 7);
+        let s1 = w[i - 2].rotate_right(19) ^ w[i - 2].rotate_right(61) ^ (w[i - 2] >> 6);
+        w[i] = w[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1);
+        i += 1;
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+    i = 0;
+    while i < 80 {
+        let s1 = e.rotate_right(14) ^ e.rotate_right(18) ^ e.rotate_right(41);
+        let ch = (e & f) ^ (!e & g);
+        let t1 = h
+            .wrapping_add(s1)
+            .wrapping_add(ch)
+            .wrapping_add(SHA512_ROUND_CONSTANTS[i])
+            .wrapping_add(w[i]);
+        let s0 = a.rotate_right(28) ^ a.rotate_right(34) ^ a.rotate_right(39);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let t2 = s0.wrapping_add(maj);
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(t1);
+        d = c;
+        c = b;
+        b = a;
+        a = t1.wrapping_add(t2);
+        i += 1;
+    }
+
+    let mut new_state = [a, b, c, d, e, f, g, h];
+    i = 0;
+    while i < 8 {
+        new_state[i] = new_state[i].wrapping_add(state[i]);
+        i += 1;
+    }
+    new_state
+}
+
+/// Computes the SHA-512/256 digest of `bytes[..len]`.
+pub(crate) const fn sha512_256(bytes: &[u8], len: usize) -> [u8; 32] {
+    let mut state = SHA512_256_INITIAL_STATE;
+    let full_blocks = len / 128;
+    let mut i = 0;
+    while i < full_blocks {
+        state = sha512_compress(state, bytes, i * 128);
+        i += 1;
+    }
+
+    // Pad the remaining bytes with `0x80`, zeros and the message length in bits.
+    let mut tail = [0_u8; 256];
+    let remainder = len % 128;
+    i = 0;
+    while i < remainder {
+        tail[i] = bytes[full_blocks * 128 + i];
+        i += 1;
+    }
+    tail[remainder] = 0x80;
+    let tail_len = if remainder < 112 { 128 } else { 256 };
+    let bit_len = (len as u64).wrapping_mul(8).to_be_bytes();
+    i = 0;
+    while i < 8 {
+        tail[tail_len - 8 + i] = bit_len[i];
+        i += 1;
+    }
+    state = sha512_compress(state, &tail, 0);
+    if tail_len == 256 {
+        state = sha512_compress(state, &tail, 128);
+    }
+
+    let mut digest = [0_u8; 32];
+    i = 0;
+    while i < 32 {
+        digest[i] = state[i / 8].to_be_bytes()[i % 8];
+        i += 1;
+    }
+    digest
+}
+
+/// Keccak-f\[1600\] round constants.
+#[rustfmt::skip]
+const KECCAK_ROUND_CONSTANTS: [u64; 24] = [
+    0x0000_0000_0000_0001, 0x0000_0000_0000_8082, 0x8000_0000_0000_808a, 0x8000_0000_8000_8000,
+    0x0000_0000_0000_808b, 0x0000_0000_8000_0001, 0x8000_0000_8000_8081, 0x8000_0000_0000_8009,
+    0x0000_0000_0000_008a, 0x0000_0000_0000_0088, 0x0000_0000_8000_8009, 0x0000_0000_8000_000a,
+    0x0000_0000_8000_808b, 0x8000_0000_0000_008b, 0x8000_0000_0000_8089, 0x8000_0000_0000_8003,
+    0x8000_0000_0000_8002, 0x8000_0000_0000_0080, 0x0000_0000_0000_800a, 0x8000_0000_8000_000a,
+    0x8000_0000_8000_8081, 0x8000_0000_0000_8080, 0x0000_0000_8000_0001, 0x8000_0000_8000_8008,
+];
+
+/// Keccak-f\[1600\] rotation offsets for the lane at `x + 5 * y`.
+#[rustfmt::skip]
+const KECCAK_ROTATIONS: [u32; 25] = [
+    0, 1, 62, 28, 27,
+    36, 44, 6, 55, 20,
+    3, 10, 43, 25, 39,
+    41, 45, 15, 21, 8,
+    18, 2, 61, 56, 14,
+];
+
+/// Rate of Keccak-256 in bytes.
+const KECCAK256_RATE: usize = 136;
+
+/// Applies the Keccak-f\[1600\] permutation.
+const fn keccak_f(mut lanes: [u64; 25]) -> [u64; 25] {
+    let mut round = 0;
+    while round < 24 {
+        // Theta step
+        let mut parities = [0_u64; 5];
+        let mut x = 0;
+        while x < 5 {
+            parities[x] = lanes[x] ^ lanes[x + 5] ^ lanes[x + 10] ^ lanes[x + 15] ^ lanes[x + 20];
+            x += 1;
+        }
+        let mut i = 0;
+        while i < 25 {
+            let x = i % 5;
+            lanes[i] ^= parities[(x + 4) % 5] ^ parities[(x + 1) % 5].rotate_left(1);
+            i += 1;
+        }
+
+        // Rho and pi steps
+        let mut permuted = [0_u64; 25];
+        i = 0;
+        while i < 25 {
+            let (x, y) = (i % 5, i / 5);
+            permuted[y + 5 * ((2 * x + 3 * y) % 5)] = lanes[i].rotate_left(KECCAK_ROTATIONS[i]);
+            i += 1;
+        }
+
+        // Chi step
+        i = 0;
+        while i < 25 {
+            let (x, row_start) = (i % 5, i - i % 5);
+            lanes[i] = permuted[i]
+                ^ (!permuted[row_start + (x + 1) % 5] & permuted[row_start + (x + 2) % 5]);
+            i += 1;
+        }
+
+        // Iota step
+        lanes[0] ^= KECCAK_ROUND_CONSTANTS[round];
+        round += 1;
+    }
+    lanes
+}
+
+/// Computes the Keccak-256 digest of `bytes[..len]`, as used in Ethereum. Note that this differs
+/// from SHA3-256 in padding.
+pub(crate) const fn keccak256(bytes: &[u8], len: usize) -> [u8; 32] {
+    let mut lanes = [0_u64; 25];
+    let mut i = 0;
+    while i < len {
+        let pos = i % KECCAK256_RATE;
+        lanes[pos / 8] ^= (bytes[i] as u64) << (8 * (pos % 8));
+        if pos == KECCAK256_RATE - 1 {
+            lanes = keccak_f(lanes);
+        }
+        i += 1;
+    }
+    let pos = len % KECCAK256_RATE;
+    lanes[pos / 8] ^= 1 << (8 * (pos % 8));
+    lanes[(KECCAK256_RATE - 1) / 8] ^= 0x80 << (8 * ((KECCAK256_RATE - 1) % 8));
+    lanes = keccak_f(lanes);
+
+    let mut digest = [0_u8; 32];
+    i = 0;
+    while i < 32 {
+        digest[i] = lanes[i / 8].to_le_bytes()[i % 8];
+        i += 1;
+    }
+    digest
+}
+
+/// Decoder verifying that the SHA-256 digest of the input bytes matches the expected value.
+/// The bytes are returned as is. This allows to check integrity of embedded data (e.g., a key,
+/// a certificate or a firmware blob) in compile time, without a build script.
+///
+/// The input is raw bytes, so the decoder can be nested with other decoders to check
+/// their output, or applied to an `include_bytes!` blob directly.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Decoder, DigestChecked};
+/// const DIGEST: [u8; 32] = decode!(
+///     Decoder::Hex,
+///     b"532eaabd9574880dbf76b9b8cc00832c20a6ec113d682299550d7a6e0f345e25",
+/// );
+/// const TEXT: &[u8] = &decode!(
+///     DigestChecked::new(DIGEST),
+///     &decode!(Decoder::Base64, b"VGVzdA=="),
+/// );
+/// assert_eq!(TEXT, b"Test");
+/// const RAW: [u8; 4] = DigestChecked::new(DIGEST).decode(b"Test");
+/// assert_eq!(RAW, *b"Test");
+/// ```
+///
+/// Digest mismatch is caught in compile time:
+///
+/// ```compile_fail
+/// # use const_decoder::{decode, Decoder, DigestChecked};
+/// # const DIGEST: [u8; 32] = decode!(
+/// #     Decoder::Hex,
+/// #     b"532eaabd9574880dbf76b9b8cc00832c20a6ec113d682299550d7a6e0f345e25",
+/// # );
+/// const TEXT: &[u8] = &decode!(
+///     DigestChecked::new(DIGEST),
+///     &decode!(Decoder::Base64, b"dGVzdA=="),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DigestChecked {
+    expected_digest: [u8; 32],
+}
+
+impl DigestChecked {
+    /// Creates a decoder expecting the input bytes to have the specified SHA-256 digest.
+    pub const fn new(expected_digest: [u8; 32]) -> Self {
+        Self { expected_digest }
+    }
+
+    const fn digest_matches(&self, bytes: &[u8]) -> bool {
+        let digest = sha256(bytes);
+        let mut i = 0;
+        while i < digest.len() {
+            if digest[i] != self.expected_digest[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Checks the digest of `input` and copies it into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` has an unexpected SHA-256 digest.
+    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        compile_assert!(
+            self.digest_matches(input),
+            "SHA-256 digest mismatch for input bytes"
+        );
+        copy_to_array(input)
+    }
+
+    /// Checks the digest of `input` and copies it into a byte array, returning an error
+    /// instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an [`InvalidLength`](DecodeErrorKind::InvalidLength) error if `input`
+    ///   has a length other than `N`.
+    /// - Returns a [`ChecksumMismatch`](DecodeErrorKind::ChecksumMismatch) error if `input`
+    ///   has an unexpected SHA-256 digest.
+    pub const fn try_decode<const N: usize>(self, input: &[u8]) -> Result<[u8; N], DecodeError> {
+        const_try!(try_check_output_len(input, input.len(), N));
+        if self.digest_matches(input) {
+            Ok(copy_to_array(input))
+        } else {
+            let kind = DecodeErrorKind::ChecksumMismatch;
+            Err(DecodeError::new(kind, input.len()))
+        }
+    }
+}
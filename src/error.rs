@@ -0,0 +1,124 @@
+//! Structured decoding errors.
+
+use core::fmt;
+
+/// Kind of a [`DecodeError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DecodeErrorKind {
+    /// Symbol that is not a part of the decoder alphabet, or a hex digit violating
+    /// the case policy.
+    InvalidSymbol {
+        /// Offending symbol.
+        symbol: u8,
+    },
+    /// Invalid padding, such as data after padding chars, or excessive or non-zero padding bits
+    /// (e.g., in Bech32).
+    InvalidPadding,
+    /// Input ends in the middle of an encoded byte (e.g., has an odd number of hex digits).
+    IncompleteInput,
+    /// Decoded length differs from the expected one.
+    InvalidLength {
+        /// Actual decoded length.
+        decoded_len: usize,
+        /// Expected decoded length.
+        expected_len: usize,
+    },
+    /// Data after the end of the encoded data, such as a PEM trailer line.
+    TrailingData,
+    /// Checksum embedded into the input does not match the decoded data.
+    ChecksumMismatch,
+    /// Required header or opening delimiter, such as a PEM header when [skipping the preamble],
+    /// gzip magic bytes, or the `<~` delimiter of Adobe Ascii85, is missing.
+    ///
+    /// [skipping the preamble]: crate::PemOptions::skip_preamble()
+    MissingHeader,
+    /// Decoded value does not fit into the output or into its designated number of bits
+    /// (e.g., a Base85 group overflowing 32 bits).
+    Overflow,
+    /// Invalid structure of a compressed or container stream, e.g., an invalid block type,
+    /// an unsupported compression method or charset, or a back-reference pointing before
+    /// the start of the output.
+    InvalidStream,
+}
+
+impl fmt::Display for DecodeErrorKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSymbol { symbol } if symbol.is_ascii() => {
+                write!(formatter, "invalid symbol {:?}", char::from(*symbol))
+            }
+            Self::InvalidSymbol { symbol } => write!(formatter, "invalid byte {symbol:#04x}"),
+            Self::InvalidPadding => formatter.write_str("invalid padding"),
+            Self::IncompleteInput => formatter.write_str("incomplete input"),
+            Self::InvalidLength {
+                decoded_len,
+                expected_len,
+            } => write!(
+                formatter,
+                "input decodes to {decoded_len} bytes, while {expected_len} bytes are expected"
+            ),
+            Self::TrailingData => formatter.write_str("trailing data"),
+            Self::ChecksumMismatch => formatter.write_str("checksum mismatch"),
+            Self::MissingHeader => formatter.write_str("missing header"),
+            Self::Overflow => formatter.write_str("overflow"),
+            Self::InvalidStream => formatter.write_str("invalid stream"),
+        }
+    }
+}
+
+/// Error returned by checked decoding methods, such as [`Decoder::try_decode()`].
+/// Consists of the error kind and the position in the input at which the error has been
+/// detected.
+///
+/// [`Decoder::try_decode()`]: crate::Decoder::try_decode()
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{DecodeError, DecodeErrorKind, Decoder};
+/// const ERR: DecodeError = match Decoder::Hex.try_decode::<3>(b"c0ffeX") {
+///     Ok(_) => panic!("unexpected success"),
+///     Err(err) => err,
+/// };
+/// assert_eq!(ERR.kind(), DecodeErrorKind::InvalidSymbol { symbol: b'X' });
+/// assert_eq!(ERR.position(), 5);
+/// assert_eq!(ERR.to_string(), "invalid symbol 'X' at position 5");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecodeError {
+    kind: DecodeErrorKind,
+    position: usize,
+}
+
+impl DecodeError {
+    pub(crate) const fn new(kind: DecodeErrorKind, position: usize) -> Self {
+        Self { kind, position }
+    }
+
+    /// Shifts the error position by `offset`, e.g. if the input was a part of a larger input.
+    pub(crate) const fn with_offset(mut self, offset: usize) -> Self {
+        self.position += offset;
+        self
+    }
+
+    /// Returns the error kind.
+    pub const fn kind(&self) -> DecodeErrorKind {
+        self.kind
+    }
+
+    /// Returns the position in the input at which the error was detected.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{} at position {}", self.kind, self.position)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for DecodeError {}
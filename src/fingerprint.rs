@@ -0,0 +1,143 @@
+//! Fingerprint decoders.
+
+use crate::{
+    decoder::{check_output_len, Decoder},
+    panics::{compile_assert, compile_panic, fmt},
+    wrappers::Stripped,
+};
+
+/// Decoder for colon-separated hex fingerprints, such as `AB:CD:EF:01`, as output by
+/// `openssl x509 -fingerprint` or shown by browsers in certificate details. Each byte must be
+/// encoded with exactly 2 hex digits; bytes must be separated by a single `:` char. Hex digits
+/// are case-insensitive.
+///
+/// Use the [`decode_fingerprint!`](crate::decode_fingerprint) macro to additionally check that
+/// the fingerprint has the length of a SHA-1 or SHA-256 digest.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Fingerprint};
+/// const FINGERPRINT: [u8; 4] = Fingerprint::decode(b"C0:FF:ee:00");
+/// assert_eq!(FINGERPRINT, [0xc0, 0xff, 0xee, 0]);
+/// const SAME: &[u8] = &decode!(Fingerprint, b"c0:ff:ee:00");
+/// assert_eq!(SAME, FINGERPRINT);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fingerprint;
+
+impl Fingerprint {
+    /// Char separating bytes in the fingerprint.
+    pub const SEPARATOR: u8 = b':';
+
+    /// Decodes `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` is not a colon-separated sequence of 2-digit hex bytes.
+    pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = Self::do_decode::<N>(input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        Self::do_decode::<0>(input).1
+    }
+
+    const fn hex_digit(input: &[u8], pos: usize) -> u8 {
+        let ch = input[pos];
+        match ch {
+            b'0'..=b'9' => ch - b'0',
+            b'A'..=b'F' => ch - b'A' + 10,
+            b'a'..=b'f' => ch - b'a' + 10,
+            _ => compile_panic!(
+                "Character '", ch as char => fmt::<char>(), "' at position ",
+                pos => fmt::<usize>(), " is not a hex digit"
+            ),
+        }
+    }
+
+    /// Returns decoded bytes (truncated to `N`) together with the full decoded length.
+    const fn do_decode<const N: usize>(input: &[u8]) -> ([u8; N], usize) {
+        let mut bytes = [0_u8; N];
+        let mut in_index = 0;
+        let mut out_index = 0;
+        while in_index < input.len() {
+            if out_index > 0 {
+                let ch = input[in_index];
+                compile_assert!(
+                    ch == Self::SEPARATOR,
+                    "Expected ':' separator at position ", in_index => fmt::<usize>(),
+                    ", got '", ch as char => fmt::<char>(), "'"
+                );
+                in_index += 1;
+            }
+            compile_assert!(
+                in_index + 2 <= input.len(),
+                "Fingerprint ends with an incomplete byte at position ", in_index => fmt::<usize>()
+            );
+
+            let value =
+                (Self::hex_digit(input, in_index) << 4) + Self::hex_digit(input, in_index + 1);
+            if out_index < N {
+                bytes[out_index] = value;
+            }
+            out_index += 1;
+            in_index += 2;
+        }
+        (bytes, out_index)
+    }
+}
+
+/// Decoder for OpenSSH SHA-256 key fingerprints, such as those output by `ssh-keygen -l`
+/// or shown when connecting to an unknown host. The fingerprint consists of the `SHA256:` prefix
+/// followed by the base64-encoded digest without padding.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, SshFingerprint};
+/// const DIGEST: [u8; 32] =
+///     SshFingerprint::decode(b"SHA256:47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU");
+/// assert_eq!(DIGEST[..4], [0xe3, 0xb0, 0xc4, 0x42]);
+/// const SAME: &[u8] = &decode!(
+///     SshFingerprint,
+///     b"SHA256:47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU",
+/// );
+/// assert_eq!(SAME, DIGEST);
+/// ```
+///
+/// Truncated fingerprints are caught in compile time:
+///
+/// ```compile_fail
+/// # use const_decoder::SshFingerprint;
+/// const DIGEST: [u8; 32] = SshFingerprint::decode(b"SHA256:47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3h");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SshFingerprint;
+
+impl SshFingerprint {
+    /// Length of a fingerprint in its text form.
+    pub const ENCODED_LEN: usize = 50;
+    /// Length of the decoded digest in bytes.
+    pub const DECODED_LEN: usize = 32;
+
+    const DECODER: Stripped<Decoder> = Stripped::new(Decoder::Base64, b"SHA256:", b"");
+
+    /// Decodes `input` into the 32-byte SHA-256 digest.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `input` does not start with the `SHA256:` prefix, or does not have 50 chars.
+    /// - Panics if the digest is not valid base64.
+    pub const fn decode(input: &[u8]) -> [u8; 32] {
+        compile_assert!(
+            input.len() == Self::ENCODED_LEN,
+            "SSH fingerprint must have 50 chars (`SHA256:` followed by 43 base64 chars \
+             without padding), got ", input.len() => fmt::<usize>()
+        );
+        Self::DECODER.decode(input)
+    }
+}
@@ -0,0 +1,144 @@
+//! heatshrink (LZSS) decompression.
+
+use crate::{
+    decoder::{check_output_len, try_check_output_len},
+    error::DecodeError,
+    panics::{compile_assert, fmt},
+};
+
+/// Decompressor for the [heatshrink] LZSS-based format, which is popular in embedded systems.
+///
+/// heatshrink streams do not contain parameters used during compression, so they must be
+/// specified when creating the decompressor: `window_bits` and `lookahead_bits` correspond
+/// to the `-w` and `-l` options of the `heatshrink` CLI tool
+/// (or to `HEATSHRINK_STATIC_WINDOW_BITS` / `HEATSHRINK_STATIC_LOOKAHEAD_BITS`).
+///
+/// [heatshrink]: https://github.com/atomicobject/heatshrink
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Decoder, Heatshrink};
+/// const DATA: &[u8] = &decode!(
+///     Heatshrink::new(8, 4),
+///     &decode!(Decoder::Hex, b"b4596d96cb7cb240064908"),
+/// );
+/// assert_eq!(DATA, b"hello, hello!");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Heatshrink {
+    window_bits: u8,
+    lookahead_bits: u8,
+}
+
+impl Heatshrink {
+    /// Creates a decompressor with the specified parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window_bits` is not in `4..=15`, or `lookahead_bits` is not
+    /// in `3..window_bits`.
+    pub const fn new(window_bits: u8, lookahead_bits: u8) -> Self {
+        compile_assert!(
+            window_bits >= 4 && window_bits <= 15,
+            "Invalid heatshrink window size ", window_bits => fmt::<u8>(), "; must be in 4..=15"
+        );
+        compile_assert!(
+            lookahead_bits >= 3 && lookahead_bits < window_bits,
+            "Invalid heatshrink lookahead size ", lookahead_bits => fmt::<u8>(),
+            "; must be in 3..", window_bits => fmt::<u8>()
+        );
+        Self {
+            window_bits,
+            lookahead_bits,
+        }
+    }
+
+    /// Decompresses `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the provided length is insufficient or too large for the decompressed data.
+    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = self.do_decode::<N>(input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    /// Decompresses `input` into a byte array, returning an error if the decompressed data
+    /// has a length other than `N`. Since any bit sequence is a valid heatshrink stream,
+    /// this is the only possible error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidLength`](crate::DecodeErrorKind::InvalidLength) error if the provided
+    /// length is insufficient or too large for the decompressed data.
+    pub const fn try_decode<const N: usize>(self, input: &[u8]) -> Result<[u8; N], DecodeError> {
+        let (bytes, out_len) = self.do_decode::<N>(input);
+        const_try!(try_check_output_len(input, out_len, N));
+        Ok(bytes)
+    }
+
+    pub(crate) const fn decode_len(self, input: &[u8]) -> usize {
+        self.do_decode::<0>(input).1
+    }
+
+    /// Reads `count` bits in the MSB-first order starting from bit position `pos`.
+    const fn read_bits(input: &[u8], pos: usize, count: usize) -> usize {
+        let mut value = 0;
+        let mut i = 0;
+        while i < count {
+            let bit_pos = pos + i;
+            let bit = (input[bit_pos / 8] >> (7 - bit_pos % 8)) & 1;
+            value = (value << 1) + bit as usize;
+            i += 1;
+        }
+        value
+    }
+
+    /// Returns decoded bytes (truncated to `N`) together with the full decoded length.
+    #[allow(clippy::cast_possible_truncation)] // literals are 8-bit
+    const fn do_decode<const N: usize>(self, input: &[u8]) -> ([u8; N], usize) {
+        let window_bits = self.window_bits as usize;
+        let lookahead_bits = self.lookahead_bits as usize;
+        let total_bits = input.len() * 8;
+
+        let mut bytes = [0_u8; N];
+        let mut bit_pos = 0;
+        let mut out_index = 0;
+        // Incomplete tags at the end of the stream are zero padding.
+        while bit_pos < total_bits {
+            let is_literal = Self::read_bits(input, bit_pos, 1) == 1;
+            bit_pos += 1;
+            if is_literal {
+                if bit_pos + 8 > total_bits {
+                    break;
+                }
+                if out_index < N {
+                    bytes[out_index] = Self::read_bits(input, bit_pos, 8) as u8;
+                }
+                out_index += 1;
+                bit_pos += 8;
+            } else {
+                if bit_pos + window_bits + lookahead_bits > total_bits {
+                    break;
+                }
+                let offset = Self::read_bits(input, bit_pos, window_bits) + 1;
+                bit_pos += window_bits;
+                let count = Self::read_bits(input, bit_pos, lookahead_bits) + 1;
+                bit_pos += lookahead_bits;
+
+                let mut i = 0;
+                while i < count {
+                    // The window is initially filled with zeros.
+                    if out_index < N && out_index >= offset {
+                        bytes[out_index] = bytes[out_index - offset];
+                    }
+                    out_index += 1;
+                    i += 1;
+                }
+            }
+        }
+        (bytes, out_index)
+    }
+}
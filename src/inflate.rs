@@ -0,0 +1,764 @@
+//! DEFLATE decompression.
+
+use crate::{
+    decoder::{check_output_len, try_check_output_len},
+    error::{DecodeError, DecodeErrorKind},
+    panics::{compile_panic, fmt},
+};
+
+const MAX_BITS: usize = 15;
+const MAX_LIT_CODES: usize = 286;
+const MAX_DIST_CODES: usize = 30;
+const FIXED_LIT_CODES: usize = 288;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA_BITS: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DIST_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DIST_EXTRA_BITS: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+/// Order in which code length code lengths are stored in a dynamic block header.
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+const FIXED_LIT_LENGTHS: [u8; FIXED_LIT_CODES] = {
+    let mut lengths = [8_u8; FIXED_LIT_CODES];
+    let mut i = 144;
+    while i < 256 {
+        lengths[i] = 9;
+        i += 1;
+    }
+    while i < 280 {
+        lengths[i] = 7;
+        i += 1;
+    }
+    lengths
+};
+const FIXED_DIST_LENGTHS: [u8; MAX_DIST_CODES] = [5; MAX_DIST_CODES];
+
+/// LSB-first bit reader over a part of the input.
+#[derive(Debug, Clone, Copy)]
+struct BitReader {
+    pos: usize,
+    end: usize,
+    buffer: u32,
+    bit_count: u32,
+}
+
+impl BitReader {
+    const fn new(start: usize, end: usize) -> Self {
+        Self {
+            pos: start,
+            end,
+            buffer: 0,
+            bit_count: 0,
+        }
+    }
+
+    /// Reads `count <= 16` bits.
+    const fn bits(mut self, input: &[u8], count: u32) -> Result<(Self, u32), InflateError> {
+        let mut value = self.buffer;
+        while self.bit_count < count {
+            if self.pos >= self.end {
+                return Err(InflateError::UnexpectedEnd);
+            }
+            value |= (input[self.pos] as u32) << self.bit_count;
+            self.pos += 1;
+            self.bit_count += 8;
+        }
+        self.buffer = value >> count;
+        self.bit_count -= count;
+        Ok((self, value & ((1 << count) - 1)))
+    }
+
+    /// Discards remaining bits in the current byte.
+    const fn align(mut self) -> Self {
+        self.buffer = 0;
+        self.bit_count = 0;
+        self
+    }
+}
+
+/// Canonical Huffman code.
+#[derive(Debug, Clone, Copy)]
+struct Huffman {
+    /// Number of codes of each length.
+    counts: [u16; MAX_BITS + 1],
+    /// Symbols ordered by their codes.
+    symbols: [u16; FIXED_LIT_CODES],
+}
+
+impl Huffman {
+    const FIXED_LIT: Self = Self::fixed(&FIXED_LIT_LENGTHS);
+    const FIXED_DIST: Self = Self::fixed(&FIXED_DIST_LENGTHS);
+
+    const fn fixed(lengths: &[u8]) -> Self {
+        match Self::new(lengths, 0, lengths.len(), 0) {
+            Ok(code) => code,
+            Err(_) => panic!("Invalid fixed Huffman code"),
+        }
+    }
+
+    /// Builds a code from `lengths[start..start + len]`. Incomplete codes are allowed
+    /// (the unused codes will lead to an error during decoding). `pos` is the position
+    /// of the code in the input used for error reporting.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)] // lengths are small
+    const fn new(
+        lengths: &[u8],
+        start: usize,
+        len: usize,
+        pos: usize,
+    ) -> Result<Self, InflateError> {
+        let mut counts = [0_u16; MAX_BITS + 1];
+        let mut i = 0;
+        while i < len {
+            counts[lengths[start + i] as usize] += 1;
+            i += 1;
+        }
+
+        let mut left = 1_i32;
+        let mut bits = 1;
+        while bits <= MAX_BITS {
+            left = (left << 1) - counts[bits] as i32;
+            if left < 0 {
+                return Err(InflateError::OversubscribedCode(pos));
+            }
+            bits += 1;
+        }
+
+        let mut offsets = [0_u16; MAX_BITS + 1];
+        bits = 1;
+        while bits < MAX_BITS {
+            offsets[bits + 1] = offsets[bits] + counts[bits];
+            bits += 1;
+        }
+        let mut symbols = [0_u16; FIXED_LIT_CODES];
+        i = 0;
+        while i < len {
+            let code_len = lengths[start + i] as usize;
+            if code_len != 0 {
+                symbols[offsets[code_len] as usize] = i as u16;
+                offsets[code_len] += 1;
+            }
+            i += 1;
+        }
+        Ok(Self { counts, symbols })
+    }
+
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)] // values are small and non-negative
+    const fn decode(
+        &self,
+        mut reader: BitReader,
+        input: &[u8],
+    ) -> Result<(BitReader, usize), InflateError> {
+        let mut code = 0_i32;
+        let mut first = 0_i32;
+        let mut index = 0_i32;
+        let mut len = 1;
+        while len <= MAX_BITS {
+            let (updated_reader, bit) = const_try!(reader.bits(input, 1));
+            reader = updated_reader;
+            code |= bit as i32;
+            let count = self.counts[len] as i32;
+            if code - count < first {
+                return Ok((
+                    reader,
+                    self.symbols[(index + code - first) as usize] as usize,
+                ));
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+            len += 1;
+        }
+        Err(InflateError::InvalidCode(reader.pos))
+    }
+
+    /// Reads literal / length and distance codes from a dynamic block header.
+    #[allow(clippy::cast_possible_truncation)] // code lengths are checked to be small
+    const fn read_dynamic(
+        reader: BitReader,
+        input: &[u8],
+    ) -> Result<(BitReader, Self, Self), InflateError> {
+        let (reader, lit_count) = const_try!(reader.bits(input, 5));
+        let (reader, dist_count) = const_try!(reader.bits(input, 5));
+        let (mut reader, code_len_count) = const_try!(reader.bits(input, 4));
+        let lit_count = lit_count as usize + 257;
+        let dist_count = dist_count as usize + 1;
+        if lit_count > MAX_LIT_CODES || dist_count > MAX_DIST_CODES {
+            return Err(InflateError::InvalidCodeCount(reader.pos));
+        }
+
+        let mut code_len_lengths = [0_u8; 19];
+        let mut i = 0;
+        while i < code_len_count as usize + 4 {
+            let (updated_reader, len) = const_try!(reader.bits(input, 3));
+            reader = updated_reader;
+            code_len_lengths[CODE_LENGTH_ORDER[i]] = len as u8;
+            i += 1;
+        }
+        let code_len_code = const_try!(Self::new(&code_len_lengths, 0, 19, reader.pos));
+
+        let total_count = lit_count + dist_count;
+        let mut lengths = [0_u8; MAX_LIT_CODES + MAX_DIST_CODES];
+        i = 0;
+        while i < total_count {
+            let (updated_reader, symbol) = const_try!(code_len_code.decode(reader, input));
+            reader = updated_reader;
+            if symbol < 16 {
+                lengths[i] = symbol as u8;
+                i += 1;
+                continue;
+            }
+
+            let (value, extra_bits, base) = match symbol {
+                16 => {
+                    if i == 0 {
+                        return Err(InflateError::RepeatWithoutLength(reader.pos));
+                    }
+                    (lengths[i - 1], 2, 3)
+                }
+                17 => (0, 3, 3),
+                _ => (0, 7, 11),
+            };
+            let (updated_reader, extra) = const_try!(reader.bits(input, extra_bits));
+            reader = updated_reader;
+            let repeat = base + extra as usize;
+            if i + repeat > total_count {
+                return Err(InflateError::TooManyCodeLengths(reader.pos));
+            }
+            let mut j = 0;
+            while j < repeat {
+                lengths[i] = value;
+                i += 1;
+                j += 1;
+            }
+        }
+        if lengths[256] == 0 {
+            return Err(InflateError::MissingEndOfBlock(reader.pos));
+        }
+
+        let lit_code = const_try!(Self::new(&lengths, 0, lit_count, reader.pos));
+        let dist_code = const_try!(Self::new(&lengths, lit_count, dist_count, reader.pos));
+        Ok((reader, lit_code, dist_code))
+    }
+}
+
+/// Inflates raw DEFLATE data in `input[start..end]`. Returns decoded bytes (truncated to `N`),
+/// the full decoded length, and the position after the end of the DEFLATE stream.
+#[allow(clippy::cast_possible_truncation)] // literals are checked to fit into a byte
+const fn inflate<const N: usize>(
+    input: &[u8],
+    start: usize,
+    end: usize,
+) -> Result<([u8; N], usize, usize), InflateError> {
+    let mut bytes = [0_u8; N];
+    let mut out_index = 0;
+    let mut reader = BitReader::new(start, end);
+    loop {
+        let (updated_reader, is_final) = const_try!(reader.bits(input, 1));
+        let (updated_reader, block_type) = const_try!(updated_reader.bits(input, 2));
+        reader = updated_reader;
+
+        if block_type == 0 {
+            reader = reader.align();
+            let pos = reader.pos;
+            if pos + 4 > end {
+                return Err(InflateError::UnexpectedEnd);
+            }
+            let len = input[pos] as usize + ((input[pos + 1] as usize) << 8);
+            let len_complement = input[pos + 2] as usize + ((input[pos + 3] as usize) << 8);
+            if len ^ len_complement != 0xffff {
+                return Err(InflateError::InvalidStoredLen(pos));
+            }
+            if pos + 4 + len > end {
+                return Err(InflateError::UnexpectedEnd);
+            }
+            let mut i = 0;
+            while i < len {
+                if out_index < N {
+                    bytes[out_index] = input[pos + 4 + i];
+                }
+                out_index += 1;
+                i += 1;
+            }
+            reader.pos = pos + 4 + len;
+        } else {
+            let (lit_code, dist_code) = match block_type {
+                1 => (Huffman::FIXED_LIT, Huffman::FIXED_DIST),
+                2 => {
+                    let (updated_reader, lit_code, dist_code) =
+                        const_try!(Huffman::read_dynamic(reader, input));
+                    reader = updated_reader;
+                    (lit_code, dist_code)
+                }
+                _ => return Err(InflateError::InvalidBlockType(reader.pos)),
+            };
+
+            loop {
+                let (updated_reader, symbol) = const_try!(lit_code.decode(reader, input));
+                reader = updated_reader;
+                if symbol < 256 {
+                    if out_index < N {
+                        bytes[out_index] = symbol as u8;
+                    }
+                    out_index += 1;
+                    continue;
+                } else if symbol == 256 {
+                    break;
+                }
+
+                let symbol = symbol - 257;
+                if symbol >= LENGTH_BASE.len() {
+                    return Err(InflateError::InvalidLengthCode(reader.pos));
+                }
+                let (updated_reader, extra) =
+                    const_try!(reader.bits(input, LENGTH_EXTRA_BITS[symbol] as u32));
+                let len = LENGTH_BASE[symbol] as usize + extra as usize;
+                let (updated_reader, symbol) = const_try!(dist_code.decode(updated_reader, input));
+                if symbol >= DIST_BASE.len() {
+                    return Err(InflateError::InvalidDistanceCode(updated_reader.pos));
+                }
+                let (updated_reader, extra) =
+                    const_try!(updated_reader.bits(input, DIST_EXTRA_BITS[symbol] as u32));
+                reader = updated_reader;
+                let distance = DIST_BASE[symbol] as usize + extra as usize;
+                if distance > out_index {
+                    return Err(InflateError::InvalidDistance {
+                        distance,
+                        pos: reader.pos,
+                    });
+                }
+
+                let mut i = 0;
+                while i < len {
+                    // If `out_index < N`, then the source index is in bounds as well.
+                    if out_index < N {
+                        bytes[out_index] = bytes[out_index - distance];
+                    }
+                    out_index += 1;
+                    i += 1;
+                }
+            }
+        }
+
+        if is_final == 1 {
+            break;
+        }
+    }
+    Ok((bytes, out_index, reader.pos))
+}
+
+/// Computes the Adler-32 checksum of `bytes`.
+const fn adler32(bytes: &[u8]) -> u32 {
+    const MODULUS: u32 = 65_521;
+
+    let mut a = 1;
+    let mut b = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        a = (a + bytes[i] as u32) % MODULUS;
+        b = (b + a) % MODULUS;
+        i += 1;
+    }
+    (b << 16) + a
+}
+
+#[allow(clippy::cast_possible_truncation)] // `i < 256`
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0_u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut value = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            value = if value & 1 == 1 {
+                (value >> 1) ^ 0xedb8_8320
+            } else {
+                value >> 1
+            };
+            bit += 1;
+        }
+        table[i] = value;
+        i += 1;
+    }
+    table
+};
+
+/// Computes the CRC-32 (ISO-HDLC) checksum of `bytes[start..end]`.
+const fn crc32(bytes: &[u8], start: usize, end: usize) -> u32 {
+    let mut crc = u32::MAX;
+    let mut i = start;
+    while i < end {
+        crc = CRC32_TABLE[((crc ^ bytes[i] as u32) & 0xff) as usize] ^ (crc >> 8);
+        i += 1;
+    }
+    !crc
+}
+
+const fn read_u32_le(input: &[u8], pos: usize) -> u32 {
+    input[pos] as u32
+        + ((input[pos + 1] as u32) << 8)
+        + ((input[pos + 2] as u32) << 16)
+        + ((input[pos + 3] as u32) << 24)
+}
+
+const fn read_u32_be(input: &[u8], pos: usize) -> u32 {
+    ((input[pos] as u32) << 24)
+        + ((input[pos + 1] as u32) << 16)
+        + ((input[pos + 2] as u32) << 8)
+        + input[pos + 3] as u32
+}
+
+/// Decompressor for [DEFLATE] streams, either raw or wrapped into the [zlib] or [gzip] container.
+///
+/// The decompressor works with raw bytes; to decompress data encoded in base64 or another
+/// supported encoding, nest [`decode!`](crate::decode) invocations. Keep in mind that
+/// decompressing large inputs in compile time may be slow.
+///
+/// [DEFLATE]: https://www.rfc-editor.org/rfc/rfc1951
+/// [zlib]: https://www.rfc-editor.org/rfc/rfc1950
+/// [gzip]: https://www.rfc-editor.org/rfc/rfc1952
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Decoder, Inflate};
+/// const TEXT: &[u8] = &decode!(
+///     Inflate::Zlib,
+///     &decode!(Decoder::Base64, b"eJwrSS0u0VEoAZPJ+bkFRanFxakpYAEAi6QKLg=="),
+/// );
+/// assert_eq!(TEXT, b"test, test, compressed test");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Inflate {
+    /// Raw DEFLATE stream without any headers.
+    Raw,
+    /// DEFLATE stream wrapped into the zlib container. The Adler-32 checksum
+    /// of the decompressed data is verified.
+    Zlib,
+    /// DEFLATE stream wrapped into the gzip container, e.g., a `.gz` file. The header CRC
+    /// (if present), the CRC-32 checksum and the size of the decompressed data are verified.
+    /// Only single-member gzip files are supported.
+    Gzip,
+}
+
+impl Inflate {
+    /// Decompresses `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for the decompressed data.
+    /// - Panics if `input` is not a valid compressed stream, the stream checksum is invalid,
+    ///   or there is data after the end of the stream.
+    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = self.do_decode::<N>(input);
+        check_output_len(out_len, N);
+        match self.check_checksum(input, &bytes) {
+            Ok(()) => bytes,
+            Err(err) => err.panic(),
+        }
+    }
+
+    /// Decompresses `input` into a byte array, returning an error on invalid input.
+    /// See [`Decoder::try_decode()`](crate::Decoder::try_decode()) for details.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if `input` is not a valid compressed stream, or decompresses
+    ///   to a length other than `N`.
+    /// - A truncated stream or container is reported as
+    ///   [`IncompleteInput`](DecodeErrorKind::IncompleteInput); data after the end of the stream
+    ///   as [`TrailingData`](DecodeErrorKind::TrailingData).
+    /// - An Adler-32 / CRC-32 checksum or gzip size mismatch is reported as
+    ///   [`ChecksumMismatch`](DecodeErrorKind::ChecksumMismatch).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::{DecodeErrorKind, Inflate};
+    /// // zlib stream for `test` with a corrupted Adler-32 checksum
+    /// let input = b"x\x9c+I-.\x01\x00\x04]\x01\xc2";
+    /// let err = Inflate::Zlib.try_decode::<4>(input).unwrap_err();
+    /// assert_eq!(err.kind(), DecodeErrorKind::ChecksumMismatch);
+    /// ```
+    pub const fn try_decode<const N: usize>(self, input: &[u8]) -> Result<[u8; N], DecodeError> {
+        let (bytes, out_len) = match self.try_decode_inner::<N>(input) {
+            Ok(output) => output,
+            Err(err) => return Err(err.into_public(input)),
+        };
+        const_try!(try_check_output_len(input, out_len, N));
+        match self.check_checksum(input, &bytes) {
+            Ok(()) => Ok(bytes),
+            Err(err) => Err(err.into_public(input)),
+        }
+    }
+
+    pub(crate) const fn decode_len(self, input: &[u8]) -> usize {
+        self.do_decode::<0>(input).1
+    }
+
+    /// Verifies the checksum of the full decompressed data.
+    const fn check_checksum(self, input: &[u8], bytes: &[u8]) -> Result<(), InflateError> {
+        let is_valid = match self {
+            Self::Raw => true, // no checksum
+            Self::Zlib => adler32(bytes) == read_u32_be(input, input.len() - 4),
+            Self::Gzip => crc32(bytes, 0, bytes.len()) == read_u32_le(input, input.len() - 8),
+        };
+        if is_valid {
+            Ok(())
+        } else {
+            Err(InflateError::ChecksumMismatch(self))
+        }
+    }
+
+    /// Returns decoded bytes (truncated to `N`) together with the full decoded length.
+    /// Checksums are not verified since they require the entire output.
+    const fn do_decode<const N: usize>(self, input: &[u8]) -> ([u8; N], usize) {
+        match self.try_decode_inner::<N>(input) {
+            Ok(output) => output,
+            Err(err) => err.panic(),
+        }
+    }
+
+    /// Same as [`Self::do_decode()`], but returns an error instead of panicking.
+    const fn try_decode_inner<const N: usize>(
+        self,
+        input: &[u8],
+    ) -> Result<([u8; N], usize), InflateError> {
+        let (start, end) = match self {
+            Self::Raw => (0, input.len()),
+            Self::Zlib => {
+                if input.len() < 6 {
+                    return Err(InflateError::ZlibTooShort);
+                }
+                let (method, flags) = (input[0], input[1]);
+                if method & 0x0f != 8 || method >> 4 > 7 {
+                    return Err(InflateError::UnsupportedZlibMethod);
+                }
+                if (((method as u16) << 8) + flags as u16) % 31 != 0 {
+                    return Err(InflateError::InvalidZlibHeaderChecksum);
+                }
+                if flags & 0x20 != 0 {
+                    return Err(InflateError::ZlibPresetDictionary);
+                }
+                (2, input.len() - 4)
+            }
+            Self::Gzip => (const_try!(Self::gzip_header_len(input)), input.len() - 8),
+        };
+
+        let (bytes, out_len, stream_end) = const_try!(inflate::<N>(input, start, end));
+        if stream_end != end {
+            return Err(InflateError::DataAfterEnd(stream_end));
+        }
+        if let Self::Gzip = self {
+            // The size is stored modulo 2^32.
+            #[allow(clippy::cast_possible_truncation)]
+            let truncated_len = out_len as u32;
+            if truncated_len != read_u32_le(input, input.len() - 4) {
+                return Err(InflateError::GzipSizeMismatch(out_len));
+            }
+        }
+        Ok((bytes, out_len))
+    }
+
+    /// Parses the gzip header and returns its length.
+    const fn gzip_header_len(input: &[u8]) -> Result<usize, InflateError> {
+        const FHCRC: u8 = 2;
+        const FEXTRA: u8 = 4;
+        const FNAME: u8 = 8;
+        const FCOMMENT: u8 = 16;
+
+        if input.len() < 18 {
+            return Err(InflateError::GzipTooShort);
+        }
+        if input[0] != 0x1f || input[1] != 0x8b {
+            return Err(InflateError::InvalidGzipMagic);
+        }
+        if input[2] != 8 {
+            return Err(InflateError::UnsupportedGzipMethod);
+        }
+        let flags = input[3];
+        if flags & 0xe0 != 0 {
+            return Err(InflateError::ReservedGzipFlags);
+        }
+
+        let end = input.len() - 8;
+        let mut pos = 10;
+        if flags & FEXTRA != 0 {
+            if pos + 2 > end {
+                return Err(InflateError::UnexpectedHeaderEnd);
+            }
+            pos += 2 + input[pos] as usize + ((input[pos + 1] as usize) << 8);
+        }
+        let mut field_count = 0;
+        while field_count < 2 {
+            let flag = if field_count == 0 { FNAME } else { FCOMMENT };
+            if flags & flag != 0 {
+                // Skip a zero-terminated string.
+                while pos < end && input[pos] != 0 {
+                    pos += 1;
+                }
+                pos += 1;
+            }
+            field_count += 1;
+        }
+        if flags & FHCRC != 0 {
+            if pos + 2 > end {
+                return Err(InflateError::UnexpectedHeaderEnd);
+            }
+            let expected_crc = input[pos] as u32 + ((input[pos + 1] as u32) << 8);
+            if crc32(input, 0, pos) & 0xffff != expected_crc {
+                return Err(InflateError::HeaderCrcMismatch(pos));
+            }
+            pos += 2;
+        }
+        if pos > end {
+            return Err(InflateError::UnexpectedHeaderEnd);
+        }
+        Ok(pos)
+    }
+}
+
+/// Error produced by [`Inflate`]. Positions are in the compressed input.
+#[derive(Debug, Clone, Copy)]
+enum InflateError {
+    UnexpectedEnd,
+    OversubscribedCode(usize),
+    InvalidCode(usize),
+    InvalidCodeCount(usize),
+    RepeatWithoutLength(usize),
+    TooManyCodeLengths(usize),
+    MissingEndOfBlock(usize),
+    InvalidStoredLen(usize),
+    InvalidBlockType(usize),
+    InvalidLengthCode(usize),
+    InvalidDistanceCode(usize),
+    InvalidDistance { distance: usize, pos: usize },
+    DataAfterEnd(usize),
+    ZlibTooShort,
+    UnsupportedZlibMethod,
+    InvalidZlibHeaderChecksum,
+    ZlibPresetDictionary,
+    GzipTooShort,
+    InvalidGzipMagic,
+    UnsupportedGzipMethod,
+    ReservedGzipFlags,
+    UnexpectedHeaderEnd,
+    HeaderCrcMismatch(usize),
+    GzipSizeMismatch(usize),
+    ChecksumMismatch(Inflate),
+}
+
+impl InflateError {
+    const fn into_public(self, input: &[u8]) -> DecodeError {
+        let (kind, pos) = match self {
+            Self::UnexpectedEnd
+            | Self::UnexpectedHeaderEnd
+            | Self::ZlibTooShort
+            | Self::GzipTooShort => (DecodeErrorKind::IncompleteInput, input.len()),
+            Self::OversubscribedCode(pos)
+            | Self::InvalidCode(pos)
+            | Self::InvalidCodeCount(pos)
+            | Self::RepeatWithoutLength(pos)
+            | Self::TooManyCodeLengths(pos)
+            | Self::MissingEndOfBlock(pos)
+            | Self::InvalidStoredLen(pos)
+            | Self::InvalidBlockType(pos)
+            | Self::InvalidLengthCode(pos)
+            | Self::InvalidDistanceCode(pos)
+            | Self::InvalidDistance { pos, .. } => (DecodeErrorKind::InvalidStream, pos),
+            Self::UnsupportedZlibMethod => (DecodeErrorKind::InvalidStream, 0),
+            Self::ZlibPresetDictionary => (DecodeErrorKind::InvalidStream, 1),
+            Self::UnsupportedGzipMethod => (DecodeErrorKind::InvalidStream, 2),
+            Self::ReservedGzipFlags => (DecodeErrorKind::InvalidStream, 3),
+            Self::InvalidZlibHeaderChecksum => (DecodeErrorKind::ChecksumMismatch, 0),
+            Self::InvalidGzipMagic => (DecodeErrorKind::MissingHeader, 0),
+            Self::HeaderCrcMismatch(pos) => (DecodeErrorKind::ChecksumMismatch, pos),
+            Self::DataAfterEnd(pos) => (DecodeErrorKind::TrailingData, pos),
+            Self::GzipSizeMismatch(_) | Self::ChecksumMismatch(_) => {
+                (DecodeErrorKind::ChecksumMismatch, input.len())
+            }
+        };
+        DecodeError::new(kind, pos)
+    }
+
+    const fn panic(self) -> ! {
+        match self {
+            Self::UnexpectedEnd => compile_panic!("Unexpected end of DEFLATE stream"),
+            Self::OversubscribedCode(_) => {
+                compile_panic!("Over-subscribed Huffman code in DEFLATE stream")
+            }
+            Self::InvalidCode(pos) => compile_panic!(
+                "Invalid Huffman code in DEFLATE stream at position ", pos => fmt::<usize>()
+            ),
+            Self::InvalidCodeCount(pos) => compile_panic!(
+                "Invalid number of codes in dynamic DEFLATE block at position ",
+                pos => fmt::<usize>()
+            ),
+            Self::RepeatWithoutLength(pos) => compile_panic!(
+                "Repeated code length without a previous length in DEFLATE stream \
+                 at position ", pos => fmt::<usize>()
+            ),
+            Self::TooManyCodeLengths(pos) => compile_panic!(
+                "Too many code lengths in DEFLATE stream at position ", pos => fmt::<usize>()
+            ),
+            Self::MissingEndOfBlock(pos) => compile_panic!(
+                "Missing end-of-block code in DEFLATE stream at position ", pos => fmt::<usize>()
+            ),
+            Self::InvalidStoredLen(pos) => compile_panic!(
+                "Invalid stored block length in DEFLATE stream at position ", pos => fmt::<usize>()
+            ),
+            Self::InvalidBlockType(pos) => compile_panic!(
+                "Invalid block type in DEFLATE stream at position ", pos => fmt::<usize>()
+            ),
+            Self::InvalidLengthCode(pos) => compile_panic!(
+                "Invalid length code in DEFLATE stream at position ", pos => fmt::<usize>()
+            ),
+            Self::InvalidDistanceCode(pos) => compile_panic!(
+                "Invalid distance code in DEFLATE stream at position ", pos => fmt::<usize>()
+            ),
+            Self::InvalidDistance { distance, pos } => compile_panic!(
+                "Distance ", distance => fmt::<usize>(), " in DEFLATE stream at position ",
+                pos => fmt::<usize>(), " points before the start of the output"
+            ),
+            Self::DataAfterEnd(pos) => compile_panic!(
+                "Unexpected data after the end of DEFLATE stream at position ",
+                pos => fmt::<usize>()
+            ),
+            Self::ZlibTooShort => compile_panic!("zlib stream is too short"),
+            Self::UnsupportedZlibMethod => {
+                compile_panic!("Unsupported zlib compression method / window size")
+            }
+            Self::InvalidZlibHeaderChecksum => compile_panic!("Invalid zlib header checksum"),
+            Self::ZlibPresetDictionary => {
+                compile_panic!("zlib streams with preset dictionaries are not supported")
+            }
+            Self::GzipTooShort => compile_panic!("gzip stream is too short"),
+            Self::InvalidGzipMagic => compile_panic!("Invalid gzip magic bytes"),
+            Self::UnsupportedGzipMethod => compile_panic!("Unsupported gzip compression method"),
+            Self::ReservedGzipFlags => compile_panic!("Reserved gzip header flags are set"),
+            Self::UnexpectedHeaderEnd => compile_panic!("Unexpected end of gzip header"),
+            Self::HeaderCrcMismatch(_) => compile_panic!("Header CRC mismatch for gzip stream"),
+            Self::GzipSizeMismatch(out_len) => compile_panic!(
+                "Size mismatch for gzip stream: decompressed data has ", out_len => fmt::<usize>(),
+                " bytes"
+            ),
+            Self::ChecksumMismatch(Inflate::Zlib) => {
+                compile_panic!("Adler-32 checksum mismatch for zlib stream")
+            }
+            Self::ChecksumMismatch(_) => compile_panic!("CRC-32 checksum mismatch for gzip stream"),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! KSUID decoder.
+
+use crate::panics::{compile_assert, compile_panic, fmt};
+
+/// Decoder for [KSUIDs] (K-sortable unique identifiers) in their canonical text form: 27 chars
+/// of base62 (alphabet `0-9A-Za-z`) encoding a 160-bit big-endian number. The first 4 bytes
+/// of the decoded KSUID are the timestamp, and the remaining 16 bytes are the random payload.
+///
+/// Since 27 base62 chars can encode values exceeding 160 bits, values greater than
+/// `aWgEPTl1tmebfsQzFP4bxwgy80V` overflow and are rejected.
+///
+/// [KSUIDs]: https://github.com/segmentio/ksuid
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Ksuid};
+/// const ID: [u8; 20] = Ksuid::decode(b"0ujtsYcgvSTl8PAuAdqWYSMnLOv");
+/// assert_eq!(ID[..4], [0x06, 0x69, 0xf7, 0xef]); // timestamp
+/// const SAME: &[u8] = &decode!(Ksuid, b"0ujtsYcgvSTl8PAuAdqWYSMnLOv");
+/// assert_eq!(SAME, ID);
+/// ```
+///
+/// Overflowing KSUIDs are caught in compile time:
+///
+/// ```compile_fail
+/// # use const_decoder::Ksuid;
+/// const ID: [u8; 20] = Ksuid::decode(b"zzzzzzzzzzzzzzzzzzzzzzzzzzz");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ksuid;
+
+impl Ksuid {
+    /// Length of a KSUID in its text form.
+    pub const ENCODED_LEN: usize = 27;
+    /// Length of a KSUID in bytes.
+    pub const DECODED_LEN: usize = 20;
+
+    /// Decodes `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `input` does not have 27 chars.
+    /// - Panics if `input` contains chars outside the base62 alphabet.
+    /// - Panics if the encoded value overflows 160 bits.
+    #[allow(clippy::cast_possible_truncation)] // intentional
+    pub const fn decode(input: &[u8]) -> [u8; 20] {
+        compile_assert!(
+            input.len() == Self::ENCODED_LEN,
+            "KSUID must have 27 chars, got ", input.len() => fmt::<usize>()
+        );
+
+        // Big-endian representation of the number encoded by the processed digits.
+        let mut bytes = [0_u8; 20];
+        let mut in_index = 0;
+        while in_index < input.len() {
+            let mut carry = Self::digit(input, in_index) as usize;
+            let mut i = bytes.len();
+            while i > 0 {
+                i -= 1;
+                carry += bytes[i] as usize * 62;
+                bytes[i] = carry as u8;
+                carry >>= 8;
+            }
+            compile_assert!(carry == 0, "KSUID overflows 160 bits");
+            in_index += 1;
+        }
+        bytes
+    }
+
+    const fn digit(input: &[u8], pos: usize) -> u8 {
+        let ch = input[pos];
+        match ch {
+            b'0'..=b'9' => ch - b'0',
+            b'A'..=b'Z' => ch - b'A' + 10,
+            b'a'..=b'z' => ch - b'a' + 36,
+            _ => compile_panic!(
+                "Character '", ch as char => fmt::<char>(), "' at position ", pos => fmt::<usize>(),
+                " is not a part of the base62 alphabet"
+            ),
+        }
+    }
+}
@@ -1,10 +1,12 @@
 //! Constant functions for converting hex- and base64-encoded strings into bytes.
-//! Works on stable Rust and in no-std environments. Base-(2,4,8,16,32,64) encodings with
+//! Works on stable Rust and in no-std environments. Base-(2,4,8,16,32,64,128) encodings with
 //! custom alphabets are supported as well via [`Encoding`].
 //!
 //! [`Decoder`] is the base type encapsulating decoding logic, with [`SkipWhitespace`]
 //! and [`Pem`] types providing its variations with slightly different properties.
-//! (For example, `Pem` allows to parse PEM files.)
+//! (For example, `Pem` allows to parse PEM files.) Encodings that do not fit into
+//! the `Decoder` model are supported by dedicated types, such as [`BubbleBabble`].
+//! Finally, there are transcoders from legacy text encodings into UTF-8, such as [`Utf16`].
 //!
 //! Methods in base types require specifying the length of the output byte array, either in its type,
 //! or using the turbofish syntax (see the examples below). To avoid this, you can instead use
@@ -95,26 +97,245 @@
 //! // The provided string contains invalid chars
 //! const BOGUS: [u8; 5] = Decoder::Hex.decode(b"c0ffeecup");
 //! ```
+//!
+//! # Crate features
+//!
+//! The crate is `no_std` and does not allocate by default; all `const fn` APIs are available
+//! without any features. With default features disabled, the crate has no dependencies.
+//!
+//! ## `detailed-panics`
+//!
+//! *(On by default)*
+//!
+//! Formats dynamic values, such as positions of invalid chars or decoded lengths,
+//! in compile-time panic messages using the [`compile-fmt`] crate (which is `no_std` as well
+//! and has no dependencies of its own). Without this feature, panic messages only contain
+//! static text, with dynamic values replaced by `…`.
+//!
+//! [`compile-fmt`]: https://crates.io/crates/compile-fmt
+//!
+//! ## `alloc`
+//!
+//! *(Off by default)*
+//!
+//! Enables runtime decoding into `Vec`s, such as `Decoder::decode_to_vec()`. This is useful
+//! if the input is not known in compile time. Also enables the `build` module with helpers
+//! for build scripts pre-decoding large assets. Requires the `alloc` crate, but not `std`.
+//!
+//! ## `std`
+//!
+//! *(Off by default)*
+//!
+//! Enables I/O adapters, such as `Decoder::decode_reader()`. Implies `alloc`.
+//!
+//! ## `rayon`
+//!
+//! *(Off by default)*
+//!
+//! Enables decoding large runtime inputs in parallel on the global [`rayon`] thread pool
+//! via `Decoder::try_decode_parallel()`. This is useful for bulk processing, e.g. decoding
+//! hundreds of megabytes of base64-encoded logs. Implies `std`. Note that `rayon` has
+//! a higher MSRV than this crate.
+//!
+//! [`rayon`]: https://crates.io/crates/rayon
+//!
+//! ## `chacha20`
+//!
+//! *(Off by default)*
+//!
+//! Enables the `ChaCha20` decoder decrypting embedded data with the eponymous stream cipher
+//! in compile time.
+//!
+//! ## `serde`
+//!
+//! *(Off by default)*
+//!
+//! Implements `Serialize` and `Deserialize` for the `HexBytes` and `Base64Bytes` newtypes
+//! using their textual representations.
+//!
+//! ## `bytes`
+//!
+//! *(Off by default)*
+//!
+//! Enables runtime decoding of [`Buf`] inputs into `Bytes` / `BytesMut` from the [`bytes`] crate,
+//! such as `Decoder::decode_to_bytes()`. This is useful for network services (e.g., based
+//! on `tokio` or `hyper`) decoding payloads without extra copies. Implies `alloc`.
+//!
+//! [`Buf`]: https://docs.rs/bytes/1/bytes/trait.Buf.html
+//! [`bytes`]: https://crates.io/crates/bytes
+//!
+//! ## `heapless`
+//!
+//! *(Off by default)*
+//!
+//! Enables runtime decoding into fixed-capacity `heapless::Vec`s from the [`heapless`] crate,
+//! such as `Decoder::try_decode_to_heapless()`. This is useful for decoding runtime inputs
+//! of variable length on `no_std` targets without an allocator.
+//!
+//! [`heapless`]: https://crates.io/crates/heapless
+//!
+//! ## `zeroize`
+//!
+//! *(Off by default)*
+//!
+//! Enables runtime decoding into [`Zeroizing`] buffers from the [`zeroize`] crate, which are
+//! wiped when dropped, such as `Decoder::decode_to_zeroizing()`. This is useful for secrets
+//! like keys decoded from runtime inputs. With the `std` feature, also wipes the input buffer
+//! read by `decode_reader()` methods. Implies `alloc`.
+//!
+//! [`Zeroizing`]: https://docs.rs/zeroize/1/zeroize/struct.Zeroizing.html
+//! [`zeroize`]: https://crates.io/crates/zeroize
 
 #![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
 // Documentation settings.
-#![doc(html_root_url = "https://docs.rs/const-decoder/0.4.0")]
+#![doc(html_root_url = "https://docs.rs/const-decoder2/0.5.1")]
 // Linter settings.
 #![warn(missing_debug_implementations, missing_docs, bare_trait_objects)]
 #![warn(clippy::all, clippy::pedantic)]
 #![allow(clippy::must_use_candidate, clippy::shadow_unrelated)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+pub use zeroize::Zeroizing;
+
+#[cfg(feature = "chacha20")]
+pub use crate::chacha20::ChaCha20;
 pub use crate::{
-    decoder::{Decoder, Encoding},
-    macros::DecoderWrapper,
-    wrappers::{Pem, SkipWhitespace},
+    age::AgeKey,
+    algorand::AlgorandAddress,
+    aligned::{
+        Align1024, Align128, Align16, Align2, Align2048, Align256, Align32, Align4, Align4096,
+        Align512, Align64, Align8, Aligned,
+    },
+    base58::{Base58, MoneroBase58},
+    base85::{Base85, GitBase85},
+    bcd::Bcd,
+    bech32::Bech32,
+    bip39::Bip39,
+    bubblebabble::BubbleBabble,
+    caesar::Caesar,
+    cbor::CborBytes,
+    cid::Cid,
+    crc16::{Crc16, Crc16Checked},
+    decoder::{AsciiCase, Decoder, Encoding, HexCase, HexOptions},
+    der::{Der, Tlv},
+    digest::DigestChecked,
+    error::{DecodeError, DecodeErrorKind},
+    fingerprint::{Fingerprint, SshFingerprint},
+    heatshrink::Heatshrink,
+    inflate::Inflate,
+    ksuid::Ksuid,
+    lz4::Lz4Block,
+    macros::{
+        assert_bytes_eq, assert_canonical, check_eip55, chunk_count, copy_prefix, fingerprint_len,
+        git_oid_len, split_into_chunks, sri_digest_len, sri_prefix, wireguard_key_len,
+        DecoderWrapper,
+    },
+    newtypes::{Base64Bytes, HexBytes},
+    no_alloc::DecodeIter,
+    nostr::Nostr,
+    nsdata::NsData,
+    nucleotides::Nucleotides,
+    oid::{DottedOid, Oid},
+    packbits::PackBits,
+    pgp_words::PgpWords,
+    signify::Signify,
+    skey::SKey,
+    sql::{PgBytea, SqlHex},
+    stellar::Strkey,
+    text::{Ebcdic, EncodedWord, HtmlEntities, ImapUtf7, Latin1, Utf16, Utf7},
+    tezos::Tezos,
+    ti_txt::{TiTxt, TiTxtSection},
+    ton::TonAddress,
+    ulid::Ulid,
+    unicode::{Base2048, Ecoji},
+    varint::Varint,
+    vlq::Base64Vlq,
+    wrappers::{
+        FixedLineLen, Pem, PemOptions, RejectTrailingData, SkipBom, SkipChars,
+        SkipLineContinuations, SkipWhitespace, Stripped,
+    },
+    xor::Xored,
+    yubikey::YubikeyOtp,
 };
 
+// Since `?` is not allowed in `const fn`s, we use its simplified version.
+macro_rules! const_try {
+    ($result:expr) => {
+        match $result {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        }
+    };
+}
+
+mod age;
+mod algorand;
+mod aligned;
+mod base58;
+mod base85;
+mod bcd;
+mod bech32;
+mod bip39;
+mod bubblebabble;
+#[cfg(feature = "bytes")]
+mod buf;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod build;
+mod caesar;
+mod cbor;
+#[cfg(feature = "chacha20")]
+mod chacha20;
+mod cid;
+mod crc16;
 mod decoder;
+mod der;
+mod digest;
+mod error;
+mod fingerprint;
+mod heatshrink;
+mod inflate;
+mod ksuid;
+mod lz4;
 mod macros;
+mod newtypes;
+mod no_alloc;
+mod nostr;
+mod nsdata;
+mod nucleotides;
+mod oid;
+mod packbits;
+mod panics;
+mod pgp_words;
+#[cfg(feature = "alloc")]
+mod runtime;
+mod signify;
+mod skey;
+mod sql;
+mod stellar;
 #[cfg(test)]
 mod tests;
+mod text;
+mod tezos;
+mod ti_txt;
+mod ton;
+mod ulid;
+mod unicode;
+mod varint;
+mod vlq;
+mod words;
 mod wrappers;
+mod xor;
+mod yubikey;
+#[cfg(feature = "zeroize")]
+mod zeroizing;
 
 #[cfg(doctest)]
 doc_comment::doctest!("../README.md");
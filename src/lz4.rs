@@ -0,0 +1,195 @@
+//! LZ4 block decompression.
+
+use crate::{
+    decoder::{check_output_len, try_check_output_len},
+    error::{DecodeError, DecodeErrorKind},
+    panics::{compile_panic, fmt},
+};
+
+/// Decompressor for the [LZ4 block format]. Frames (i.e., data with the `04 22 4d 18` magic
+/// number, as produced by the `lz4` CLI tool) are not supported; only raw blocks are.
+///
+/// The decompressor works with raw bytes; to decompress data encoded in base64 or another
+/// supported encoding, nest [`decode!`](crate::decode) invocations.
+///
+/// [LZ4 block format]: https://github.com/lz4/lz4/blob/dev/doc/lz4_Block_format.md
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Decoder, Lz4Block};
+/// const DATA: &[u8] = &decode!(
+///     Lz4Block,
+///     &decode!(Decoder::Hex, b"3561626303005078797a3132"),
+/// );
+/// assert_eq!(DATA, b"abcabcabcabcxyz12");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Lz4Block;
+
+impl Lz4Block {
+    /// Decompresses `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for the decompressed data.
+    /// - Panics if `input` is not a valid LZ4 block.
+    pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = Self::do_decode::<N>(input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    /// Decompresses `input` into a byte array, returning an error on invalid input.
+    /// See [`Decoder::try_decode()`](crate::Decoder::try_decode()) for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is not a valid LZ4 block, or decompresses to a length
+    /// other than `N`. A truncated block is reported as
+    /// [`IncompleteInput`](DecodeErrorKind::IncompleteInput), and an invalid match offset
+    /// as [`InvalidStream`](DecodeErrorKind::InvalidStream).
+    pub const fn try_decode<const N: usize>(input: &[u8]) -> Result<[u8; N], DecodeError> {
+        let (bytes, out_len) = match Self::try_decode_inner::<N>(input) {
+            Ok(output) => output,
+            Err(err) => return Err(err.into_public(input)),
+        };
+        const_try!(try_check_output_len(input, out_len, N));
+        Ok(bytes)
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        Self::do_decode::<0>(input).1
+    }
+
+    /// Reads an extended length starting from `pos`. Returns the length increment
+    /// and the position after it.
+    const fn read_len(input: &[u8], mut pos: usize) -> Result<(usize, usize), Lz4Error> {
+        let mut len = 0;
+        loop {
+            if pos >= input.len() {
+                return Err(Lz4Error::LengthEnd(pos));
+            }
+            let byte = input[pos];
+            len += byte as usize;
+            pos += 1;
+            if byte != u8::MAX {
+                return Ok((len, pos));
+            }
+        }
+    }
+
+    /// Returns decoded bytes (truncated to `N`) together with the full decoded length.
+    const fn do_decode<const N: usize>(input: &[u8]) -> ([u8; N], usize) {
+        match Self::try_decode_inner::<N>(input) {
+            Ok(output) => output,
+            Err(err) => err.panic(),
+        }
+    }
+
+    /// Same as [`Self::do_decode()`], but returns an error instead of panicking.
+    const fn try_decode_inner<const N: usize>(input: &[u8]) -> Result<([u8; N], usize), Lz4Error> {
+        let mut bytes = [0_u8; N];
+        let mut in_index = 0;
+        let mut out_index = 0;
+        while in_index < input.len() {
+            let token = input[in_index];
+            in_index += 1;
+
+            let mut literals_len = (token >> 4) as usize;
+            if literals_len == 15 {
+                let (extra_len, new_index) = const_try!(Self::read_len(input, in_index));
+                literals_len += extra_len;
+                in_index = new_index;
+            }
+            if in_index + literals_len > input.len() {
+                return Err(Lz4Error::LiteralsEnd(in_index));
+            }
+            let mut i = 0;
+            while i < literals_len {
+                if out_index < N {
+                    bytes[out_index] = input[in_index + i];
+                }
+                out_index += 1;
+                i += 1;
+            }
+            in_index += literals_len;
+            if in_index == input.len() {
+                // The last sequence only contains literals.
+                break;
+            }
+
+            if in_index + 2 > input.len() {
+                return Err(Lz4Error::OffsetEnd(in_index));
+            }
+            let offset = input[in_index] as usize + ((input[in_index + 1] as usize) << 8);
+            if offset == 0 || offset > out_index {
+                return Err(Lz4Error::InvalidOffset {
+                    offset,
+                    pos: in_index,
+                });
+            }
+            in_index += 2;
+
+            let mut match_len = (token & 0x0f) as usize + 4;
+            if match_len == 19 {
+                let (extra_len, new_index) = const_try!(Self::read_len(input, in_index));
+                match_len += extra_len;
+                in_index = new_index;
+            }
+            i = 0;
+            while i < match_len {
+                // If `out_index < N`, then the source index is in bounds as well.
+                if out_index < N {
+                    bytes[out_index] = bytes[out_index - offset];
+                }
+                out_index += 1;
+                i += 1;
+            }
+        }
+        Ok((bytes, out_index))
+    }
+}
+
+/// Error produced by [`Lz4Block`].
+#[derive(Debug, Clone, Copy)]
+enum Lz4Error {
+    LengthEnd(usize),
+    LiteralsEnd(usize),
+    OffsetEnd(usize),
+    InvalidOffset { offset: usize, pos: usize },
+}
+
+impl Lz4Error {
+    const fn into_public(self, input: &[u8]) -> DecodeError {
+        match self {
+            Self::LengthEnd(_) | Self::LiteralsEnd(_) | Self::OffsetEnd(_) => {
+                DecodeError::new(DecodeErrorKind::IncompleteInput, input.len())
+            }
+            Self::InvalidOffset { pos, .. } => {
+                DecodeError::new(DecodeErrorKind::InvalidStream, pos)
+            }
+        }
+    }
+
+    const fn panic(self) -> ! {
+        match self {
+            Self::LengthEnd(pos) => compile_panic!(
+                "Unexpected end of LZ4 block while reading length at position ",
+                pos => fmt::<usize>()
+            ),
+            Self::LiteralsEnd(pos) => compile_panic!(
+                "Unexpected end of LZ4 block while reading literals at position ",
+                pos => fmt::<usize>()
+            ),
+            Self::OffsetEnd(pos) => compile_panic!(
+                "Unexpected end of LZ4 block while reading match offset at position ",
+                pos => fmt::<usize>()
+            ),
+            Self::InvalidOffset { offset, pos } => compile_panic!(
+                "Invalid LZ4 match offset ", offset => fmt::<usize>(), " at position ",
+                pos => fmt::<usize>()
+            ),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Newtypes for byte arrays parsed from / formatted as text at runtime.
+
+use core::{fmt, str::FromStr};
+
+use crate::{decoder::Decoder, error::DecodeError};
+
+macro_rules! impl_bytes_newtype {
+    ($name:ident, $decoder:expr) => {
+        impl<const N: usize> $name<N> {
+            /// Decodes `input` at runtime.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if `input` is invalid, or decodes to a length other than `N`.
+            pub const fn try_decode(input: &[u8]) -> Result<Self, DecodeError> {
+                match $decoder.try_decode(input) {
+                    Ok(bytes) => Ok(Self(bytes)),
+                    Err(err) => Err(err),
+                }
+            }
+        }
+
+        impl<const N: usize> From<[u8; N]> for $name<N> {
+            fn from(bytes: [u8; N]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl<const N: usize> From<$name<N>> for [u8; N] {
+            fn from(value: $name<N>) -> Self {
+                value.0
+            }
+        }
+
+        impl<const N: usize> AsRef<[u8]> for $name<N> {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl<const N: usize> FromStr for $name<N> {
+            type Err = DecodeError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::try_decode(s.as_bytes())
+            }
+        }
+
+        impl<const N: usize> TryFrom<&str> for $name<N> {
+            type Error = DecodeError;
+
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                Self::try_decode(s.as_bytes())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+        impl<const N: usize> serde::Serialize for $name<N> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+        impl<'de, const N: usize> serde::Deserialize<'de> for $name<N> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct StrVisitor<const N: usize>;
+
+                impl<const N: usize> serde::de::Visitor<'_> for StrVisitor<N> {
+                    type Value = $name<N>;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        write!(formatter, "{} encoding of {N} bytes", stringify!($name))
+                    }
+
+                    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                        value.parse().map_err(E::custom)
+                    }
+                }
+
+                deserializer.deserialize_str(StrVisitor)
+            }
+        }
+    };
+}
+
+/// Byte array parsed from and formatted as hex at runtime. This allows to use the same format
+/// both for constants (via [`Decoder::Hex`]) and for values read from config files or
+/// command-line args.
+///
+/// Parsing accepts both lowercase and uppercase digits; formatting uses lowercase digits.
+/// If the `serde` crate feature is enabled, the type is (de)serialized as a hex string.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{DecodeErrorKind, HexBytes};
+/// let key: HexBytes<4> = "c0FFee00".parse()?;
+/// assert_eq!(key.0, [0xc0, 0xff, 0xee, 0]);
+/// assert_eq!(key.to_string(), "c0ffee00");
+///
+/// let err = HexBytes::<4>::try_from("c0ffee").unwrap_err();
+/// assert_eq!(
+///     err.kind(),
+///     DecodeErrorKind::InvalidLength { decoded_len: 3, expected_len: 4 }
+/// );
+/// # Ok::<_, const_decoder::DecodeError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HexBytes<const N: usize>(pub [u8; N]);
+
+impl_bytes_newtype!(HexBytes, Decoder::Hex);
+
+impl<const N: usize> fmt::Display for HexBytes<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in &self.0 {
+            write!(formatter, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Byte array parsed from and formatted as base64 (with the standard alphabet) at runtime.
+/// This allows to use the same format both for constants (via [`Decoder::Base64`]) and for values
+/// read from config files or command-line args.
+///
+/// Parsing accepts padded or unpadded inputs; formatting uses padding.
+/// If the `serde` crate feature is enabled, the type is (de)serialized as a base64 string.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::Base64Bytes;
+/// let token: Base64Bytes<4> = "VGVzdA==".parse()?;
+/// assert_eq!(token.0, *b"Test");
+/// assert_eq!(token.to_string(), "VGVzdA==");
+/// assert!("VGVzdA?".parse::<Base64Bytes<4>>().is_err());
+/// # Ok::<_, const_decoder::DecodeError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Base64Bytes<const N: usize>(pub [u8; N]);
+
+impl_bytes_newtype!(Base64Bytes, Decoder::Base64);
+
+impl<const N: usize> fmt::Display for Base64Bytes<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for chunk in self.0.chunks(3) {
+            let chars = Decoder::Base64.encode::<4>(chunk);
+            for &ch in &chars {
+                fmt::Write::write_char(formatter, char::from(ch))?;
+            }
+        }
+        Ok(())
+    }
+}
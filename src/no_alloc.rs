@@ -0,0 +1,180 @@
+//! Allocation-free runtime decoding.
+
+use core::iter;
+
+#[cfg(any(feature = "heapless", feature = "rayon"))]
+use crate::error::DecodeErrorKind;
+use crate::{
+    decoder::{DecodeCursor, Decoder},
+    error::DecodeError,
+    wrappers::{SkipWhitespace, Skipper},
+};
+
+#[cfg(feature = "heapless")]
+fn try_decode_to_heapless<const CAP: usize>(
+    mut cursor: DecodeCursor,
+    input: &[u8],
+) -> Result<heapless::Vec<u8, CAP>, DecodeError> {
+    let mut output = heapless::Vec::new();
+    loop {
+        let (new_cursor, byte) = cursor.try_next_byte(input)?;
+        cursor = new_cursor;
+        let Some(byte) = byte else {
+            break;
+        };
+        if output.push(byte).is_err() {
+            let pos = cursor.in_index() - 1;
+            return Err(DecodeError::new(DecodeErrorKind::Overflow, pos));
+        }
+    }
+    cursor.try_finish()?;
+    Ok(output)
+}
+
+#[cfg(feature = "rayon")]
+fn try_decode_into(
+    mut cursor: DecodeCursor,
+    input: &[u8],
+    output: &mut [u8],
+) -> Result<usize, DecodeError> {
+    let mut len = 0;
+    loop {
+        let (new_cursor, byte) = cursor.try_next_byte(input)?;
+        cursor = new_cursor;
+        let Some(byte) = byte else {
+            break;
+        };
+        let Some(slot) = output.get_mut(len) else {
+            let pos = cursor.in_index() - 1;
+            return Err(DecodeError::new(DecodeErrorKind::Overflow, pos));
+        };
+        *slot = byte;
+        len += 1;
+    }
+    cursor.try_finish()?;
+    Ok(len)
+}
+
+/// Iterator over bytes decoded from input, returned by [`Decoder::decode_iter()`].
+/// Decodes input lazily, one byte at a time, without allocation.
+///
+/// Yields an error if the input is invalid; the error is the last item produced.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{DecodeErrorKind, Decoder};
+/// let mut sum = 0_u32;
+/// for byte in Decoder::Hex.decode_iter(b"c0ffee") {
+///     sum += u32::from(byte?);
+/// }
+/// assert_eq!(sum, 0xc0 + 0xff + 0xee);
+///
+/// let bytes: Result<Vec<_>, _> = Decoder::Hex.decode_iter(b"c0ffe").collect();
+/// assert_eq!(bytes.unwrap_err().kind(), DecodeErrorKind::IncompleteInput);
+/// # Ok::<_, const_decoder::DecodeError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct DecodeIter<'a> {
+    cursor: Option<DecodeCursor>,
+    input: &'a [u8],
+}
+
+impl<'a> DecodeIter<'a> {
+    const fn new(cursor: DecodeCursor, input: &'a [u8]) -> Self {
+        Self {
+            cursor: Some(cursor),
+            input,
+        }
+    }
+}
+
+impl Iterator for DecodeIter<'_> {
+    type Item = Result<u8, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cursor = self.cursor.take()?;
+        match cursor.try_next_byte(self.input) {
+            Ok((cursor, Some(byte))) => {
+                self.cursor = Some(cursor);
+                Some(Ok(byte))
+            }
+            Ok((cursor, None)) => cursor.try_finish().err().map(Err),
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+impl iter::FusedIterator for DecodeIter<'_> {}
+
+impl Decoder {
+    /// Decodes `input` into a [`heapless::Vec`] with capacity `CAP`. Unlike [`Self::try_decode()`],
+    /// the decoded length may be less than the capacity, so this method is useful for runtime
+    /// inputs in environments without an allocator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is invalid, or if it decodes to more than `CAP` bytes
+    /// (with the [`Overflow`](DecodeErrorKind::Overflow) kind).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::{DecodeErrorKind, Decoder};
+    /// let bytes: heapless::Vec<u8, 16> = Decoder::Hex.try_decode_to_heapless(b"c0ffee")?;
+    /// assert_eq!(bytes, [0xc0, 0xff, 0xee]);
+    ///
+    /// let err = Decoder::Hex.try_decode_to_heapless::<2>(b"c0ffee").unwrap_err();
+    /// assert_eq!(err.kind(), DecodeErrorKind::Overflow);
+    /// # Ok::<_, const_decoder::DecodeError>(())
+    /// ```
+    #[cfg(feature = "heapless")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+    pub fn try_decode_to_heapless<const CAP: usize>(
+        self,
+        input: &[u8],
+    ) -> Result<heapless::Vec<u8, CAP>, DecodeError> {
+        try_decode_to_heapless(DecodeCursor::new(self, input, None, false), input)
+    }
+
+    /// Decodes `input` into the provided `output` buffer, returning the decoded length.
+    /// Used by parallel decoding to write chunks directly into the shared output.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn try_decode_into(
+        self,
+        input: &[u8],
+        output: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        try_decode_into(DecodeCursor::new(self, input, None, false), input, output)
+    }
+
+    /// Returns an iterator lazily decoding `input` one byte at a time.
+    pub const fn decode_iter(self, input: &[u8]) -> DecodeIter<'_> {
+        DecodeIter::new(DecodeCursor::new(self, input, None, false), input)
+    }
+}
+
+impl SkipWhitespace {
+    /// Decodes `input` into a [`heapless::Vec`] with capacity `CAP`.
+    /// See [`Decoder::try_decode_to_heapless()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is invalid, or if it decodes to more than `CAP` bytes.
+    #[cfg(feature = "heapless")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+    pub fn try_decode_to_heapless<const CAP: usize>(
+        self,
+        input: &[u8],
+    ) -> Result<heapless::Vec<u8, CAP>, DecodeError> {
+        let cursor = DecodeCursor::new(self.0, input, Some(Skipper::Whitespace), false);
+        try_decode_to_heapless(cursor, input)
+    }
+
+    /// Returns an iterator lazily decoding `input` one byte at a time.
+    /// See [`Decoder::decode_iter()`] for details.
+    pub const fn decode_iter(self, input: &[u8]) -> DecodeIter<'_> {
+        let cursor = DecodeCursor::new(self.0, input, Some(Skipper::Whitespace), false);
+        DecodeIter::new(cursor, input)
+    }
+}
@@ -0,0 +1,64 @@
+//! Nostr entity decoder.
+
+use crate::bech32::Bech32;
+
+/// Type of a bare [Nostr] entity encoded with Bech32 as per [NIP-19]. The decoder verifies
+/// the human-readable part and the checksum, and outputs the 32-byte key or event ID.
+///
+/// [Nostr]: https://nostr.com/
+/// [NIP-19]: https://github.com/nostr-protocol/nips/blob/master/19.md
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Nostr};
+/// const PUBLIC_KEY: [u8; 32] =
+///     Nostr::PublicKey.decode(b"npub10elfcs4fr0l0r8af98jlmgdh9c8tcxjvz9qkw038js35mp4dma8qzvjptg");
+/// assert_eq!(PUBLIC_KEY[..4], [0x7e, 0x7e, 0x9c, 0x42]);
+/// const SECRET_KEY: &[u8] = &decode!(
+///     Nostr::SecretKey,
+///     b"nsec1vl029mgpspedva04g90vltkh6fvh240zqtv9k0t9af8935ke9laqsnlfe5",
+/// );
+/// assert_eq!(SECRET_KEY.len(), 32);
+/// ```
+///
+/// Entities of an unexpected type are caught in compile time:
+///
+/// ```compile_fail
+/// # use const_decoder::Nostr;
+/// const PUBLIC_KEY: [u8; 32] =
+///     Nostr::PublicKey.decode(b"nsec1vl029mgpspedva04g90vltkh6fvh240zqtv9k0t9af8935ke9laqsnlfe5");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Nostr {
+    /// Public key (`npub1...`).
+    PublicKey,
+    /// Secret key (`nsec1...`).
+    SecretKey,
+    /// Event ID (`note1...`).
+    Note,
+}
+
+impl Nostr {
+    /// Length of the decoded key or ID in bytes.
+    pub const DECODED_LEN: usize = 32;
+
+    const fn hrp(self) -> &'static str {
+        match self {
+            Self::PublicKey => "npub",
+            Self::SecretKey => "nsec",
+            Self::Note => "note",
+        }
+    }
+
+    /// Decodes `input` into the 32-byte key or event ID.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `input` has an unexpected human-readable part or length.
+    /// - Panics if `input` is not valid Bech32, e.g. has an invalid checksum.
+    pub const fn decode(self, input: &[u8]) -> [u8; 32] {
+        Bech32::new(self.hrp()).decode(input)
+    }
+}
@@ -0,0 +1,52 @@
+//! Decoder for the Apple `NSData` description format.
+
+use crate::{
+    decoder::Decoder,
+    macros::DecoderWrapper,
+    wrappers::{SkipWhitespace, Stripped},
+};
+
+/// Decoder for hex data enclosed in angle brackets with whitespace between groups of digits,
+/// such as `<deadbeef cafef00d>`. This format is used by old-style (`OpenStep`) property
+/// lists and `NSData` / `CFData` descriptions (e.g., in `po` output of debuggers), so that
+/// blobs copied from them can be decoded as is. Hex digits are case-insensitive, and whitespace
+/// (including line breaks) is allowed anywhere between the brackets.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, NsData};
+/// const TOKEN: [u8; 8] = NsData::decode(b"<deadbeef cafef00d>");
+/// assert_eq!(TOKEN, [0xde, 0xad, 0xbe, 0xef, 0xca, 0xfe, 0xf0, 0x0d]);
+/// const EMPTY: &[u8] = &decode!(NsData, b"<>");
+/// assert!(EMPTY.is_empty());
+/// ```
+///
+/// Inputs without brackets are caught in compile time:
+///
+/// ```compile_fail
+/// # use const_decoder::NsData;
+/// const TOKEN: [u8; 8] = NsData::decode(b"deadbeef cafef00d");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NsData;
+
+impl NsData {
+    const DECODER: Stripped<SkipWhitespace> =
+        Stripped::new(Decoder::Hex.skip_whitespace(), b"<", b">");
+
+    /// Decodes `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `input` is not enclosed in `<` `>` brackets.
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` contains invalid chars, or an odd number of hex digits.
+    pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+        Self::DECODER.decode(input)
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        DecoderWrapper(Self::DECODER).decode_len(input)
+    }
+}
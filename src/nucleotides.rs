@@ -0,0 +1,92 @@
+//! 2-bit nucleotide decoder.
+
+use crate::{
+    decoder::check_output_len,
+    panics::{clip, compile_panic, fmt},
+};
+
+/// Decoder packing nucleotide sequences into 2 bits per nucleotide: `A` = `0b00`, `C` = `0b01`,
+/// `G` = `0b10`, and `T` / `U` = `0b11`. Nucleotides are case-insensitive; the first nucleotide
+/// occupies the most significant bits of the first byte.
+///
+/// If the sequence length is not divisible by 4, the last byte is padded with zero bits
+/// (i.e., as if the sequence were completed with `A`s).
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Nucleotides};
+/// const SEQ: [u8; 2] = Nucleotides::Dna.decode(b"GATTACA");
+/// assert_eq!(SEQ, [0b_1000_1111, 0b_0001_0000]);
+///
+/// const RNA_SEQ: &[u8] = &decode!(Nucleotides::Rna, b"gauuaca");
+/// assert_eq!(RNA_SEQ, SEQ);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Nucleotides {
+    /// DNA nucleotides (`A`, `C`, `G`, `T`).
+    Dna,
+    /// RNA nucleotides (`A`, `C`, `G`, `U`).
+    Rna,
+    /// Either DNA or RNA nucleotides; `T` and `U` are treated identically.
+    Any,
+}
+
+impl Nucleotides {
+    /// Decodes `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` contains chars other than the nucleotides accepted by this decoder.
+    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = self.do_decode::<N>(input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    /// Returns the number of bytes that a sequence with the specified length decodes to.
+    pub const fn max_decoded_len(encoded_len: usize) -> usize {
+        (encoded_len + 3) / 4
+    }
+
+    pub(crate) const fn decode_len(self, input: &[u8]) -> usize {
+        self.do_decode::<0>(input).1
+    }
+
+    const fn lookup(self, input: &[u8], pos: usize) -> u8 {
+        let ch = input[pos];
+        match (ch.to_ascii_uppercase(), self) {
+            (b'A', _) => 0,
+            (b'C', _) => 1,
+            (b'G', _) => 2,
+            (b'T', Self::Dna | Self::Any) | (b'U', Self::Rna | Self::Any) => 3,
+            _ => {
+                let kind = match self {
+                    Self::Dna => "DNA",
+                    Self::Rna => "RNA",
+                    Self::Any => "DNA or RNA",
+                };
+                compile_panic!(
+                    "Character '", ch as char => fmt::<char>(), "' at position ",
+                    pos => fmt::<usize>(), " is not a ", kind => clip(10, ""), " nucleotide"
+                );
+            }
+        }
+    }
+
+    /// Returns decoded bytes (truncated to `N`) together with the full decoded length.
+    const fn do_decode<const N: usize>(self, input: &[u8]) -> ([u8; N], usize) {
+        let mut bytes = [0_u8; N];
+        let mut in_index = 0;
+        while in_index < input.len() {
+            let value = self.lookup(input, in_index);
+            let out_index = in_index / 4;
+            if out_index < N {
+                bytes[out_index] |= value << (6 - 2 * (in_index % 4));
+            }
+            in_index += 1;
+        }
+        (bytes, Self::max_decoded_len(input.len()))
+    }
+}
@@ -0,0 +1,221 @@
+//! ASN.1 object identifiers.
+
+use crate::{
+    decoder::check_output_len,
+    panics::{compile_assert, compile_panic, fmt},
+};
+
+/// Converter of dotted object identifier (OID) strings, such as `1.2.840.113549.1.1.11`,
+/// into their DER encoding. The output contains only the value bytes of the encoding,
+/// i.e., excludes the `OBJECT IDENTIFIER` tag and length. Arcs must fit into `u64`.
+///
+/// See [`DottedOid`] for the reverse conversion.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Oid};
+/// // sha256WithRSAEncryption
+/// const OID: &[u8] = &decode!(Oid, b"1.2.840.113549.1.1.11");
+/// assert_eq!(OID, [0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Oid;
+
+impl Oid {
+    /// Converts the dotted OID string into DER value bytes.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for the encoding.
+    /// - Panics if `input` is not a valid dotted OID.
+    pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = Self::do_decode::<N>(input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        Self::do_decode::<0>(input).1
+    }
+
+    /// Parses a decimal arc starting from `pos`. Returns the arc and the position after it.
+    const fn parse_arc(input: &[u8], pos: usize) -> (u64, usize) {
+        let mut value = 0_u64;
+        let mut i = pos;
+        while i < input.len() && input[i] != b'.' {
+            let ch = input[i];
+            compile_assert!(
+                ch.is_ascii_digit(),
+                "Character '", ch as char => fmt::<char>(), "' at position ",
+                i => fmt::<usize>(), " is not a part of a dotted OID"
+            );
+            compile_assert!(
+                i == pos || input[pos] != b'0',
+                "OID arc at position ", pos => fmt::<usize>(), " has a leading zero"
+            );
+            let Some(new_value) = value.checked_mul(10) else {
+                compile_panic!("OID arc at position ", pos => fmt::<usize>(), " overflows u64");
+            };
+            let Some(new_value) = new_value.checked_add((ch - b'0') as u64) else {
+                compile_panic!("OID arc at position ", pos => fmt::<usize>(), " overflows u64");
+            };
+            value = new_value;
+            i += 1;
+        }
+        compile_assert!(i > pos, "Empty OID arc at position ", pos => fmt::<usize>());
+        (value, i)
+    }
+
+    /// Returns decoded bytes (truncated to `N`) together with the full decoded length.
+    #[allow(clippy::cast_possible_truncation)] // intentional
+    const fn do_decode<const N: usize>(input: &[u8]) -> ([u8; N], usize) {
+        let (first_arc, pos) = Self::parse_arc(input, 0);
+        compile_assert!(pos < input.len(), "OID must have at least 2 arcs");
+        let (second_arc, mut pos) = Self::parse_arc(input, pos + 1);
+        compile_assert!(
+            first_arc <= 2 && (first_arc == 2 || second_arc < 40),
+            "Invalid first OID arcs: ", first_arc => fmt::<u64>(), ".", second_arc => fmt::<u64>()
+        );
+        let Some(mut subidentifier) = second_arc.checked_add(first_arc * 40) else {
+            compile_panic!("Second OID arc overflows u64");
+        };
+
+        let mut bytes = [0_u8; N];
+        let mut out_index = 0;
+        loop {
+            // Encode `subidentifier` in base 128, most significant group first.
+            let mut group_count = 1;
+            while group_count < 10 && subidentifier >> (7 * group_count) != 0 {
+                group_count += 1;
+            }
+            while group_count > 0 {
+                group_count -= 1;
+                let mut byte = (subidentifier >> (7 * group_count)) as u8 & 0x7f;
+                if group_count > 0 {
+                    byte |= 0x80;
+                }
+                if out_index < N {
+                    bytes[out_index] = byte;
+                }
+                out_index += 1;
+            }
+
+            if pos == input.len() {
+                break;
+            }
+            let (arc, new_pos) = Self::parse_arc(input, pos + 1);
+            subidentifier = arc;
+            pos = new_pos;
+        }
+        (bytes, out_index)
+    }
+}
+
+/// Converter of DER-encoded object identifiers (OIDs) into dotted strings, such as
+/// `1.2.840.113549.1.1.11`. The input must contain only the value bytes of the encoding,
+/// i.e., exclude the `OBJECT IDENTIFIER` tag and length. Arcs must fit into `u64`.
+///
+/// See [`Oid`] for the reverse conversion.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, DottedOid};
+/// const OID: &[u8] = &decode!(DottedOid, &[0x2b, 0x65, 0x70]);
+/// assert_eq!(OID, b"1.3.101.112"); // Ed25519
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DottedOid;
+
+impl DottedOid {
+    /// Converts the DER value bytes of an OID into a dotted OID string.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for the dotted string.
+    /// - Panics if `input` is not a valid DER-encoded OID.
+    pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = Self::do_decode::<N>(input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        Self::do_decode::<0>(input).1
+    }
+
+    /// Reads a base-128 subidentifier starting from `pos`. Returns it and the position after it.
+    const fn read_subidentifier(input: &[u8], pos: usize) -> (u64, usize) {
+        compile_assert!(
+            input[pos] != 0x80,
+            "Non-minimal OID subidentifier encoding at position ", pos => fmt::<usize>()
+        );
+        let mut value = 0_u64;
+        let mut i = pos;
+        loop {
+            compile_assert!(
+                i < input.len(),
+                "Unterminated OID subidentifier at position ", pos => fmt::<usize>()
+            );
+            compile_assert!(
+                value >> 57 == 0,
+                "OID subidentifier at position ", pos => fmt::<usize>(), " overflows u64"
+            );
+            let byte = input[i];
+            value = (value << 7) + (byte & 0x7f) as u64;
+            i += 1;
+            if byte & 0x80 == 0 {
+                return (value, i);
+            }
+        }
+    }
+
+    /// Returns decoded bytes (truncated to `N`) together with the full decoded length.
+    #[allow(clippy::cast_possible_truncation)] // intentional
+    const fn do_decode<const N: usize>(input: &[u8]) -> ([u8; N], usize) {
+        compile_assert!(!input.is_empty(), "Empty OID");
+
+        let mut bytes = [0_u8; N];
+        let mut out_index = 0;
+        let mut pos = 0;
+        let mut is_first = true;
+        while pos < input.len() {
+            let (subidentifier, new_pos) = Self::read_subidentifier(input, pos);
+            pos = new_pos;
+
+            let mut arc_index = 0;
+            let arc_count = if is_first { 2 } else { 1 };
+            while arc_index < arc_count {
+                let arc = match (is_first, arc_index) {
+                    (false, _) => subidentifier,
+                    (true, 0) if subidentifier < 80 => subidentifier / 40,
+                    (true, 0) => 2,
+                    (true, _) if subidentifier < 80 => subidentifier % 40,
+                    (true, _) => subidentifier - 80,
+                };
+                if out_index > 0 {
+                    if out_index < N {
+                        bytes[out_index] = b'.';
+                    }
+                    out_index += 1;
+                }
+
+                let mut digit_count = 1;
+                while digit_count < 20 && arc / 10_u64.pow(digit_count) != 0 {
+                    digit_count += 1;
+                }
+                while digit_count > 0 {
+                    digit_count -= 1;
+                    if out_index < N {
+                        bytes[out_index] = b'0' + (arc / 10_u64.pow(digit_count) % 10) as u8;
+                    }
+                    out_index += 1;
+                }
+                arc_index += 1;
+            }
+            is_first = false;
+        }
+        (bytes, out_index)
+    }
+}
@@ -0,0 +1,116 @@
+//! PackBits run-length decoder.
+
+use crate::{
+    decoder::{check_output_len, try_check_output_len},
+    error::{DecodeError, DecodeErrorKind},
+    panics::{compile_panic, fmt},
+};
+
+/// Decoder for the [PackBits] run-length encoding, used e.g. in TIFF, MacPaint images
+/// and some bitmap font formats.
+///
+/// Each run starts with a header byte `n` interpreted as a signed integer:
+///
+/// - `0..=127`: the following `n + 1` bytes are copied literally.
+/// - `-127..=-1`: the following byte is repeated `1 - n` times.
+/// - `-128`: no-op; the next byte is a header byte.
+///
+/// [PackBits]: https://en.wikipedia.org/wiki/PackBits
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Decoder, PackBits};
+/// const DATA: &[u8] = &decode!(
+///     PackBits,
+///     &decode!(
+///         Decoder::Hex.skip_whitespace(),
+///         b"fe aa 02 80 00 2a fd aa 03 80 00 2a 22 f7 aa",
+///     ),
+/// );
+/// assert_eq!(DATA.len(), 24);
+/// assert_eq!(DATA[..5], [0xaa, 0xaa, 0xaa, 0x80, 0]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackBits;
+
+impl PackBits {
+    /// Decodes `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` ends in the middle of a run.
+    pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = Self::do_decode::<N>(input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    /// Decodes `input` into a byte array, returning an error on invalid input.
+    /// See [`Decoder::try_decode()`](crate::Decoder::try_decode()) for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` ends in the middle of a run
+    /// ([`IncompleteInput`](DecodeErrorKind::IncompleteInput)), or decodes to a length
+    /// other than `N`.
+    pub const fn try_decode<const N: usize>(input: &[u8]) -> Result<[u8; N], DecodeError> {
+        let Ok((bytes, out_len)) = Self::try_decode_inner::<N>(input) else {
+            let kind = DecodeErrorKind::IncompleteInput;
+            return Err(DecodeError::new(kind, input.len()));
+        };
+        const_try!(try_check_output_len(input, out_len, N));
+        Ok(bytes)
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        Self::do_decode::<0>(input).1
+    }
+
+    /// Returns decoded bytes (truncated to `N`) together with the full decoded length.
+    const fn do_decode<const N: usize>(input: &[u8]) -> ([u8; N], usize) {
+        match Self::try_decode_inner::<N>(input) {
+            Ok(output) => output,
+            Err(run_pos) => compile_panic!(
+                "Unexpected end of PackBits input in run at position ", run_pos => fmt::<usize>()
+            ),
+        }
+    }
+
+    /// Same as [`Self::do_decode()`], but returns the position of the incomplete run
+    /// instead of panicking.
+    const fn try_decode_inner<const N: usize>(input: &[u8]) -> Result<([u8; N], usize), usize> {
+        let mut bytes = [0_u8; N];
+        let mut in_index = 0;
+        let mut out_index = 0;
+        while in_index < input.len() {
+            let header = input[in_index];
+            in_index += 1;
+            if header == 0x80 {
+                continue;
+            }
+
+            let (run_len, is_literal) = if header < 0x80 {
+                (header as usize + 1, true)
+            } else {
+                (257 - header as usize, false)
+            };
+            let data_len = if is_literal { run_len } else { 1 };
+            if in_index + data_len > input.len() {
+                return Err(in_index - 1);
+            }
+
+            let mut i = 0;
+            while i < run_len {
+                if out_index < N {
+                    bytes[out_index] = input[in_index + if is_literal { i } else { 0 }];
+                }
+                out_index += 1;
+                i += 1;
+            }
+            in_index += data_len;
+        }
+        Ok((bytes, out_index))
+    }
+}
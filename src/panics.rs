@@ -0,0 +1,85 @@
+//! Panic messages for compile-time errors.
+//!
+//! With the `detailed-panics` feature, messages are formatted with `compile-fmt` and include
+//! dynamic values, such as positions of invalid chars. Without it, the macros below accept
+//! the same args, but only keep the string literals, replacing dynamic values with `…`.
+
+#[cfg(feature = "detailed-panics")]
+pub(crate) use compile_fmt::{clip, clip_ascii, compile_assert, compile_panic, fmt, Ascii};
+
+#[cfg(not(feature = "detailed-panics"))]
+pub(crate) use self::fallback::{
+    clip, clip_ascii, compile_assert, compile_panic, fmt, panic_message, Ascii,
+};
+
+#[cfg(not(feature = "detailed-panics"))]
+mod fallback {
+    /// ASCII string, counterpart of `compile_fmt::Ascii`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub(crate) struct Ascii<'a>(&'a str);
+
+    impl<'a> Ascii<'a> {
+        pub(crate) const fn new(s: &'a str) -> Self {
+            let bytes = s.as_bytes();
+            let mut i = 0;
+            while i < bytes.len() {
+                assert!(bytes[i].is_ascii(), "String contains non-ASCII chars");
+                i += 1;
+            }
+            Self(s)
+        }
+    }
+
+    /// Format of a dynamic value, which is ignored without `compile-fmt`.
+    #[derive(Debug, Clone, Copy)]
+    pub(crate) struct Fmt;
+
+    #[allow(clippy::extra_unused_type_parameters)] // mirrors `compile_fmt::fmt()`
+    pub(crate) const fn fmt<T>() -> Fmt {
+        Fmt
+    }
+
+    pub(crate) const fn clip(_capacity: usize, _clip_with: &'static str) -> Fmt {
+        Fmt
+    }
+
+    pub(crate) const fn clip_ascii(_capacity: usize, _clip_with: &'static str) -> Fmt {
+        Fmt
+    }
+
+    macro_rules! compile_assert {
+        ($check:expr, $($arg:tt)+) => {{
+            if !$check {
+                $crate::panics::compile_panic!($($arg)+);
+            }
+        }};
+    }
+
+    macro_rules! compile_panic {
+        ($($arg:tt)+) => {
+            $crate::panics::panic_message!([] $($arg)+)
+        };
+    }
+
+    /// Concatenates string literals in args, evaluating and discarding dynamic values.
+    macro_rules! panic_message {
+        ([$($msg:tt)*] $arg:expr => $fmt:expr $(, $($rest:tt)*)?) => {{
+            let _ = ($arg, $fmt);
+            $crate::panics::panic_message!([$($msg)* "…",] $($($rest)*)?)
+        }};
+        ([$($msg:tt)*] $arg:literal $(, $($rest:tt)*)?) => {
+            $crate::panics::panic_message!([$($msg)* $arg,] $($($rest)*)?)
+        };
+        ([$($msg:tt)*] $arg:expr $(, $($rest:tt)*)?) => {{
+            let _ = $arg;
+            $crate::panics::panic_message!([$($msg)* "…",] $($($rest)*)?)
+        }};
+        ([$($msg:tt)*]) => {
+            ::core::panic!("{}", ::core::concat!($($msg)*))
+        };
+    }
+
+    pub(crate) use compile_assert;
+    pub(crate) use compile_panic;
+    pub(crate) use panic_message;
+}
@@ -0,0 +1,186 @@
+//! PGP word list decoder.
+
+use crate::{
+    decoder::check_output_len,
+    panics::{compile_assert, compile_panic, fmt},
+    words::{compare_word, next_word},
+};
+
+/// Decoder for the [PGP word list] (aka biometric word list), used to read fingerprints
+/// and other binary data aloud. Each byte is encoded with a single word; bytes at even positions
+/// (starting from 0) are encoded with two-syllable words from the "even" list, and bytes at odd
+/// positions with three-syllable words from the "odd" list. The alternation is verified
+/// during decoding, which allows detecting swapped, duplicated or omitted words.
+///
+/// Words are separated by whitespace and are case-insensitive.
+///
+/// [PGP word list]: https://en.wikipedia.org/wiki/PGP_word_list
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, PgpWords};
+/// const FINGERPRINT: [u8; 4] = PgpWords::decode(b"topmost Istanbul Pluto vagabond");
+/// assert_eq!(FINGERPRINT, [0xe5, 0x82, 0x94, 0xf2]);
+/// const SAME: &[u8] = &decode!(PgpWords, b"TOPMOST ISTANBUL PLUTO VAGABOND");
+/// assert_eq!(SAME, FINGERPRINT);
+/// ```
+///
+/// Words from the wrong list are caught in compile time:
+///
+/// ```compile_fail
+/// # use const_decoder::PgpWords;
+/// // `Istanbul` and `Pluto` are swapped
+/// const FINGERPRINT: [u8; 4] = PgpWords::decode(b"topmost Pluto Istanbul vagabond");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PgpWords;
+
+impl PgpWords {
+    /// Decodes `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` contains words not from the PGP word list, or words
+    ///   from the list not corresponding to their position.
+    pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = Self::do_decode::<N>(input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        Self::do_decode::<0>(input).1
+    }
+
+    #[allow(clippy::cast_possible_truncation)] // `index < 256`
+    const fn find(input: &[u8], word: (usize, usize), words: &[&[u8]; 256]) -> Option<u8> {
+        let mut i = 0;
+        while i < words.len() {
+            if compare_word(input, word.0, word.1, words[i], false).is_eq() {
+                return Some(i as u8);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Returns decoded bytes (truncated to `N`) together with the full decoded length.
+    const fn do_decode<const N: usize>(input: &[u8]) -> ([u8; N], usize) {
+        let mut bytes = [0_u8; N];
+        let mut pos = 0;
+        let mut out_index = 0;
+        loop {
+            let word = next_word(input, pos);
+            if word.0 == word.1 {
+                break;
+            }
+
+            let (expected, other) = if out_index % 2 == 0 {
+                (EVEN_WORDS, ODD_WORDS)
+            } else {
+                (ODD_WORDS, EVEN_WORDS)
+            };
+            let Some(byte) = Self::find(input, word, expected) else {
+                compile_assert!(
+                    Self::find(input, word, other).is_none(),
+                    "Word at position ", word.0 => fmt::<usize>(),
+                    " is from the wrong PGP word list for byte ", out_index => fmt::<usize>(),
+                    "; a word may be missing, duplicated or swapped"
+                );
+                compile_panic!(
+                    "Word at position ", word.0 => fmt::<usize>(), " is not in the PGP word list"
+                );
+            };
+            if out_index < N {
+                bytes[out_index] = byte;
+            }
+            out_index += 1;
+            pos = word.1;
+        }
+        (bytes, out_index)
+    }
+}
+
+/// Two-syllable words encoding bytes at even positions.
+#[rustfmt::skip]
+const EVEN_WORDS: &[&[u8]; 256] = &[
+    b"aardvark", b"absurd", b"accrue", b"acme", b"adrift", b"adult", b"afflict", b"ahead",
+    b"aimless", b"algol", b"allow", b"alone", b"ammo", b"ancient", b"apple", b"artist", b"assume",
+    b"athens", b"atlas", b"aztec", b"baboon", b"backfield", b"backward", b"banjo", b"beaming",
+    b"bedlamp", b"beehive", b"beeswax", b"befriend", b"belfast", b"berserk", b"billiard", b"bison",
+    b"blackjack", b"blockade", b"blowtorch", b"bluebird", b"bombast", b"bookshelf", b"brackish",
+    b"breadline", b"breakup", b"brickyard", b"briefcase", b"burbank", b"button", b"buzzard",
+    b"cement", b"chairlift", b"chatter", b"checkup", b"chisel", b"choking", b"chopper",
+    b"christmas", b"clamshell", b"classic", b"classroom", b"cleanup", b"clockwork", b"cobra",
+    b"commence", b"concert", b"cowbell", b"crackdown", b"cranky", b"crowfoot", b"crucial",
+    b"crumpled", b"crusade", b"cubic", b"dashboard", b"deadbolt", b"deckhand", b"dogsled",
+    b"dragnet", b"drainage", b"dreadful", b"drifter", b"dropper", b"drumbeat", b"drunken",
+    b"dupont", b"dwelling", b"eating", b"edict", b"egghead", b"eightball", b"endorse", b"endow",
+    b"enlist", b"erase", b"escape", b"exceed", b"eyeglass", b"eyetooth", b"facial", b"fallout",
+    b"flagpole", b"flatfoot", b"flytrap", b"fracture", b"framework", b"freedom", b"frighten",
+    b"gazelle", b"geiger", b"glitter", b"glucose", b"goggles", b"goldfish", b"gremlin", b"guidance",
+    b"hamlet", b"highchair", b"hockey", b"indoors", b"indulge", b"inverse", b"involve", b"island",
+    b"jawbone", b"keyboard", b"kickoff", b"kiwi", b"klaxon", b"locale", b"lockup", b"merit",
+    b"minnow", b"miser", b"mohawk", b"mural", b"music", b"necklace", b"neptune", b"newborn",
+    b"nightbird", b"oakland", b"obtuse", b"offload", b"optic", b"orca", b"payday", b"peachy",
+    b"pheasant", b"physique", b"playhouse", b"pluto", b"preclude", b"prefer", b"preshrunk",
+    b"printer", b"prowler", b"pupil", b"puppy", b"python", b"quadrant", b"quiver", b"quota",
+    b"ragtime", b"ratchet", b"rebirth", b"reform", b"regain", b"reindeer", b"rematch", b"repay",
+    b"retouch", b"revenge", b"reward", b"rhythm", b"ribcage", b"ringbolt", b"robust", b"rocker",
+    b"ruffled", b"sailboat", b"sawdust", b"scallion", b"scenic", b"scorecard", b"scotland",
+    b"seabird", b"select", b"sentence", b"shadow", b"shamrock", b"showgirl", b"skullcap",
+    b"skydive", b"slingshot", b"slowdown", b"snapline", b"snapshot", b"snowcap", b"snowslide",
+    b"solo", b"southward", b"soybean", b"spaniel", b"spearhead", b"spellbind", b"spheroid",
+    b"spigot", b"spindle", b"spyglass", b"stagehand", b"stagnate", b"stairway", b"standard",
+    b"stapler", b"steamship", b"sterling", b"stockman", b"stopwatch", b"stormy", b"sugar",
+    b"surmount", b"suspense", b"sweatband", b"swelter", b"tactics", b"talon", b"tapeworm",
+    b"tempest", b"tiger", b"tissue", b"tonic", b"topmost", b"tracker", b"transit", b"trauma",
+    b"treadmill", b"trojan", b"trouble", b"tumor", b"tunnel", b"tycoon", b"uncut", b"unearth",
+    b"unwind", b"uproot", b"upset", b"upshot", b"vapor", b"village", b"virus", b"vulcan", b"waffle",
+    b"wallet", b"watchword", b"wayside", b"willow", b"woodlark", b"zulu",
+];
+
+/// Three-syllable words encoding bytes at odd positions.
+#[rustfmt::skip]
+const ODD_WORDS: &[&[u8]; 256] = &[
+    b"adroitness", b"adviser", b"aftermath", b"aggregate", b"alkali", b"almighty", b"amulet",
+    b"amusement", b"antenna", b"applicant", b"apollo", b"armistice", b"article", b"asteroid",
+    b"atlantic", b"atmosphere", b"autopsy", b"babylon", b"backwater", b"barbecue", b"belowground",
+    b"bifocals", b"bodyguard", b"bookseller", b"borderline", b"bottomless", b"bradbury", b"bravado",
+    b"brazilian", b"breakaway", b"burlington", b"businessman", b"butterfat", b"camelot",
+    b"candidate", b"cannonball", b"capricorn", b"caravan", b"caretaker", b"celebrate", b"cellulose",
+    b"certify", b"chambermaid", b"cherokee", b"chicago", b"clergyman", b"coherence", b"combustion",
+    b"commando", b"company", b"component", b"concurrent", b"confidence", b"conformist",
+    b"congregate", b"consensus", b"consulting", b"corporate", b"corrosion", b"councilman",
+    b"crossover", b"crucifix", b"cumbersome", b"customer", b"dakota", b"decadence", b"december",
+    b"decimal", b"designing", b"detector", b"detergent", b"determine", b"dictator", b"dinosaur",
+    b"direction", b"disable", b"disbelief", b"disruptive", b"distortion", b"document", b"embezzle",
+    b"enchanting", b"enrollment", b"enterprise", b"equation", b"equipment", b"escapade", b"eskimo",
+    b"everyday", b"examine", b"existence", b"exodus", b"fascinate", b"filament", b"finicky",
+    b"forever", b"fortitude", b"frequency", b"gadgetry", b"galveston", b"getaway", b"glossary",
+    b"gossamer", b"graduate", b"gravity", b"guitarist", b"hamburger", b"hamilton", b"handiwork",
+    b"hazardous", b"headwaters", b"hemisphere", b"hesitate", b"hideaway", b"holiness", b"hurricane",
+    b"hydraulic", b"impartial", b"impetus", b"inception", b"indigo", b"inertia", b"infancy",
+    b"inferno", b"informant", b"insincere", b"insurgent", b"integrate", b"intention", b"inventive",
+    b"istanbul", b"jamaica", b"jupiter", b"leprosy", b"letterhead", b"liberty", b"maritime",
+    b"matchmaker", b"maverick", b"medusa", b"megaton", b"microscope", b"microwave", b"midsummer",
+    b"millionaire", b"miracle", b"misnomer", b"molasses", b"molecule", b"montana", b"monument",
+    b"mosquito", b"narrative", b"nebula", b"newsletter", b"norwegian", b"october", b"ohio",
+    b"onlooker", b"opulent", b"orlando", b"outfielder", b"pacific", b"pandemic", b"pandora",
+    b"paperweight", b"paragon", b"paragraph", b"paramount", b"passenger", b"pedigree", b"pegasus",
+    b"penetrate", b"perceptive", b"performance", b"pharmacy", b"phonetic", b"photograph",
+    b"pioneer", b"pocketful", b"politeness", b"positive", b"potato", b"processor", b"provincial",
+    b"proximate", b"puberty", b"publisher", b"pyramid", b"quantity", b"racketeer", b"rebellion",
+    b"recipe", b"recover", b"repellent", b"replica", b"reproduce", b"resistor", b"responsive",
+    b"retraction", b"retrieval", b"retrospect", b"revenue", b"revival", b"revolver", b"sandalwood",
+    b"sardonic", b"saturday", b"savagery", b"scavenger", b"sensation", b"sociable", b"souvenir",
+    b"specialist", b"speculate", b"stethoscope", b"stupendous", b"supportive", b"surrender",
+    b"suspicious", b"sympathy", b"tambourine", b"telephone", b"therapist", b"tobacco", b"tolerance",
+    b"tomorrow", b"torpedo", b"tradition", b"travesty", b"trombonist", b"truncated", b"typewriter",
+    b"ultimate", b"undaunted", b"underfoot", b"unicorn", b"unify", b"universe", b"unravel",
+    b"upcoming", b"vacancy", b"vagabond", b"vertigo", b"virginia", b"visitor", b"vocalist",
+    b"voyager", b"warranty", b"waterloo", b"whimsical", b"wichita", b"wilmington", b"wyoming",
+    b"yesteryear", b"yucatan",
+];
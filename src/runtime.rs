@@ -0,0 +1,354 @@
+//! Runtime decoding APIs requiring allocation or I/O.
+
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(all(feature = "std", feature = "zeroize"))]
+use zeroize::Zeroizing;
+
+use crate::{
+    decoder::{DecodeCursor, Decoder},
+    error::DecodeError,
+    wrappers::{Pem, PemOptions, SkipWhitespace, Skipper},
+};
+
+fn decode_to_vec(mut cursor: DecodeCursor, input: &[u8], capacity: usize) -> Vec<u8> {
+    let mut output = Vec::with_capacity(capacity);
+    loop {
+        let (new_cursor, byte) = cursor.next_byte(input);
+        cursor = new_cursor;
+        let Some(byte) = byte else {
+            break;
+        };
+        output.push(byte);
+    }
+    cursor.finish();
+    output
+}
+
+fn try_decode_to_vec(
+    mut cursor: DecodeCursor,
+    input: &[u8],
+    capacity: usize,
+) -> Result<Vec<u8>, DecodeError> {
+    let mut output = Vec::with_capacity(capacity);
+    loop {
+        let (new_cursor, byte) = cursor.try_next_byte(input)?;
+        cursor = new_cursor;
+        let Some(byte) = byte else {
+            break;
+        };
+        output.push(byte);
+    }
+    cursor.try_finish()?;
+    Ok(output)
+}
+
+/// Minimum input length processed by a single thread in [`Decoder::try_decode_parallel()`].
+#[cfg(feature = "rayon")]
+const MIN_PARALLEL_CHUNK_LEN: usize = 1 << 18;
+
+#[cfg(all(feature = "std", not(feature = "zeroize")))]
+fn read_to_end(mut reader: impl io::Read) -> io::Result<Vec<u8>> {
+    let mut input = Vec::new();
+    reader.read_to_end(&mut input)?;
+    Ok(input)
+}
+
+/// Reads `reader` into a buffer wiped on drop. The buffer is grown manually, so that
+/// reallocations do not leave copies of the input behind.
+#[cfg(all(feature = "std", feature = "zeroize"))]
+fn read_to_end(mut reader: impl io::Read) -> io::Result<Zeroizing<Vec<u8>>> {
+    const CHUNK_LEN: usize = 4_096;
+
+    let mut input = Zeroizing::new(Vec::new());
+    let mut chunk = Zeroizing::new([0_u8; CHUNK_LEN]);
+    loop {
+        let chunk_len = match reader.read(&mut *chunk) {
+            Ok(0) => break,
+            Ok(len) => len,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        if input.capacity() - input.len() < chunk_len {
+            let capacity = (input.len() + chunk_len).max(input.capacity() * 2);
+            let mut grown = Zeroizing::new(Vec::with_capacity(capacity));
+            grown.extend_from_slice(&input);
+            input = grown;
+        }
+        input.extend_from_slice(&chunk[..chunk_len]);
+    }
+    Ok(input)
+}
+
+#[cfg(feature = "std")]
+#[allow(clippy::needless_pass_by_value)] // used in `map_err()`
+fn invalid_data(err: DecodeError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+impl Decoder {
+    /// Decodes `input` into a vector. Unlike [`Self::decode()`], this method does not require
+    /// knowing the output length in advance, and thus is useful for runtime inputs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` contains invalid chars.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::Decoder;
+    /// let input = String::from("c0ffee");
+    /// assert_eq!(Decoder::Hex.decode_to_vec(input.as_bytes()), [0xc0, 0xff, 0xee]);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn decode_to_vec(self, input: &[u8]) -> Vec<u8> {
+        let cursor = DecodeCursor::new(self, input, None, false);
+        decode_to_vec(cursor, input, self.max_decoded_len(input.len()))
+    }
+
+    /// Decodes `input` into a vector, returning an error on invalid input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::{DecodeErrorKind, Decoder};
+    /// let err = Decoder::Hex.try_decode_to_vec(b"c0ffeecup").unwrap_err();
+    /// assert_eq!(err.kind(), DecodeErrorKind::InvalidSymbol { symbol: b'u' });
+    /// assert_eq!(err.position(), 7);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn try_decode_to_vec(self, input: &[u8]) -> Result<Vec<u8>, DecodeError> {
+        let cursor = DecodeCursor::new(self, input, None, false);
+        try_decode_to_vec(cursor, input, self.max_decoded_len(input.len()))
+    }
+
+    /// Decodes `input` into a vector in parallel on the global [`rayon`] thread pool.
+    /// Large inputs are split into chunks at boundaries of char groups decoded to whole bytes
+    /// (e.g., 2 chars for hex or 4 chars for base64), and the chunks are decoded
+    /// in parallel directly into the output. The number of chunks is determined by
+    /// [`rayon::current_num_threads()`].
+    ///
+    /// The output and errors are the same as for [`Self::try_decode_to_vec()`]; if several
+    /// chunks are invalid, the error for the earliest chunk is returned. The input is decoded
+    /// sequentially on the current thread in the following cases:
+    ///
+    /// - The input is small (less than 256 KiB per thread).
+    /// - The decoder keeps state across the entire input (e.g., hex decoders
+    ///   with [`HexCase::Uniform`](crate::HexCase) or [skipped underscores](Self::allow_underscores())).
+    /// - The input contains padding chars `=` before the final chunk, e.g. if it is
+    ///   a concatenation of several padded base64 strings. Decoders skip padding, which
+    ///   would break alignment of the following chunks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::Decoder;
+    /// let input = "c0ffee".repeat(100_000);
+    /// let bytes = Decoder::Hex.try_decode_parallel(input.as_bytes())?;
+    /// assert_eq!(bytes.len(), 300_000);
+    /// assert_eq!(bytes[..3], [0xc0, 0xff, 0xee]);
+    /// # Ok::<_, const_decoder::DecodeError>(())
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    pub fn try_decode_parallel(self, input: &[u8]) -> Result<Vec<u8>, DecodeError> {
+        let chunk_count = rayon::current_num_threads().min(input.len() / MIN_PARALLEL_CHUNK_LEN);
+        self.try_decode_in_chunks(input, chunk_count)
+    }
+
+    /// Decodes `input` split into `chunk_count` chunks in parallel.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn try_decode_in_chunks(
+        self,
+        input: &[u8],
+        chunk_count: usize,
+    ) -> Result<Vec<u8>, DecodeError> {
+        let Some(group_len) = self.independent_group_len() else {
+            return self.try_decode_to_vec(input);
+        };
+        let chunk_len = match input.len().checked_div(chunk_count) {
+            Some(len) if chunk_count > 1 => len / group_len * group_len,
+            _ => 0,
+        };
+        if chunk_len == 0 {
+            return self.try_decode_to_vec(input);
+        }
+
+        let (head, tail) = input.split_at(chunk_len * (chunk_count - 1));
+        if head.contains(&b'=') {
+            // Padding chars are skipped by decoders, which would break group alignment.
+            return self.try_decode_to_vec(input);
+        }
+
+        let mut output = alloc::vec![0_u8; self.max_decoded_len(input.len())];
+        let (head_output, tail_output) = output.split_at_mut(self.max_decoded_len(head.len()));
+        let (head_results, tail_result) = rayon::join(
+            || {
+                head.par_chunks(chunk_len)
+                    .zip(head_output.par_chunks_mut(self.max_decoded_len(chunk_len)))
+                    .enumerate()
+                    .map(|(i, (chunk, chunk_output))| {
+                        self.try_decode_into(chunk, chunk_output)
+                            .map_err(|err| err.with_offset(i * chunk_len))
+                    })
+                    .collect::<Vec<_>>()
+            },
+            || {
+                self.try_decode_into(tail, tail_output)
+                    .map_err(|err| err.with_offset(head.len()))
+            },
+        );
+        // Results are ordered by chunk, so the earliest error is returned.
+        for result in head_results {
+            result?;
+        }
+        let tail_len = tail_result?;
+
+        output.truncate(self.max_decoded_len(head.len()) + tail_len);
+        Ok(output)
+    }
+
+    /// Reads all data from `reader` and decodes it.
+    ///
+    /// With the `zeroize` crate feature, the buffer holding the read data is wiped after decoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns I/O errors from the reader as is. Returns an error with
+    /// [`InvalidData`](io::ErrorKind::InvalidData) kind wrapping a [`DecodeError`]
+    /// if the data cannot be decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::Decoder;
+    /// let reader: &[u8] = b"dGVzdA==";
+    /// assert_eq!(Decoder::Base64.decode_reader(reader)?, b"test");
+    /// let reader: &[u8] = b"dGVzdA?";
+    /// assert!(Decoder::Base64.decode_reader(reader).is_err());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn decode_reader(self, reader: impl io::Read) -> io::Result<Vec<u8>> {
+        let input = read_to_end(reader)?;
+        self.try_decode_to_vec(&input).map_err(invalid_data)
+    }
+}
+
+impl SkipWhitespace {
+    /// Decodes `input` into a vector. See [`Decoder::decode_to_vec()`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` contains invalid chars.
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn decode_to_vec(self, input: &[u8]) -> Vec<u8> {
+        let cursor = DecodeCursor::new(self.0, input, Some(Skipper::Whitespace), false);
+        decode_to_vec(cursor, input, self.0.max_decoded_len(input.len()))
+    }
+
+    /// Decodes `input` into a vector, returning an error on invalid input.
+    /// See [`Decoder::try_decode_to_vec()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is invalid.
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn try_decode_to_vec(self, input: &[u8]) -> Result<Vec<u8>, DecodeError> {
+        let cursor = DecodeCursor::new(self.0, input, Some(Skipper::Whitespace), false);
+        try_decode_to_vec(cursor, input, self.0.max_decoded_len(input.len()))
+    }
+
+    /// Reads all data from `reader` and decodes it. See [`Decoder::decode_reader()`]
+    /// for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns I/O errors from the reader as is. Returns an error with
+    /// [`InvalidData`](io::ErrorKind::InvalidData) kind wrapping a [`DecodeError`]
+    /// if the data cannot be decoded.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn decode_reader(self, reader: impl io::Read) -> io::Result<Vec<u8>> {
+        let input = read_to_end(reader)?;
+        self.try_decode_to_vec(&input).map_err(invalid_data)
+    }
+}
+
+impl Pem {
+    /// Decodes `input` into a vector. See [`Decoder::decode_to_vec()`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` contains invalid chars.
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn decode_to_vec(input: &[u8]) -> Vec<u8> {
+        PemOptions::DEFAULT.decode_to_vec(input)
+    }
+
+    /// Decodes `input` into a vector, returning an error on invalid input.
+    /// See [`Decoder::try_decode_to_vec()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::{DecodeErrorKind, Pem};
+    /// let pem = "-----BEGIN PUBLIC KEY-----\ndGVzdA==\n-----END PUBLIC KEY-----\n";
+    /// assert_eq!(Pem::try_decode_to_vec(pem.as_bytes())?, b"test");
+    ///
+    /// let err = Pem.skip_preamble().try_decode_to_vec(b"dGVzdA==").unwrap_err();
+    /// assert_eq!(err.kind(), DecodeErrorKind::MissingHeader);
+    /// # Ok::<_, const_decoder::DecodeError>(())
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn try_decode_to_vec(input: &[u8]) -> Result<Vec<u8>, DecodeError> {
+        PemOptions::DEFAULT.try_decode_to_vec(input)
+    }
+}
+
+impl PemOptions {
+    /// Decodes `input` into a vector. See [`Decoder::decode_to_vec()`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Self::decode()`], except for output length mismatch.
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn decode_to_vec(self, input: &[u8]) -> Vec<u8> {
+        let skipper = Some(Skipper::Pem(self));
+        let cursor = DecodeCursor::new(Decoder::Base64, input, skipper, self.reject_trailing_data);
+        decode_to_vec(cursor, input, self.max_decoded_len(input.len()))
+    }
+
+    /// Decodes `input` into a vector, returning an error on invalid input.
+    /// See [`Decoder::try_decode_to_vec()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same situations as [`Self::try_decode()`], except for output
+    /// length mismatch.
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn try_decode_to_vec(self, input: &[u8]) -> Result<Vec<u8>, DecodeError> {
+        let skipper = Some(Skipper::Pem(self));
+        let cursor =
+            DecodeCursor::try_new(Decoder::Base64, input, skipper, self.reject_trailing_data)?;
+        try_decode_to_vec(cursor, input, self.max_decoded_len(input.len()))
+    }
+}
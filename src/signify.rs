@@ -0,0 +1,154 @@
+//! Decoder for signify / minisign keys and signatures.
+
+use crate::{
+    decoder::Decoder,
+    panics::{compile_assert, fmt},
+};
+
+/// Maximum supported decoded length of the base64 line.
+const MAX_DECODED_LEN: usize = 2 + 8 + 64;
+
+/// Parsed [signify] or [minisign] public key (`N == 32`) or signature (`N == 64`).
+///
+/// The input is the contents of a `.pub` or `.sig` file: an optional `untrusted comment: ...`
+/// line followed by a base64-encoded line. Any lines after the base64 line (e.g., trusted
+/// comments in minisign signatures) are ignored. The decoded line must consist of a 2-byte
+/// algorithm tag (`Ed`, or `ED` for prehashed minisign signatures), an 8-byte key ID and
+/// the key or signature bytes.
+///
+/// [signify]: https://man.openbsd.org/signify.1
+/// [minisign]: https://jedisct1.github.io/minisign/
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::Signify;
+/// const PUBLIC_KEY: Signify<32> = Signify::parse(
+///     b"untrusted comment: minisign public key E7620F1842B4E81F
+///     RWQf6LRCGA9i53mlYecO4IzT51TGPpvWucNSCh1CBM0QTaLn73Y7GFO3",
+/// );
+/// const KEY_ID: [u8; 8] = PUBLIC_KEY.key_id();
+/// // minisign displays the key ID as a little-endian number
+/// assert_eq!(u64::from_le_bytes(KEY_ID), 0xe762_0f18_42b4_e81f);
+/// assert_eq!(PUBLIC_KEY.data()[..4], [0x79, 0xa5, 0x61, 0xe7]);
+/// ```
+///
+/// Values with an unexpected length are caught in compile time:
+///
+/// ```compile_fail
+/// # use const_decoder::Signify;
+/// const SIGNATURE: Signify<64> =
+///     Signify::parse(b"RWQf6LRCGA9i53mlYecO4IzT51TGPpvWucNSCh1CBM0QTaLn73Y7GFO3");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Signify<const N: usize> {
+    algorithm: [u8; 2],
+    key_id: [u8; 8],
+    data: [u8; N],
+}
+
+impl<const N: usize> Signify<N> {
+    /// Parses a public key or signature from the contents of a signify / minisign file.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `N` is not 32 or 64.
+    /// - Panics if the base64 line is invalid, or has an unexpected decoded length.
+    /// - Panics if the algorithm tag is unexpected.
+    pub const fn parse(input: &[u8]) -> Self {
+        compile_assert!(
+            N == 32 || N == 64,
+            "Unsupported signify data length ", N => fmt::<usize>(),
+            "; expected 32 (public keys) or 64 (signatures)"
+        );
+        let line = Self::base64_line(input);
+        let (bytes, len) = Decoder::Base64.decode_prefix::<MAX_DECODED_LEN>(line);
+        compile_assert!(
+            len == 10 + N,
+            "signify data decodes to ", len => fmt::<usize>(), " bytes, while ",
+            10 + N => fmt::<usize>(), " bytes are expected"
+        );
+
+        let algorithm = [bytes[0], bytes[1]];
+        let is_prehashed = algorithm[0] == b'E' && algorithm[1] == b'D' && N == 64;
+        compile_assert!(
+            (algorithm[0] == b'E' && algorithm[1] == b'd') || is_prehashed,
+            "Unexpected signify algorithm tag '", algorithm[0] as char => fmt::<char>(),
+            algorithm[1] as char => fmt::<char>(), "'; expected 'Ed' (Ed25519)"
+        );
+
+        let mut key_id = [0_u8; 8];
+        let mut i = 0;
+        while i < 8 {
+            key_id[i] = bytes[2 + i];
+            i += 1;
+        }
+        let mut data = [0_u8; N];
+        i = 0;
+        while i < N {
+            data[i] = bytes[10 + i];
+            i += 1;
+        }
+        Self {
+            algorithm,
+            key_id,
+            data,
+        }
+    }
+
+    /// Returns the base64-encoded line, skipping the untrusted comment line (if any)
+    /// and any trailing lines.
+    const fn base64_line(input: &[u8]) -> &[u8] {
+        const COMMENT_PREFIX: &[u8] = b"untrusted comment:";
+
+        let mut has_comment = input.len() >= COMMENT_PREFIX.len();
+        let mut i = 0;
+        while has_comment && i < COMMENT_PREFIX.len() {
+            has_comment = input[i] == COMMENT_PREFIX[i];
+            i += 1;
+        }
+
+        let mut line = input;
+        if has_comment {
+            while let Some((&ch, rest)) = line.split_first() {
+                line = rest;
+                if ch == b'\n' {
+                    break;
+                }
+            }
+        }
+        // Skip leading whitespace, e.g. indentation.
+        while let Some((&ch, rest)) = line.split_first() {
+            if !ch.is_ascii_whitespace() {
+                break;
+            }
+            line = rest;
+        }
+
+        let mut line_len = 0;
+        while line_len < line.len() && !line[line_len].is_ascii_whitespace() {
+            line_len += 1;
+        }
+        while line.len() > line_len {
+            if let Some((_, rest)) = line.split_last() {
+                line = rest;
+            }
+        }
+        line
+    }
+
+    /// Returns the algorithm tag: `*b"Ed"`, or `*b"ED"` for prehashed minisign signatures.
+    pub const fn algorithm(&self) -> [u8; 2] {
+        self.algorithm
+    }
+
+    /// Returns the 8-byte key ID, in the order it is stored.
+    pub const fn key_id(&self) -> [u8; 8] {
+        self.key_id
+    }
+
+    /// Returns the Ed25519 public key or signature.
+    pub const fn data(&self) -> [u8; N] {
+        self.data
+    }
+}
@@ -0,0 +1,165 @@
+//! Decoders for SQL binary literals.
+
+use crate::{
+    decoder::{check_output_len, Decoder},
+    macros::DecoderWrapper,
+    panics::{compile_assert, fmt},
+    wrappers::SkipWhitespace,
+};
+
+/// Decoder for [PostgreSQL `bytea`][bytea] values in their text representation, as output
+/// by `psql` or `pg_dump`. Both formats are supported and detected automatically:
+///
+/// - The hex format (the default since version 9.0): `\x` followed by hex digits, e.g. `\xdeadbeef`.
+///   Whitespace between digits is ignored.
+/// - The legacy escape format: bytes are written as is, except for `\\` denoting a backslash
+///   and `\ooo` (3 octal digits) denoting an arbitrary byte.
+///
+/// Note that the backslashes are a part of the value; they must be escaped in a Rust
+/// string literal or written in a raw literal.
+///
+/// [bytea]: https://www.postgresql.org/docs/current/datatype-binary.html
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, PgBytea};
+/// const HEX: [u8; 4] = PgBytea::decode(br"\xdeadbeef");
+/// assert_eq!(HEX, [0xde, 0xad, 0xbe, 0xef]);
+/// const ESCAPED: &[u8] = &decode!(PgBytea, br"a\000\\b\377");
+/// assert_eq!(ESCAPED, b"a\0\\b\xff");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PgBytea;
+
+impl PgBytea {
+    const HEX: SkipWhitespace = Decoder::Hex.skip_whitespace();
+
+    /// Decodes `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` contains invalid hex digits (in the hex format) or escape sequences
+    ///   (in the escape format).
+    pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+        if let Some(hex) = Self::hex_digits(input) {
+            return Self::HEX.decode(hex);
+        }
+        let (bytes, out_len) = Self::do_decode_escaped::<N>(input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        if let Some(hex) = Self::hex_digits(input) {
+            DecoderWrapper(Self::HEX).decode_len(hex)
+        } else {
+            Self::do_decode_escaped::<0>(input).1
+        }
+    }
+
+    /// Returns hex digits if the input is in the hex format.
+    const fn hex_digits(input: &[u8]) -> Option<&[u8]> {
+        match input {
+            [b'\\', b'x', hex @ ..] => Some(hex),
+            _ => None,
+        }
+    }
+
+    /// Returns decoded bytes (truncated to `N`) together with the full decoded length.
+    const fn do_decode_escaped<const N: usize>(input: &[u8]) -> ([u8; N], usize) {
+        let mut bytes = [0_u8; N];
+        let mut in_index = 0;
+        let mut out_index = 0;
+        while in_index < input.len() {
+            let mut byte = input[in_index];
+            if byte == b'\\' {
+                let (value, escape_len) = Self::read_escape(input, in_index);
+                byte = value;
+                in_index += escape_len;
+            } else {
+                in_index += 1;
+            }
+            if out_index < N {
+                bytes[out_index] = byte;
+            }
+            out_index += 1;
+        }
+        (bytes, out_index)
+    }
+
+    const fn is_octal_digit(input: &[u8], pos: usize) -> bool {
+        pos < input.len() && matches!(input[pos], b'0'..=b'7')
+    }
+
+    /// Reads an escape sequence starting with a backslash at `pos`. Returns the escaped byte
+    /// and the length of the sequence.
+    const fn read_escape(input: &[u8], pos: usize) -> (u8, usize) {
+        if pos + 1 < input.len() && input[pos + 1] == b'\\' {
+            return (b'\\', 2);
+        }
+        compile_assert!(
+            Self::is_octal_digit(input, pos + 1)
+                && Self::is_octal_digit(input, pos + 2)
+                && Self::is_octal_digit(input, pos + 3),
+            "Invalid escape sequence at position ", pos => fmt::<usize>(),
+            "; expected `\\\\` or a backslash followed by 3 octal digits"
+        );
+        compile_assert!(
+            input[pos + 1] <= b'3',
+            "Octal escape sequence at position ", pos => fmt::<usize>(), " overflows a byte"
+        );
+        let value = ((input[pos + 1] - b'0') << 6)
+            + ((input[pos + 2] - b'0') << 3)
+            + (input[pos + 3] - b'0');
+        (value, 4)
+    }
+}
+
+/// Decoder for SQL hex literals in the standard `X'...'` form (e.g., as output by `mysqldump`
+/// or the `.dump` command of `sqlite3`) or in the `0x...` form (e.g., as used by SQL Server).
+/// The prefix is case-insensitive, as are hex digits.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, SqlHex};
+/// const STANDARD: [u8; 2] = SqlHex::decode(b"X'AB12'");
+/// assert_eq!(STANDARD, [0xab, 0x12]);
+/// const PREFIXED: &[u8] = &decode!(SqlHex, b"0xab12");
+/// assert_eq!(PREFIXED, STANDARD);
+/// ```
+///
+/// Inputs without the literal wrapper are caught in compile time:
+///
+/// ```compile_fail
+/// # use const_decoder::SqlHex;
+/// const BYTES: [u8; 2] = SqlHex::decode(b"AB12");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SqlHex;
+
+impl SqlHex {
+    /// Decodes `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `input` does not have the `X'...'` or `0x...` form.
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` contains invalid hex digits, or an odd number of them.
+    pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+        Decoder::Hex.decode(Self::hex_digits(input))
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        DecoderWrapper(Decoder::Hex).decode_len(Self::hex_digits(input))
+    }
+
+    const fn hex_digits(input: &[u8]) -> &[u8] {
+        match input {
+            [b'X' | b'x', b'\'', hex @ .., b'\''] | [b'0', b'x' | b'X', hex @ ..] => hex,
+            _ => panic!("SQL hex literal must have the `X'...'` or `0x...` form"),
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Stellar strkey decoder.
+
+use crate::{
+    crc16::Crc16,
+    decoder::{try_check_input_len, Decoder},
+    error::{DecodeError, DecodeErrorKind},
+    panics::{compile_assert, fmt},
+};
+
+/// Type of a [Stellar strkey], determined by its version byte (and thus the first char).
+/// The decoder outputs the 32-byte payload of the strkey after verifying its version byte
+/// and the CRC-16/XMODEM checksum.
+///
+/// [Stellar strkey]: https://github.com/stellar/stellar-protocol/blob/master/ecosystem/sep-0023.md
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Strkey};
+/// const ACCOUNT: [u8; 32] =
+///     Strkey::AccountId.decode(b"GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ");
+/// assert_eq!(ACCOUNT[..4], [0x3f, 0x0c, 0x34, 0xbf]);
+/// const SEED: &[u8] = &decode!(
+///     Strkey::Seed,
+///     b"SCZANGBA5YHTNYVVV4C3U252E2B6P6F5T3U6MM63WBSBZATAQI3EBTQ4",
+/// );
+/// assert_eq!(SEED.len(), 32);
+/// ```
+///
+/// Strkeys with an unexpected type or an invalid checksum are caught in compile time:
+///
+/// ```compile_fail
+/// # use const_decoder::Strkey;
+/// const ACCOUNT: [u8; 32] =
+///     Strkey::AccountId.decode(b"SCZANGBA5YHTNYVVV4C3U252E2B6P6F5T3U6MM63WBSBZATAQI3EBTQ4");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Strkey {
+    /// Ed25519 public key of an account (`G...`).
+    AccountId,
+    /// Ed25519 secret seed (`S...`).
+    Seed,
+    /// Pre-authorized transaction hash (`T...`).
+    PreAuthTx,
+    /// SHA-256 hash used as a signer (`X...`).
+    Sha256Hash,
+}
+
+impl Strkey {
+    /// Length of a strkey with a 32-byte payload in its text form.
+    pub const ENCODED_LEN: usize = 56;
+    /// Length of the decoded payload in bytes.
+    pub const DECODED_LEN: usize = 32;
+
+    const BASE32: Decoder = Decoder::custom("ABCDEFGHIJKLMNOPQRSTUVWXYZ234567");
+
+    const fn version_byte(self) -> u8 {
+        match self {
+            Self::AccountId => 6 << 3,
+            Self::Seed => 18 << 3,
+            Self::PreAuthTx => 19 << 3,
+            Self::Sha256Hash => 23 << 3,
+        }
+    }
+
+    const fn prefix(self) -> char {
+        match self {
+            Self::AccountId => 'G',
+            Self::Seed => 'S',
+            Self::PreAuthTx => 'T',
+            Self::Sha256Hash => 'X',
+        }
+    }
+
+    /// Decodes `input` into the 32-byte payload.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `input` does not have 56 chars, or contains chars outside the base32 alphabet.
+    /// - Panics if the version byte does not correspond to this strkey type.
+    /// - Panics if the checksum is invalid.
+    pub const fn decode(self, input: &[u8]) -> [u8; 32] {
+        compile_assert!(
+            input.len() == Self::ENCODED_LEN,
+            "Stellar strkey must have 56 chars, got ", input.len() => fmt::<usize>()
+        );
+        let raw: [u8; 35] = Self::BASE32.decode(input);
+        compile_assert!(
+            raw[0] == self.version_byte(),
+            "Unexpected Stellar strkey version byte ", raw[0] => fmt::<u8>(),
+            "; expected a strkey starting with '", self.prefix() => fmt::<char>(), "'"
+        );
+        let checksum = Crc16::Xmodem.checksum_of_prefix(&raw, 33);
+        let trailer = u16::from_le_bytes([raw[33], raw[34]]);
+        compile_assert!(
+            checksum == trailer,
+            "Stellar strkey checksum mismatch: computed ", checksum => fmt::<u16>(),
+            ", while the strkey contains ", trailer => fmt::<u16>()
+        );
+
+        Self::payload(&raw)
+    }
+
+    /// Decodes `input` into the 32-byte payload, returning an error on invalid input.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if `input` does not have 56 chars, or contains chars outside
+    ///   the base32 alphabet.
+    /// - Returns an [`InvalidSymbol`](DecodeErrorKind::InvalidSymbol) error at position 0
+    ///   if the version byte does not correspond to this strkey type.
+    /// - Returns a [`ChecksumMismatch`](DecodeErrorKind::ChecksumMismatch) error if the checksum
+    ///   is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use const_decoder::{DecodeErrorKind, Strkey};
+    /// let err = Strkey::AccountId
+    ///     .try_decode(b"GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGA")
+    ///     .unwrap_err();
+    /// assert_eq!(err.kind(), DecodeErrorKind::ChecksumMismatch);
+    /// ```
+    pub const fn try_decode(self, input: &[u8]) -> Result<[u8; 32], DecodeError> {
+        if let Err(err) = try_check_input_len(input, Self::ENCODED_LEN) {
+            return Err(err);
+        }
+        let raw: [u8; 35] = match Self::BASE32.try_decode(input) {
+            Ok(raw) => raw,
+            Err(err) => return Err(err),
+        };
+        if raw[0] != self.version_byte() {
+            let kind = DecodeErrorKind::InvalidSymbol { symbol: input[0] };
+            return Err(DecodeError::new(kind, 0));
+        }
+        let checksum = Crc16::Xmodem.checksum_of_prefix(&raw, 33);
+        if checksum != u16::from_le_bytes([raw[33], raw[34]]) {
+            let kind = DecodeErrorKind::ChecksumMismatch;
+            return Err(DecodeError::new(kind, input.len()));
+        }
+        Ok(Self::payload(&raw))
+    }
+
+    const fn payload(raw: &[u8; 35]) -> [u8; 32] {
+        let mut payload = [0_u8; 32];
+        let mut i = 0;
+        while i < 32 {
+            payload[i] = raw[i + 1];
+            i += 1;
+        }
+        payload
+    }
+}
@@ -0,0 +1,1139 @@
+//! Transcoders from legacy text encodings into UTF-8.
+
+use crate::{
+    decoder::{check_output_len, try_check_output_len},
+    error::{DecodeError, DecodeErrorKind},
+    panics::{compile_panic, fmt},
+};
+
+/// Encodes a Unicode scalar value as UTF-8. Returns the encoded bytes and their number.
+#[allow(clippy::cast_possible_truncation)] // intended
+const fn encode_utf8(code_point: u32) -> ([u8; 4], usize) {
+    if code_point < 0x80 {
+        ([code_point as u8, 0, 0, 0], 1)
+    } else if code_point < 0x800 {
+        let bytes = [
+            0xc0 | (code_point >> 6) as u8,
+            0x80 | (code_point & 0x3f) as u8,
+            0,
+            0,
+        ];
+        (bytes, 2)
+    } else if code_point < 0x1_0000 {
+        let bytes = [
+            0xe0 | (code_point >> 12) as u8,
+            0x80 | ((code_point >> 6) & 0x3f) as u8,
+            0x80 | (code_point & 0x3f) as u8,
+            0,
+        ];
+        (bytes, 3)
+    } else {
+        let bytes = [
+            0xf0 | (code_point >> 18) as u8,
+            0x80 | ((code_point >> 12) & 0x3f) as u8,
+            0x80 | ((code_point >> 6) & 0x3f) as u8,
+            0x80 | (code_point & 0x3f) as u8,
+        ];
+        (bytes, 4)
+    }
+}
+
+/// Writes the UTF-8 encoding of a code point to the `$bytes` buffer starting from `$out_index`,
+/// skipping bytes not fitting into the buffer. A macro rather than a function to avoid
+/// copying the buffer.
+macro_rules! write_utf8 {
+    ($bytes:ident, $out_index:ident, $code_point:expr) => {{
+        let (encoded, len) = encode_utf8($code_point);
+        let mut i = 0;
+        while i < len {
+            if $out_index < $bytes.len() {
+                $bytes[$out_index] = encoded[i];
+            }
+            $out_index += 1;
+            i += 1;
+        }
+    }};
+}
+
+/// Unwraps the result of a fallible transcoding, panicking on an error.
+const fn unwrap_output<const N: usize>(
+    result: Result<([u8; N], usize), TextError>,
+    input: &[u8],
+) -> ([u8; N], usize) {
+    match result {
+        Ok(output) => output,
+        Err(err) => err.panic(input),
+    }
+}
+
+/// Converts the result of a fallible transcoding into the public form, checking
+/// the output length.
+const fn try_output<const N: usize>(
+    result: Result<([u8; N], usize), TextError>,
+    input: &[u8],
+) -> Result<[u8; N], DecodeError> {
+    let (bytes, out_len) = match result {
+        Ok(output) => output,
+        Err(err) => return Err(err.into_public(input)),
+    };
+    const_try!(try_check_output_len(input, out_len, N));
+    Ok(bytes)
+}
+
+/// Transcoder of raw UTF-16 bytes into UTF-8. Surrogate pairs are validated; unpaired
+/// surrogates lead to a panic (or an error for [`Self::try_decode()`]).
+///
+/// The transcoder works with raw bytes; to transcode UTF-16 bytes encoded in hex or another
+/// supported encoding, nest [`decode!`](crate::decode) invocations.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Decoder, Utf16};
+/// const TEXT: [u8; 4] = Utf16::Le.decode(b"t\0e\0s\0t\0");
+/// assert_eq!(TEXT, *b"test");
+///
+/// // Transcoding from hex-encoded bytes:
+/// const HEX_TEXT: &[u8] = &decode!(
+///     Utf16::Be,
+///     &decode!(Decoder::Hex, b"00480069d83dde00"),
+/// );
+/// assert_eq!(HEX_TEXT, "Hi😀".as_bytes());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Utf16 {
+    /// Little-endian UTF-16 (UTF-16LE).
+    Le,
+    /// Big-endian UTF-16 (UTF-16BE).
+    Be,
+}
+
+impl Utf16 {
+    const fn code_unit(self, input: &[u8], pos: usize) -> u32 {
+        let (high, low) = match self {
+            Self::Le => (input[pos + 1], input[pos]),
+            Self::Be => (input[pos], input[pos + 1]),
+        };
+        ((high as u32) << 8) + low as u32
+    }
+
+    /// Returns the BOM matching the endianness, which is stripped by [`SkipBom`](crate::SkipBom).
+    pub(crate) const fn bom(self) -> &'static [u8] {
+        match self {
+            Self::Le => b"\xff\xfe",
+            Self::Be => b"\xfe\xff",
+        }
+    }
+
+    /// Transcodes `input` into a UTF-8 byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` has an odd length or contains unpaired surrogates.
+    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = unwrap_output(self.do_decode::<N>(input), input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    /// Transcodes `input` into a UTF-8 byte array, returning an error on invalid input.
+    /// See [`Decoder::try_decode()`](crate::Decoder::try_decode()) for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` has an odd length
+    /// ([`IncompleteInput`](DecodeErrorKind::IncompleteInput)), contains unpaired surrogates
+    /// ([`InvalidSymbol`](DecodeErrorKind::InvalidSymbol) at the start of the surrogate),
+    /// or transcodes to a length other than `N`.
+    pub const fn try_decode<const N: usize>(self, input: &[u8]) -> Result<[u8; N], DecodeError> {
+        try_output(self.do_decode::<N>(input), input)
+    }
+
+    pub(crate) const fn decode_len(self, input: &[u8]) -> usize {
+        unwrap_output(self.do_decode::<0>(input), input).1
+    }
+
+    const fn do_decode<const N: usize>(self, input: &[u8]) -> Result<([u8; N], usize), TextError> {
+        if input.len() % 2 != 0 {
+            return Err(TextError::OddLength);
+        }
+
+        let mut bytes = [0_u8; N];
+        let mut in_index = 0;
+        let mut out_index = 0;
+        while in_index < input.len() {
+            let unit = self.code_unit(input, in_index);
+            let code_point = if unit >= 0xd800 && unit < 0xdc00 {
+                let low_index = in_index + 2;
+                let low = if low_index < input.len() {
+                    self.code_unit(input, low_index)
+                } else {
+                    0
+                };
+                if low < 0xdc00 || low >= 0xe000 {
+                    return Err(TextError::Utf16HighSurrogate(in_index));
+                }
+                in_index += 2;
+                0x1_0000 + ((unit - 0xd800) << 10) + (low - 0xdc00)
+            } else if unit >= 0xdc00 && unit < 0xe000 {
+                return Err(TextError::Utf16LowSurrogate(in_index));
+            } else {
+                unit
+            };
+            write_utf8!(bytes, out_index, code_point);
+            in_index += 2;
+        }
+        Ok((bytes, out_index))
+    }
+}
+
+/// Transcoder of Latin-1 (ISO-8859-1) bytes into UTF-8. Since Latin-1 maps each byte
+/// to the Unicode code point with the same value, any input is valid.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Latin1};
+/// const GREETING: &str = match core::str::from_utf8(&decode!(Latin1, b"Gr\xfc\xdfe")) {
+///     Ok(s) => s,
+///     Err(_) => unreachable!(),
+/// };
+/// assert_eq!(GREETING, "Grüße");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Latin1;
+
+impl Latin1 {
+    /// Transcodes `input` into a UTF-8 byte array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the provided length is insufficient or too large for `input`.
+    pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = Self::do_decode::<N>(input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    /// Transcodes `input` into a UTF-8 byte array, returning an error if the transcoded data
+    /// has a length other than `N`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidLength`](DecodeErrorKind::InvalidLength) error if the provided length
+    /// is insufficient or too large for `input`.
+    pub const fn try_decode<const N: usize>(input: &[u8]) -> Result<[u8; N], DecodeError> {
+        let (bytes, out_len) = Self::do_decode::<N>(input);
+        const_try!(try_check_output_len(input, out_len, N));
+        Ok(bytes)
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        let mut in_index = 0;
+        let mut out_len = input.len();
+        while in_index < input.len() {
+            if !input[in_index].is_ascii() {
+                out_len += 1;
+            }
+            in_index += 1;
+        }
+        out_len
+    }
+
+    const fn do_decode<const N: usize>(input: &[u8]) -> ([u8; N], usize) {
+        let mut bytes = [0_u8; N];
+        let mut in_index = 0;
+        let mut out_index = 0;
+        while in_index < input.len() {
+            write_utf8!(bytes, out_index, input[in_index] as u32);
+            in_index += 1;
+        }
+        (bytes, out_index)
+    }
+}
+
+/// Mapping of EBCDIC code page 037 bytes to Unicode code points (all of which are in the Latin-1
+/// range).
+#[rustfmt::skip]
+const CP037: [u8; 256] = [
+    0x00, 0x01, 0x02, 0x03, 0x9c, 0x09, 0x86, 0x7f, 0x97, 0x8d, 0x8e, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x10, 0x11, 0x12, 0x13, 0x9d, 0x85, 0x08, 0x87, 0x18, 0x19, 0x92, 0x8f, 0x1c, 0x1d, 0x1e, 0x1f,
+    0x80, 0x81, 0x82, 0x83, 0x84, 0x0a, 0x17, 0x1b, 0x88, 0x89, 0x8a, 0x8b, 0x8c, 0x05, 0x06, 0x07,
+    0x90, 0x91, 0x16, 0x93, 0x94, 0x95, 0x96, 0x04, 0x98, 0x99, 0x9a, 0x9b, 0x14, 0x15, 0x9e, 0x1a,
+    0x20, 0xa0, 0xe2, 0xe4, 0xe0, 0xe1, 0xe3, 0xe5, 0xe7, 0xf1, 0xa2, 0x2e, 0x3c, 0x28, 0x2b, 0x7c,
+    0x26, 0xe9, 0xea, 0xeb, 0xe8, 0xed, 0xee, 0xef, 0xec, 0xdf, 0x21, 0x24, 0x2a, 0x29, 0x3b, 0xac,
+    0x2d, 0x2f, 0xc2, 0xc4, 0xc0, 0xc1, 0xc3, 0xc5, 0xc7, 0xd1, 0xa6, 0x2c, 0x25, 0x5f, 0x3e, 0x3f,
+    0xf8, 0xc9, 0xca, 0xcb, 0xc8, 0xcd, 0xce, 0xcf, 0xcc, 0x60, 0x3a, 0x23, 0x40, 0x27, 0x3d, 0x22,
+    0xd8, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0xab, 0xbb, 0xf0, 0xfd, 0xfe, 0xb1,
+    0xb0, 0x6a, 0x6b, 0x6c, 0x6d, 0x6e, 0x6f, 0x70, 0x71, 0x72, 0xaa, 0xba, 0xe6, 0xb8, 0xc6, 0xa4,
+    0xb5, 0x7e, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a, 0xa1, 0xbf, 0xd0, 0xdd, 0xde, 0xae,
+    0x5e, 0xa3, 0xa5, 0xb7, 0xa9, 0xa7, 0xb6, 0xbc, 0xbd, 0xbe, 0x5b, 0x5d, 0xaf, 0xa8, 0xb4, 0xd7,
+    0x7b, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0xad, 0xf4, 0xf6, 0xf2, 0xf3, 0xf5,
+    0x7d, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x50, 0x51, 0x52, 0xb9, 0xfb, 0xfc, 0xf9, 0xfa, 0xff,
+    0x5c, 0xf7, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0xb2, 0xd4, 0xd6, 0xd2, 0xd3, 0xd5,
+    0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0xb3, 0xdb, 0xdc, 0xd9, 0xda, 0x9f,
+];
+
+/// Transcoder of EBCDIC bytes into UTF-8, e.g. for data sourced from IBM mainframes.
+/// Each byte is mapped to a single code point, so any input is valid.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Decoder, Ebcdic};
+/// const HELLO: [u8; 5] = Ebcdic::Cp037.decode(b"\xc8\x85\x93\x93\x96");
+/// assert_eq!(HELLO, *b"Hello");
+///
+/// // Transcoding from hex-encoded bytes:
+/// const TEXT: &[u8] = &decode!(Ebcdic::Cp500, &decode!(Decoder::Hex, b"c799dc5985404af15a4f"));
+/// assert_eq!(TEXT, "Grüße [1]!".as_bytes());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Ebcdic {
+    /// Code page 037 (USA / Canada).
+    Cp037,
+    /// Code page 500 (International). Differs from [`Self::Cp037`] in the placement of
+    /// several punctuation chars, such as `[`, `]`, `!` and `|`.
+    Cp500,
+}
+
+impl Ebcdic {
+    /// Returns the code point corresponding to the specified byte.
+    const fn code_point(self, byte: u8) -> u32 {
+        let mapped = match (self, byte) {
+            (Self::Cp500, 0x4a) => 0x5b,
+            (Self::Cp500, 0x4f) => 0x21,
+            (Self::Cp500, 0x5a) => 0x5d,
+            (Self::Cp500, 0x5f) => 0x5e,
+            (Self::Cp500, 0xb0) => 0xa2,
+            (Self::Cp500, 0xba) => 0xac,
+            (Self::Cp500, 0xbb) => 0x7c,
+            _ => CP037[byte as usize],
+        };
+        mapped as u32
+    }
+
+    /// Transcodes `input` into a UTF-8 byte array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the provided length is insufficient or too large for `input`.
+    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = self.do_decode::<N>(input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    /// Transcodes `input` into a UTF-8 byte array, returning an error if the transcoded data
+    /// has a length other than `N`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidLength`](DecodeErrorKind::InvalidLength) error if the provided length
+    /// is insufficient or too large for `input`.
+    pub const fn try_decode<const N: usize>(self, input: &[u8]) -> Result<[u8; N], DecodeError> {
+        let (bytes, out_len) = self.do_decode::<N>(input);
+        const_try!(try_check_output_len(input, out_len, N));
+        Ok(bytes)
+    }
+
+    pub(crate) const fn decode_len(self, input: &[u8]) -> usize {
+        self.do_decode::<0>(input).1
+    }
+
+    const fn do_decode<const N: usize>(self, input: &[u8]) -> ([u8; N], usize) {
+        let mut bytes = [0_u8; N];
+        let mut in_index = 0;
+        let mut out_index = 0;
+        while in_index < input.len() {
+            write_utf8!(bytes, out_index, self.code_point(input[in_index]));
+            in_index += 1;
+        }
+        (bytes, out_index)
+    }
+}
+
+/// Named HTML entities supported by [`HtmlEntities`].
+const NAMED_ENTITIES: &[(&[u8], u32)] = &[
+    (b"amp", 0x26),
+    (b"lt", 0x3c),
+    (b"gt", 0x3e),
+    (b"quot", 0x22),
+    (b"apos", 0x27),
+    (b"nbsp", 0xa0),
+    (b"cent", 0xa2),
+    (b"pound", 0xa3),
+    (b"yen", 0xa5),
+    (b"sect", 0xa7),
+    (b"copy", 0xa9),
+    (b"laquo", 0xab),
+    (b"reg", 0xae),
+    (b"deg", 0xb0),
+    (b"plusmn", 0xb1),
+    (b"para", 0xb6),
+    (b"middot", 0xb7),
+    (b"raquo", 0xbb),
+    (b"times", 0xd7),
+    (b"divide", 0xf7),
+    (b"ndash", 0x2013),
+    (b"mdash", 0x2014),
+    (b"lsquo", 0x2018),
+    (b"rsquo", 0x2019),
+    (b"ldquo", 0x201c),
+    (b"rdquo", 0x201d),
+    (b"bull", 0x2022),
+    (b"hellip", 0x2026),
+    (b"euro", 0x20ac),
+    (b"trade", 0x2122),
+];
+
+/// Decoder of HTML character references in UTF-8 text.
+///
+/// Supports decimal (`&#169;`) and hexadecimal (`&#xA9;`) numeric references, and common
+/// named entities (`&amp;`, `&lt;`, `&gt;`, `&quot;`, `&apos;`, `&nbsp;`, `&copy;`, etc.).
+/// All other bytes are copied to the output as is. The decoder is strict: each `&` char
+/// must start a known entity terminated by `;`.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, HtmlEntities};
+/// const TEXT: &[u8] = &decode!(HtmlEntities, b"&lt;p&gt;Tom &amp; Jerry &#xA9; 1940&lt;/p&gt;");
+/// assert_eq!(TEXT, "<p>Tom & Jerry © 1940</p>".as_bytes());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HtmlEntities;
+
+impl HtmlEntities {
+    /// Decodes `input` into a UTF-8 byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` contains an unknown or malformed entity, or a numeric reference
+    ///   to an invalid code point.
+    pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = unwrap_output(Self::do_decode::<N>(input), input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    /// Decodes `input` into a UTF-8 byte array, returning an error on invalid input.
+    /// See [`Decoder::try_decode()`](crate::Decoder::try_decode()) for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` contains an unknown or malformed entity, or a numeric
+    /// reference to an invalid code point, or decodes to a length other than `N`. Malformed
+    /// entities are reported as [`InvalidSymbol`](DecodeErrorKind::InvalidSymbol) at the `&` char,
+    /// except for invalid digits in numeric references, which are reported at the digit.
+    pub const fn try_decode<const N: usize>(input: &[u8]) -> Result<[u8; N], DecodeError> {
+        try_output(Self::do_decode::<N>(input), input)
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        unwrap_output(Self::do_decode::<0>(input), input).1
+    }
+
+    /// Parses a numeric reference with the digits in `start..end`.
+    const fn parse_numeric(
+        input: &[u8],
+        start: usize,
+        end: usize,
+        entity_pos: usize,
+    ) -> Result<u32, TextError> {
+        let (radix, mut i) = if input[start] == b'x' || input[start] == b'X' {
+            (16, start + 1)
+        } else {
+            (10, start)
+        };
+        if i >= end {
+            return Err(TextError::EmptyNumericEntity(entity_pos));
+        }
+
+        let mut code_point = 0_u32;
+        while i < end {
+            let ch = input[i];
+            let digit = match ch {
+                b'0'..=b'9' => ch - b'0',
+                b'a'..=b'f' if radix == 16 => ch - b'a' + 10,
+                b'A'..=b'F' if radix == 16 => ch - b'A' + 10,
+                _ => return Err(TextError::InvalidEntityDigit { pos: i, entity_pos }),
+            };
+            code_point = code_point * radix + digit as u32;
+            if code_point > 0x10_ffff {
+                return Err(TextError::EntityOutOfRange(entity_pos));
+            }
+            i += 1;
+        }
+        if code_point == 0 || (code_point >= 0xd800 && code_point < 0xe000) {
+            return Err(TextError::EntityNotScalar(entity_pos));
+        }
+        Ok(code_point)
+    }
+
+    const fn lookup_named(
+        input: &[u8],
+        start: usize,
+        end: usize,
+        entity_pos: usize,
+    ) -> Result<u32, TextError> {
+        let mut entity_idx = 0;
+        while entity_idx < NAMED_ENTITIES.len() {
+            let (name, code_point) = NAMED_ENTITIES[entity_idx];
+            if name.len() == end - start {
+                let mut i = 0;
+                while i < name.len() && name[i] == input[start + i] {
+                    i += 1;
+                }
+                if i == name.len() {
+                    return Ok(code_point);
+                }
+            }
+            entity_idx += 1;
+        }
+        Err(TextError::UnknownEntity(entity_pos))
+    }
+
+    const fn do_decode<const N: usize>(input: &[u8]) -> Result<([u8; N], usize), TextError> {
+        let mut bytes = [0_u8; N];
+        let mut in_index = 0;
+        let mut out_index = 0;
+        while in_index < input.len() {
+            if input[in_index] != b'&' {
+                if out_index < N {
+                    bytes[out_index] = input[in_index];
+                }
+                out_index += 1;
+                in_index += 1;
+                continue;
+            }
+
+            let mut end = in_index + 1;
+            while end < input.len() && input[end] != b';' && input[end] != b'&' {
+                end += 1;
+            }
+            if end >= input.len() || input[end] != b';' {
+                return Err(TextError::UnterminatedEntity(in_index));
+            }
+            if end == in_index + 1 {
+                return Err(TextError::EmptyEntity(in_index));
+            }
+            let code_point = if input[in_index + 1] == b'#' {
+                const_try!(Self::parse_numeric(input, in_index + 2, end, in_index))
+            } else {
+                const_try!(Self::lookup_named(input, in_index + 1, end, in_index))
+            };
+            write_utf8!(bytes, out_index, code_point);
+            in_index = end + 1;
+        }
+        Ok((bytes, out_index))
+    }
+}
+
+/// Returns the value of a modified base64 char used in UTF-7.
+const fn utf7_base64_value(ch: u8, imap: bool) -> Option<u32> {
+    Some(match ch {
+        b'A'..=b'Z' => ch - b'A',
+        b'a'..=b'z' => ch - b'a' + 26,
+        b'0'..=b'9' => ch - b'0' + 52,
+        b'+' => 62,
+        b'/' if !imap => 63,
+        b',' if imap => 63,
+        _ => return None,
+    } as u32)
+}
+
+/// Decodes UTF-7 input into UTF-8; shared by the standard and IMAP variants.
+const fn decode_utf7<const N: usize>(
+    input: &[u8],
+    imap: bool,
+) -> Result<([u8; N], usize), TextError> {
+    let shift_char = if imap { b'&' } else { b'+' };
+    let mut bytes = [0_u8; N];
+    let mut in_index = 0;
+    let mut out_index = 0;
+    while in_index < input.len() {
+        let ch = input[in_index];
+        if ch != shift_char {
+            let is_direct = if imap {
+                ch >= 0x20 && ch < 0x7f
+            } else {
+                ch.is_ascii()
+            };
+            if !is_direct {
+                return Err(TextError::NotDirectlyEncoded(in_index));
+            }
+            if out_index < N {
+                bytes[out_index] = ch;
+            }
+            out_index += 1;
+            in_index += 1;
+            continue;
+        }
+
+        let shift_start = in_index;
+        in_index += 1;
+        if in_index < input.len() && input[in_index] == b'-' {
+            // `+-` / `&-` encodes the shift char itself.
+            if out_index < N {
+                bytes[out_index] = shift_char;
+            }
+            out_index += 1;
+            in_index += 1;
+            continue;
+        }
+
+        let mut acc = 0_u32;
+        let mut filled_bits = 0;
+        let mut high_surrogate: Option<u32> = None;
+        while in_index < input.len() {
+            let Some(value) = utf7_base64_value(input[in_index], imap) else {
+                break;
+            };
+            acc = ((acc << 6) + value) & 0x3f_ffff;
+            filled_bits += 6;
+            if filled_bits >= 16 {
+                filled_bits -= 16;
+                let unit = (acc >> filled_bits) & 0xffff;
+                let code_point = if let Some(high) = high_surrogate {
+                    if unit < 0xdc00 || unit >= 0xe000 {
+                        return Err(TextError::Utf7HighSurrogate(shift_start));
+                    }
+                    high_surrogate = None;
+                    Some(0x1_0000 + ((high - 0xd800) << 10) + (unit - 0xdc00))
+                } else if unit >= 0xd800 && unit < 0xdc00 {
+                    high_surrogate = Some(unit);
+                    None
+                } else {
+                    if unit >= 0xdc00 && unit < 0xe000 {
+                        return Err(TextError::Utf7LowSurrogate(shift_start));
+                    }
+                    Some(unit)
+                };
+                if let Some(code_point) = code_point {
+                    write_utf8!(bytes, out_index, code_point);
+                }
+            }
+            in_index += 1;
+        }
+
+        if high_surrogate.is_some() {
+            return Err(TextError::Utf7HighSurrogate(shift_start));
+        }
+        if filled_bits >= 6 || acc & ((1 << filled_bits) - 1) != 0 {
+            return Err(TextError::Utf7Padding(shift_start));
+        }
+        if in_index < input.len() && input[in_index] == b'-' {
+            // The explicit terminator of a shifted sequence is absorbed.
+            in_index += 1;
+        } else if imap {
+            return Err(TextError::UnterminatedShift {
+                shift_start,
+                pos: in_index,
+            });
+        }
+    }
+    Ok((bytes, out_index))
+}
+
+/// Decoder of UTF-7 text as per [RFC 2152] into UTF-8.
+///
+/// Directly encoded ASCII chars are copied as is; base64-encoded shifted sequences starting
+/// with `+` are decoded from UTF-16 (surrogate pairs are validated). A shifted sequence is
+/// terminated either with an absorbed `-` char, or with any char not in the base64 alphabet.
+///
+/// [RFC 2152]: https://datatracker.ietf.org/doc/html/rfc2152
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Utf7};
+/// const TEXT: &[u8] = &decode!(Utf7, b"Hi Mom -+Jjo--! 1 +- 1 = 2");
+/// assert_eq!(TEXT, "Hi Mom -☺-! 1 + 1 = 2".as_bytes());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Utf7;
+
+impl Utf7 {
+    /// Decodes `input` into a UTF-8 byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` contains non-ASCII chars, or a shifted sequence is malformed.
+    pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = unwrap_output(decode_utf7::<N>(input, false), input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    /// Decodes `input` into a UTF-8 byte array, returning an error on invalid input.
+    /// See [`Decoder::try_decode()`](crate::Decoder::try_decode()) for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` contains non-ASCII chars, or a shifted sequence is malformed,
+    /// or `input` decodes to a length other than `N`.
+    pub const fn try_decode<const N: usize>(input: &[u8]) -> Result<[u8; N], DecodeError> {
+        try_output(decode_utf7::<N>(input, false), input)
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        unwrap_output(decode_utf7::<0>(input, false), input).1
+    }
+}
+
+/// Decoder of modified UTF-7 used for IMAP mailbox names as per [RFC 3501, section 5.1.3]
+/// into UTF-8.
+///
+/// Differs from [`Utf7`] in that shifted sequences start with `&`, use `,` instead of `/`
+/// in the base64 alphabet, and must be explicitly terminated with `-`. Only printable ASCII chars
+/// may be encoded directly.
+///
+/// [RFC 3501, section 5.1.3]: https://datatracker.ietf.org/doc/html/rfc3501#section-5.1.3
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, ImapUtf7};
+/// const MAILBOX: &[u8] = &decode!(ImapUtf7, b"~peter/mail/&U,BTFw-/&ZeVnLIqe-");
+/// assert_eq!(MAILBOX, "~peter/mail/台北/日本語".as_bytes());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ImapUtf7;
+
+impl ImapUtf7 {
+    /// Decodes `input` into a UTF-8 byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` contains non-printable chars, or a shifted sequence is malformed.
+    pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = unwrap_output(decode_utf7::<N>(input, true), input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    /// Decodes `input` into a UTF-8 byte array, returning an error on invalid input.
+    /// See [`Decoder::try_decode()`](crate::Decoder::try_decode()) for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` contains non-printable chars, or a shifted sequence
+    /// is malformed, or `input` decodes to a length other than `N`.
+    pub const fn try_decode<const N: usize>(input: &[u8]) -> Result<[u8; N], DecodeError> {
+        try_output(decode_utf7::<N>(input, true), input)
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        unwrap_output(decode_utf7::<0>(input, true), input).1
+    }
+}
+
+/// Charset of a MIME encoded-word supported by [`EncodedWord`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MimeCharset {
+    Utf8,
+    Ascii,
+    Latin1,
+}
+
+impl MimeCharset {
+    const fn parse(input: &[u8], start: usize, end: usize) -> Result<Self, TextError> {
+        if Self::eq_ignore_case(input, start, end, b"UTF-8") {
+            Ok(Self::Utf8)
+        } else if Self::eq_ignore_case(input, start, end, b"US-ASCII") {
+            Ok(Self::Ascii)
+        } else if Self::eq_ignore_case(input, start, end, b"ISO-8859-1") {
+            Ok(Self::Latin1)
+        } else {
+            Err(TextError::UnsupportedCharset(start))
+        }
+    }
+
+    const fn eq_ignore_case(input: &[u8], start: usize, end: usize, expected: &[u8]) -> bool {
+        if end - start != expected.len() {
+            return false;
+        }
+        let mut i = 0;
+        while i < expected.len() {
+            if !input[start + i].eq_ignore_ascii_case(&expected[i]) {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+}
+
+/// Decoder of a MIME encoded-word as per [RFC 2047] (e.g., `=?UTF-8?B?R3LDvMOfZQ==?=`), as used
+/// in email headers, into UTF-8.
+///
+/// Both the `B` (base64) and `Q` (quoted-printable-like, with `_` standing for a space)
+/// encodings are supported; the encoding flag and the charset name are case-insensitive.
+/// Supported charsets are UTF-8, US-ASCII and ISO-8859-1; the latter is transcoded
+/// to UTF-8. An optional [RFC 2231] language suffix of the charset (e.g., `UTF-8*en`) is ignored.
+///
+/// [RFC 2047]: https://datatracker.ietf.org/doc/html/rfc2047
+/// [RFC 2231]: https://datatracker.ietf.org/doc/html/rfc2231#section-5
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, EncodedWord};
+/// const SUBJECT: &[u8] = &decode!(EncodedWord, b"=?UTF-8?B?R3LDvMOfZQ==?=");
+/// assert_eq!(SUBJECT, "Grüße".as_bytes());
+/// const NAME: &[u8] = &decode!(EncodedWord, b"=?iso-8859-1?q?Andr=E9_Pirard?=");
+/// assert_eq!(NAME, "André Pirard".as_bytes());
+/// ```
+///
+/// Unsupported charsets are caught in compile time:
+///
+/// ```compile_fail
+/// # use const_decoder::{decode, EncodedWord};
+/// const SUBJECT: &[u8] = &decode!(EncodedWord, b"=?KOI8-R?B?8NLJ18XU?=");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EncodedWord;
+
+impl EncodedWord {
+    /// Decodes `input` into a UTF-8 byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` is not a well-formed encoded-word, or uses an unsupported charset.
+    /// - Panics if the encoded text is invalid for the specified encoding, or the decoded bytes
+    ///   are not ASCII for the US-ASCII charset.
+    pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = unwrap_output(Self::do_decode::<N>(input), input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    /// Decodes `input` into a UTF-8 byte array, returning an error on invalid input.
+    /// See [`Decoder::try_decode()`](crate::Decoder::try_decode()) for details.
+    ///
+    /// # Errors
+    ///
+    /// - Returns a [`MissingHeader`](DecodeErrorKind::MissingHeader) error if `input` does not have
+    ///   the `=?charset?encoding?text?=` form, and an [`InvalidStream`](DecodeErrorKind::InvalidStream)
+    ///   error if it uses an unsupported charset.
+    /// - Returns an error if the encoded text is invalid for the specified encoding, the decoded
+    ///   bytes are not ASCII for the US-ASCII charset, or `input` decodes to a length other than `N`.
+    pub const fn try_decode<const N: usize>(input: &[u8]) -> Result<[u8; N], DecodeError> {
+        try_output(Self::do_decode::<N>(input), input)
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        unwrap_output(Self::do_decode::<0>(input), input).1
+    }
+
+    /// Parses the encoded-word framing. Returns the charset, whether the text is base64-encoded,
+    /// and the start and end indices of the encoded text.
+    const fn parse(input: &[u8]) -> Result<(MimeCharset, bool, usize, usize), TextError> {
+        let len = input.len();
+        let is_framed = len >= 8
+            && input[0] == b'='
+            && input[1] == b'?'
+            && input[len - 2] == b'?'
+            && input[len - 1] == b'=';
+        if !is_framed {
+            return Err(TextError::InvalidEncodedWord);
+        }
+
+        let mut charset_end = 2;
+        while charset_end < len - 2 && input[charset_end] != b'?' && input[charset_end] != b'*' {
+            charset_end += 1;
+        }
+        let mut flag_index = charset_end;
+        while flag_index < len - 2 && input[flag_index] != b'?' {
+            flag_index += 1;
+        }
+        flag_index += 1;
+        if flag_index + 1 >= len - 2 || input[flag_index + 1] != b'?' {
+            return Err(TextError::InvalidEncodedWord);
+        }
+        let charset = const_try!(MimeCharset::parse(input, 2, charset_end));
+
+        let is_base64 = match input[flag_index] {
+            b'B' | b'b' => true,
+            b'Q' | b'q' => false,
+            _ => return Err(TextError::UnknownEncoding(flag_index)),
+        };
+        Ok((charset, is_base64, flag_index + 2, len - 2))
+    }
+
+    const fn do_decode<const N: usize>(input: &[u8]) -> Result<([u8; N], usize), TextError> {
+        let (charset, is_base64, start, end) = const_try!(Self::parse(input));
+        let mut bytes = [0_u8; N];
+        let mut out_index = 0;
+        let mut in_index = start;
+
+        let mut acc = 0_u32;
+        let mut filled_bits = 0;
+        let mut padding_len = 0;
+        while in_index < end {
+            let ch_index = in_index;
+            let ch = input[in_index];
+            let byte = if is_base64 {
+                if ch == b'=' {
+                    padding_len += 1;
+                    in_index += 1;
+                    continue;
+                }
+                if padding_len > 0 {
+                    return Err(TextError::DataAfterPadding(in_index));
+                }
+                let Some(value) = utf7_base64_value(ch, false) else {
+                    return Err(TextError::InvalidBase64Char(in_index));
+                };
+                acc = ((acc << 6) + value) & 0xfff;
+                filled_bits += 6;
+                in_index += 1;
+                if filled_bits < 8 {
+                    continue;
+                }
+                filled_bits -= 8;
+                #[allow(clippy::cast_possible_truncation)] // intended
+                let byte = (acc >> filled_bits) as u8;
+                byte
+            } else if ch == b'_' {
+                in_index += 1;
+                b' '
+            } else if ch == b'=' {
+                if in_index + 2 >= end {
+                    return Err(TextError::TruncatedEscape(in_index));
+                }
+                let high = const_try!(Self::hex_value(input, in_index + 1));
+                let low = const_try!(Self::hex_value(input, in_index + 2));
+                in_index += 3;
+                (high << 4) | low
+            } else {
+                if ch <= b' ' || ch >= 0x7f || ch == b'?' {
+                    return Err(TextError::NotAllowedInQ(in_index));
+                }
+                in_index += 1;
+                ch
+            };
+
+            match charset {
+                MimeCharset::Utf8 => {
+                    if out_index < N {
+                        bytes[out_index] = byte;
+                    }
+                    out_index += 1;
+                }
+                MimeCharset::Ascii => {
+                    if !byte.is_ascii() {
+                        return Err(TextError::NonAsciiByte {
+                            byte,
+                            pos: ch_index,
+                        });
+                    }
+                    if out_index < N {
+                        bytes[out_index] = byte;
+                    }
+                    out_index += 1;
+                }
+                MimeCharset::Latin1 => {
+                    write_utf8!(bytes, out_index, byte as u32);
+                }
+            }
+        }
+
+        if is_base64 {
+            let char_count = end - start;
+            if char_count % 4 != 0 || padding_len > 2 || filled_bits >= 6 {
+                return Err(TextError::InvalidBase64Padding(end));
+            }
+            if acc & ((1 << filled_bits) - 1) != 0 {
+                return Err(TextError::NonZeroPaddingBits(end));
+            }
+        }
+        Ok((bytes, out_index))
+    }
+
+    const fn hex_value(input: &[u8], pos: usize) -> Result<u8, TextError> {
+        let ch = input[pos];
+        match ch {
+            b'0'..=b'9' => Ok(ch - b'0'),
+            b'A'..=b'F' => Ok(ch - b'A' + 10),
+            b'a'..=b'f' => Ok(ch - b'a' + 10),
+            _ => Err(TextError::NotHexDigit(pos)),
+        }
+    }
+}
+
+/// Error produced by the transcoders in this module.
+#[derive(Debug, Clone, Copy)]
+enum TextError {
+    OddLength,
+    Utf16HighSurrogate(usize),
+    Utf16LowSurrogate(usize),
+    EmptyNumericEntity(usize),
+    InvalidEntityDigit { pos: usize, entity_pos: usize },
+    EntityOutOfRange(usize),
+    EntityNotScalar(usize),
+    UnknownEntity(usize),
+    UnterminatedEntity(usize),
+    EmptyEntity(usize),
+    NotDirectlyEncoded(usize),
+    Utf7HighSurrogate(usize),
+    Utf7LowSurrogate(usize),
+    Utf7Padding(usize),
+    UnterminatedShift { shift_start: usize, pos: usize },
+    InvalidEncodedWord,
+    UnsupportedCharset(usize),
+    UnknownEncoding(usize),
+    DataAfterPadding(usize),
+    InvalidBase64Char(usize),
+    TruncatedEscape(usize),
+    NotHexDigit(usize),
+    NotAllowedInQ(usize),
+    NonAsciiByte { byte: u8, pos: usize },
+    InvalidBase64Padding(usize),
+    NonZeroPaddingBits(usize),
+}
+
+impl TextError {
+    const fn into_public(self, input: &[u8]) -> DecodeError {
+        let (kind, pos) = match self {
+            Self::OddLength => (DecodeErrorKind::IncompleteInput, input.len()),
+            Self::UnterminatedShift { pos, .. } if pos == input.len() => {
+                (DecodeErrorKind::IncompleteInput, pos)
+            }
+            Self::Utf16HighSurrogate(pos)
+            | Self::Utf16LowSurrogate(pos)
+            | Self::EmptyNumericEntity(pos)
+            | Self::InvalidEntityDigit { pos, .. }
+            | Self::EntityOutOfRange(pos)
+            | Self::EntityNotScalar(pos)
+            | Self::UnknownEntity(pos)
+            | Self::UnterminatedEntity(pos)
+            | Self::EmptyEntity(pos)
+            | Self::NotDirectlyEncoded(pos)
+            | Self::Utf7HighSurrogate(pos)
+            | Self::Utf7LowSurrogate(pos)
+            | Self::UnterminatedShift { pos, .. }
+            | Self::UnknownEncoding(pos)
+            | Self::InvalidBase64Char(pos)
+            | Self::NotHexDigit(pos)
+            | Self::NotAllowedInQ(pos) => {
+                let kind = DecodeErrorKind::InvalidSymbol { symbol: input[pos] };
+                (kind, pos)
+            }
+            Self::NonAsciiByte { byte, pos } => {
+                (DecodeErrorKind::InvalidSymbol { symbol: byte }, pos)
+            }
+            Self::InvalidEncodedWord => (DecodeErrorKind::MissingHeader, 0),
+            Self::UnsupportedCharset(pos) => (DecodeErrorKind::InvalidStream, pos),
+            Self::TruncatedEscape(pos) => (DecodeErrorKind::IncompleteInput, pos),
+            Self::Utf7Padding(pos)
+            | Self::DataAfterPadding(pos)
+            | Self::InvalidBase64Padding(pos)
+            | Self::NonZeroPaddingBits(pos) => (DecodeErrorKind::InvalidPadding, pos),
+        };
+        DecodeError::new(kind, pos)
+    }
+
+    const fn panic(self, input: &[u8]) -> ! {
+        match self {
+            Self::OddLength => compile_panic!(
+                "UTF-16 input has odd length ", input.len() => fmt::<usize>()
+            ),
+            Self::Utf16HighSurrogate(pos) => compile_panic!(
+                "Unpaired high surrogate at position ", pos => fmt::<usize>()
+            ),
+            Self::Utf16LowSurrogate(pos) => compile_panic!(
+                "Unpaired low surrogate at position ", pos => fmt::<usize>()
+            ),
+            Self::EmptyNumericEntity(entity_pos) => compile_panic!(
+                "Empty numeric character reference at position ", entity_pos => fmt::<usize>()
+            ),
+            Self::InvalidEntityDigit { pos, entity_pos } => compile_panic!(
+                "Invalid digit '", input[pos] as char => fmt::<char>(), "' in numeric character \
+                 reference at position ", entity_pos => fmt::<usize>()
+            ),
+            Self::EntityOutOfRange(entity_pos) => compile_panic!(
+                "Numeric character reference at position ", entity_pos => fmt::<usize>(),
+                " is out of Unicode range"
+            ),
+            Self::EntityNotScalar(entity_pos) => compile_panic!(
+                "Numeric character reference at position ", entity_pos => fmt::<usize>(),
+                " does not correspond to a Unicode scalar value"
+            ),
+            Self::UnknownEntity(pos) => {
+                compile_panic!("Unknown HTML entity at position ", pos => fmt::<usize>())
+            }
+            Self::UnterminatedEntity(pos) => {
+                compile_panic!("Unterminated HTML entity at position ", pos => fmt::<usize>())
+            }
+            Self::EmptyEntity(pos) => {
+                compile_panic!("Empty HTML entity at position ", pos => fmt::<usize>())
+            }
+            Self::NotDirectlyEncoded(pos) => compile_panic!(
+                "Character with decimal code ", input[pos] => fmt::<u8>(), " at position ",
+                pos => fmt::<usize>(), " cannot be directly encoded in UTF-7"
+            ),
+            Self::Utf7HighSurrogate(shift_start) => compile_panic!(
+                "Unpaired high surrogate in UTF-7 shifted sequence at position ",
+                shift_start => fmt::<usize>()
+            ),
+            Self::Utf7LowSurrogate(shift_start) => compile_panic!(
+                "Unpaired low surrogate in UTF-7 shifted sequence at position ",
+                shift_start => fmt::<usize>()
+            ),
+            Self::Utf7Padding(shift_start) => compile_panic!(
+                "Invalid padding of UTF-7 shifted sequence at position ",
+                shift_start => fmt::<usize>()
+            ),
+            Self::UnterminatedShift { shift_start, .. } => compile_panic!(
+                "Unterminated shifted sequence at position ", shift_start => fmt::<usize>()
+            ),
+            Self::InvalidEncodedWord => {
+                compile_panic!("MIME encoded-word must have the `=?charset?encoding?text?=` form")
+            }
+            Self::UnsupportedCharset(pos) => compile_panic!(
+                "Unsupported charset in MIME encoded-word at position ", pos => fmt::<usize>(),
+                "; expected UTF-8, US-ASCII or ISO-8859-1"
+            ),
+            Self::UnknownEncoding(pos) => compile_panic!(
+                "Unknown MIME encoded-word encoding '", input[pos] as char => fmt::<char>(),
+                "' at position ", pos => fmt::<usize>(), "; expected 'B' or 'Q'"
+            ),
+            Self::DataAfterPadding(pos) => compile_panic!(
+                "Base64 data after padding at position ", pos => fmt::<usize>()
+            ),
+            Self::InvalidBase64Char(pos) => compile_panic!(
+                "Character '", input[pos] as char => fmt::<char>(), "' at position ",
+                pos => fmt::<usize>(), " is not a base64 char"
+            ),
+            Self::TruncatedEscape(pos) => compile_panic!(
+                "Truncated escape sequence at position ", pos => fmt::<usize>()
+            ),
+            Self::NotHexDigit(pos) => compile_panic!(
+                "Character '", input[pos] as char => fmt::<char>(), "' at position ",
+                pos => fmt::<usize>(), " is not a hex digit"
+            ),
+            Self::NotAllowedInQ(pos) => compile_panic!(
+                "Character with decimal code ", input[pos] => fmt::<u8>(), " at position ",
+                pos => fmt::<usize>(), " is not allowed in Q-encoded text"
+            ),
+            Self::NonAsciiByte { byte, .. } => compile_panic!(
+                "Decoded byte ", byte => fmt::<u8>(),
+                " is not ASCII, while the encoded-word charset is US-ASCII"
+            ),
+            Self::InvalidBase64Padding(_) => {
+                compile_panic!("Invalid padding of base64 text in MIME encoded-word")
+            }
+            Self::NonZeroPaddingBits(_) => {
+                compile_panic!("Non-zero padding bits in base64 text of MIME encoded-word")
+            }
+        }
+    }
+}
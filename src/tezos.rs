@@ -0,0 +1,212 @@
+//! Decoder for checksummed Tezos Base58 values.
+
+use crate::{
+    base58::{Base58, Base58Error},
+    decoder::check_output_len,
+    digest::{sha256, sha256_of_prefix},
+    error::{DecodeError, DecodeErrorKind},
+    panics::{clip, compile_panic, fmt},
+};
+
+/// Type of a checksummed [Tezos] Base58 value, determined by its multi-byte prefix (and thus
+/// the first chars of the encoded value). The decoder outputs the payload after verifying
+/// the prefix and the checksum (the first 4 bytes of the double SHA-256 digest).
+///
+/// [Tezos]: https://tezos.gitlab.io/
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Tezos};
+/// const ADDRESS: [u8; 20] = Tezos::Tz1.decode(b"tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx");
+/// assert_eq!(ADDRESS[..4], [0x02, 0x29, 0x8c, 0x03]);
+/// const PUBLIC_KEY: &[u8] = &decode!(
+///     Tezos::Edpk,
+///     b"edpkuBknW28nW72KG6RoHtYW7p12T6GKc7nAbwYX5m8Wd9sDVC9yav",
+/// );
+/// assert_eq!(PUBLIC_KEY.len(), 32);
+/// ```
+///
+/// Values with an unexpected prefix or an invalid checksum are caught in compile time:
+///
+/// ```compile_fail
+/// # use const_decoder::Tezos;
+/// const ADDRESS: [u8; 20] = Tezos::Tz1.decode(b"KT1PWx2mnDueood7fEmfbBDKx1D9BAnnXitn");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Tezos {
+    /// Ed25519 public key hash (`tz1...`), 20 bytes.
+    Tz1,
+    /// Secp256k1 public key hash (`tz2...`), 20 bytes.
+    Tz2,
+    /// P-256 public key hash (`tz3...`), 20 bytes.
+    Tz3,
+    /// Originated contract hash (`KT1...`), 20 bytes.
+    Kt1,
+    /// Ed25519 public key (`edpk...`), 32 bytes.
+    Edpk,
+    /// Ed25519 secret key seed (`edsk...`), 32 bytes.
+    Edsk,
+    /// Ed25519 signature (`edsig...`), 64 bytes.
+    Edsig,
+}
+
+impl Tezos {
+    /// Maximum length of a decoded value including the prefix and the checksum.
+    const MAX_RAW_LEN: usize = 5 + 64 + 4;
+
+    const fn prefix(self) -> &'static [u8] {
+        match self {
+            Self::Tz1 => &[6, 161, 159],
+            Self::Tz2 => &[6, 161, 161],
+            Self::Tz3 => &[6, 161, 164],
+            Self::Kt1 => &[2, 90, 121],
+            Self::Edpk => &[13, 15, 37, 217],
+            Self::Edsk => &[13, 15, 58, 7],
+            Self::Edsig => &[9, 245, 205, 134, 18],
+        }
+    }
+
+    const fn text_prefix(self) -> &'static str {
+        match self {
+            Self::Tz1 => "tz1",
+            Self::Tz2 => "tz2",
+            Self::Tz3 => "tz3",
+            Self::Kt1 => "KT1",
+            Self::Edpk => "edpk",
+            Self::Edsk => "edsk",
+            Self::Edsig => "edsig",
+        }
+    }
+
+    /// Returns the length of the decoded payload in bytes.
+    pub const fn payload_len(self) -> usize {
+        match self {
+            Self::Tz1 | Self::Tz2 | Self::Tz3 | Self::Kt1 => 20,
+            Self::Edpk | Self::Edsk => 32,
+            Self::Edsig => 64,
+        }
+    }
+
+    /// Decodes `input` into the payload.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `N` differs from the [payload length](Self::payload_len()).
+    /// - Panics if `input` contains chars outside the Base58 alphabet, or has an unexpected length.
+    /// - Panics if the prefix does not correspond to this value type.
+    /// - Panics if the checksum is invalid.
+    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        check_output_len(self.payload_len(), N);
+        match self.decode_inner(input) {
+            Ok(payload) => payload,
+            Err(err) => err.panic(self, input, N),
+        }
+    }
+
+    /// Decodes `input` into the payload, returning an error on invalid input.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if `input` contains chars outside the Base58 alphabet, or decodes
+    ///   to a length other than `N` (the payload length is expected).
+    /// - Returns an [`InvalidSymbol`](DecodeErrorKind::InvalidSymbol) error at position 0
+    ///   if the prefix does not correspond to this value type.
+    /// - Returns a [`ChecksumMismatch`](DecodeErrorKind::ChecksumMismatch) error if the checksum
+    ///   is invalid.
+    pub const fn try_decode<const N: usize>(self, input: &[u8]) -> Result<[u8; N], DecodeError> {
+        match self.decode_inner(input) {
+            Ok(payload) => Ok(payload),
+            Err(err) => Err(err.into_public(self, input)),
+        }
+    }
+
+    const fn decode_inner<const N: usize>(self, input: &[u8]) -> Result<[u8; N], TezosError> {
+        let prefix = self.prefix();
+        let (raw, raw_len) = match Base58::BITCOIN.try_decode_inner::<{ Self::MAX_RAW_LEN }>(input)
+        {
+            Ok(output) => output,
+            Err(err) => return Err(TezosError::Base58(err)),
+        };
+        if raw_len != prefix.len() + N + 4 {
+            return Err(TezosError::Length(raw_len));
+        }
+
+        let mut i = 0;
+        while i < prefix.len() {
+            if raw[i] != prefix[i] {
+                return Err(TezosError::Prefix(i, raw[i]));
+            }
+            i += 1;
+        }
+
+        let data_len = raw_len - 4;
+        let checksum = sha256(&sha256_of_prefix(&raw, data_len));
+        i = 0;
+        while i < 4 {
+            if checksum[i] != raw[data_len + i] {
+                return Err(TezosError::ChecksumMismatch);
+            }
+            i += 1;
+        }
+
+        let mut payload = [0_u8; N];
+        i = 0;
+        while i < N {
+            payload[i] = raw[prefix.len() + i];
+            i += 1;
+        }
+        Ok(payload)
+    }
+}
+
+/// Error produced by [`Tezos`].
+#[derive(Debug)]
+enum TezosError {
+    Base58(Base58Error),
+    /// Decoded length including the prefix and the checksum.
+    Length(usize),
+    /// Position and value of the mismatched prefix byte.
+    Prefix(usize, u8),
+    ChecksumMismatch,
+}
+
+impl TezosError {
+    const fn into_public(self, value_type: Tezos, input: &[u8]) -> DecodeError {
+        match self {
+            Self::Base58(err) => err.into_public(input),
+            Self::Length(raw_len) => {
+                let kind = DecodeErrorKind::InvalidLength {
+                    decoded_len: raw_len.saturating_sub(value_type.prefix().len() + 4),
+                    expected_len: value_type.payload_len(),
+                };
+                DecodeError::new(kind, input.len())
+            }
+            Self::Prefix(..) => {
+                let symbol = if input.is_empty() { 0 } else { input[0] };
+                DecodeError::new(DecodeErrorKind::InvalidSymbol { symbol }, 0)
+            }
+            Self::ChecksumMismatch => {
+                DecodeError::new(DecodeErrorKind::ChecksumMismatch, input.len())
+            }
+        }
+    }
+
+    const fn panic(self, value_type: Tezos, input: &[u8], payload_len: usize) -> ! {
+        match self {
+            Self::Base58(err) => err.panic(&Base58::BITCOIN, input, Tezos::MAX_RAW_LEN),
+            Self::Length(raw_len) => compile_panic!(
+                "Tezos value decodes to ", raw_len => fmt::<usize>(), " bytes, while a '",
+                value_type.text_prefix() => clip(5, ""), "' value must decode to ",
+                value_type.prefix().len() + payload_len + 4 => fmt::<usize>(), " bytes"
+            ),
+            Self::Prefix(pos, byte) => compile_panic!(
+                "Unexpected Tezos prefix byte ", byte => fmt::<u8>(), " at position ",
+                pos => fmt::<usize>(), "; expected a value starting with '",
+                value_type.text_prefix() => clip(5, ""), "'"
+            ),
+            Self::ChecksumMismatch => panic!("Tezos Base58 checksum mismatch"),
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Decoder for the TI-TXT firmware image format.
+
+use crate::{
+    decoder::check_output_len,
+    panics::{compile_assert, compile_panic, fmt},
+};
+
+/// Decoder for the TI-TXT firmware image format used by Texas Instruments tools for MSP430
+/// and other microcontrollers. An image consists of sections, each starting with an `@ADDR` line
+/// (the hex start address of the section) followed by lines of hex bytes separated by spaces;
+/// the image is terminated with a `q` line.
+///
+/// [`Self::decode()`] concatenates data of all sections; section addresses and their offsets
+/// in the concatenated data can be obtained with [`Self::sections()`]. Hex digits
+/// are case-insensitive.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, TiTxt, TiTxtSection};
+/// const IMAGE: &[u8] = b"
+///     @F000
+///     31 40 00 03 B2 40 80 5A 20 01
+///     @FFFE
+///     00 F0
+///     q
+/// ";
+/// const DATA: &[u8] = &decode!(TiTxt, IMAGE);
+/// assert_eq!(DATA.len(), 12);
+/// assert_eq!(DATA[10..], [0x00, 0xf0]);
+///
+/// const SECTIONS: [TiTxtSection; 2] = TiTxt::sections(IMAGE);
+/// assert_eq!(SECTIONS[1].address(), 0xfffe);
+/// assert_eq!(SECTIONS[1].offset(), 10);
+/// assert_eq!(SECTIONS[1].len(), 2);
+/// ```
+///
+/// Images without the `q` terminator are caught in compile time:
+///
+/// ```compile_fail
+/// # use const_decoder::{decode, TiTxt};
+/// const DATA: &[u8] = &decode!(TiTxt, b"@F000\n31 40 00 03\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TiTxt;
+
+impl TiTxt {
+    /// Decodes `input` into a byte array containing the concatenated data of all sections.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` is not a valid TI-TXT image.
+    pub const fn decode<const N: usize>(input: &[u8]) -> [u8; N] {
+        let (bytes, out_len, ..) = Self::parse::<N, 0>(input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    pub(crate) const fn decode_len(input: &[u8]) -> usize {
+        Self::parse::<0, 0>(input).1
+    }
+
+    /// Returns the number of sections in `input`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is not a valid TI-TXT image.
+    pub const fn section_count(input: &[u8]) -> usize {
+        Self::parse::<0, 0>(input).3
+    }
+
+    /// Returns information about sections in `input`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `K` differs from the number of sections.
+    /// - Panics if `input` is not a valid TI-TXT image.
+    pub const fn sections<const K: usize>(input: &[u8]) -> [TiTxtSection; K] {
+        let (_, _, sections, section_count) = Self::parse::<0, K>(input);
+        compile_assert!(
+            section_count == K,
+            "TI-TXT image has ", section_count => fmt::<usize>(),
+            " sections, while ", K => fmt::<usize>(), " sections are expected"
+        );
+        sections
+    }
+
+    const fn is_space(ch: u8) -> bool {
+        matches!(ch, b' ' | b'\t' | b'\r' | b'\n')
+    }
+
+    const fn hex_digit(input: &[u8], pos: usize) -> u8 {
+        let ch = input[pos];
+        match ch {
+            b'0'..=b'9' => ch - b'0',
+            b'a'..=b'f' => ch - b'a' + 10,
+            b'A'..=b'F' => ch - b'A' + 10,
+            _ => compile_panic!(
+                "Character '", ch as char => fmt::<char>(), "' at position ",
+                pos => fmt::<usize>(), " is not a hex digit"
+            ),
+        }
+    }
+
+    /// Parses the image. Returns the concatenated data (truncated to `N`) together with its full
+    /// length, and section info (truncated to `K`) together with the full number of sections.
+    const fn parse<const N: usize, const K: usize>(
+        input: &[u8],
+    ) -> ([u8; N], usize, [TiTxtSection; K], usize) {
+        let mut bytes = [0_u8; N];
+        let mut out_index = 0;
+        let mut sections = [TiTxtSection::EMPTY; K];
+        let mut section_count = 0;
+        let mut is_terminated = false;
+        let mut pos = 0;
+
+        while pos < input.len() {
+            let ch = input[pos];
+            if Self::is_space(ch) {
+                pos += 1;
+                continue;
+            }
+            compile_assert!(
+                !is_terminated,
+                "Unexpected data after the TI-TXT terminator at position ", pos => fmt::<usize>()
+            );
+
+            if ch == b'q' || ch == b'Q' {
+                is_terminated = true;
+                pos += 1;
+            } else if ch == b'@' {
+                let start = pos;
+                pos += 1;
+                let mut address = 0_u32;
+                while pos < input.len() && !Self::is_space(input[pos]) {
+                    compile_assert!(
+                        pos - start <= 8,
+                        "TI-TXT section address at position ", start => fmt::<usize>(),
+                        " is too long"
+                    );
+                    address = (address << 4) + Self::hex_digit(input, pos) as u32;
+                    pos += 1;
+                }
+                compile_assert!(
+                    pos > start + 1,
+                    "Empty TI-TXT section address at position ", start => fmt::<usize>()
+                );
+                if section_count < K {
+                    sections[section_count] = TiTxtSection {
+                        address,
+                        offset: out_index,
+                        len: 0,
+                    };
+                }
+                section_count += 1;
+            } else {
+                compile_assert!(
+                    section_count > 0,
+                    "TI-TXT data at position ", pos => fmt::<usize>(),
+                    " precedes the first section address"
+                );
+                compile_assert!(
+                    pos + 1 < input.len()
+                        && (pos + 2 == input.len() || Self::is_space(input[pos + 2])),
+                    "TI-TXT data byte at position ", pos => fmt::<usize>(),
+                    " must consist of 2 hex digits"
+                );
+                let byte = (Self::hex_digit(input, pos) << 4) + Self::hex_digit(input, pos + 1);
+                if out_index < N {
+                    bytes[out_index] = byte;
+                }
+                out_index += 1;
+                if section_count <= K {
+                    sections[section_count - 1].len += 1;
+                }
+                pos += 2;
+            }
+        }
+
+        compile_assert!(is_terminated, "TI-TXT image is not terminated with 'q'");
+        (bytes, out_index, sections, section_count)
+    }
+}
+
+/// Section of a [`TiTxt`] image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TiTxtSection {
+    address: u32,
+    offset: usize,
+    len: usize,
+}
+
+impl TiTxtSection {
+    const EMPTY: Self = Self {
+        address: 0,
+        offset: 0,
+        len: 0,
+    };
+
+    /// Returns the start address of the section.
+    pub const fn address(&self) -> u32 {
+        self.address
+    }
+
+    /// Returns the offset of the section data in the output of [`TiTxt::decode()`].
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the length of the section data in bytes.
+    #[allow(clippy::len_without_is_empty)] // empty sections are uncommon
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+}
@@ -0,0 +1,161 @@
+//! TON user-friendly address decoder.
+
+use crate::{
+    crc16::Crc16,
+    decoder::{try_check_input_len, Decoder},
+    error::{DecodeError, DecodeErrorKind},
+    panics::{compile_assert, compile_panic, fmt},
+};
+
+/// Parsed [TON user-friendly address][TON]. The address is encoded with base64 or base64url
+/// (the encoding is detected automatically) and consists of a flags byte, a workchain ID,
+/// a 32-byte account ID and a big-endian CRC-16/XMODEM checksum.
+///
+/// [TON]: https://docs.ton.org/learn/overviews/addresses
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::TonAddress;
+/// const ADDRESS: TonAddress =
+///     TonAddress::parse(b"EQDKbjIcfM6ezt8KjKJJLshZJJSqX7XOA4ff-W72r5gqPrHF");
+/// const ACCOUNT_ID: [u8; 32] = ADDRESS.account_id();
+/// assert_eq!(ADDRESS.workchain(), 0);
+/// assert!(ADDRESS.is_bounceable());
+/// assert!(!ADDRESS.is_testnet_only());
+/// assert_eq!(ACCOUNT_ID[..4], [0xca, 0x6e, 0x32, 0x1c]);
+/// ```
+///
+/// Addresses with an invalid checksum are caught in compile time:
+///
+/// ```compile_fail
+/// # use const_decoder::TonAddress;
+/// const ADDRESS: TonAddress =
+///     TonAddress::parse(b"EQDKbjIcfM6ezt8KjKJJLshZJJSqX7XOA4ff-W72r5gqPrHA");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TonAddress {
+    flags: u8,
+    workchain: i8,
+    account_id: [u8; 32],
+}
+
+impl TonAddress {
+    /// Length of an address in its text form.
+    pub const ENCODED_LEN: usize = 48;
+
+    const BOUNCEABLE_TAG: u8 = 0x11;
+    const NON_BOUNCEABLE_TAG: u8 = 0x51;
+    const TESTNET_FLAG: u8 = 0x80;
+
+    /// Parses an address from its user-friendly form.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `input` does not have 48 chars, or is not valid base64 / base64url.
+    /// - Panics if the flags byte is invalid.
+    /// - Panics if the checksum is invalid.
+    pub const fn parse(input: &[u8]) -> Self {
+        compile_assert!(
+            input.len() == Self::ENCODED_LEN,
+            "TON address must have 48 chars, got ", input.len() => fmt::<usize>()
+        );
+        let raw: [u8; 36] = Self::decoder(input).decode(input);
+
+        let flags = raw[0];
+        let tag = flags & !Self::TESTNET_FLAG;
+        if tag != Self::BOUNCEABLE_TAG && tag != Self::NON_BOUNCEABLE_TAG {
+            compile_panic!(
+                "Invalid TON address flags byte ", flags => fmt::<u8>(),
+                "; expected 0x11 (bounceable) or 0x51 (non-bounceable), optionally with \
+                 the 0x80 testnet flag"
+            );
+        }
+        let checksum = Crc16::Xmodem.checksum_of_prefix(&raw, 34);
+        let trailer = u16::from_be_bytes([raw[34], raw[35]]);
+        compile_assert!(
+            checksum == trailer,
+            "TON address checksum mismatch: computed ", checksum => fmt::<u16>(),
+            ", while the address contains ", trailer => fmt::<u16>()
+        );
+
+        Self::from_raw(&raw)
+    }
+
+    /// Parses an address from its user-friendly form, returning an error on invalid input.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if `input` does not have 48 chars, or is not valid base64 / base64url.
+    /// - Returns an [`InvalidSymbol`](DecodeErrorKind::InvalidSymbol) error at position 0
+    ///   if the flags byte is invalid.
+    /// - Returns a [`ChecksumMismatch`](DecodeErrorKind::ChecksumMismatch) error if the checksum
+    ///   is invalid.
+    pub const fn try_parse(input: &[u8]) -> Result<Self, DecodeError> {
+        if let Err(err) = try_check_input_len(input, Self::ENCODED_LEN) {
+            return Err(err);
+        }
+        let raw: [u8; 36] = match Self::decoder(input).try_decode(input) {
+            Ok(raw) => raw,
+            Err(err) => return Err(err),
+        };
+        let tag = raw[0] & !Self::TESTNET_FLAG;
+        if tag != Self::BOUNCEABLE_TAG && tag != Self::NON_BOUNCEABLE_TAG {
+            let kind = DecodeErrorKind::InvalidSymbol { symbol: input[0] };
+            return Err(DecodeError::new(kind, 0));
+        }
+        let checksum = Crc16::Xmodem.checksum_of_prefix(&raw, 34);
+        if checksum != u16::from_be_bytes([raw[34], raw[35]]) {
+            let kind = DecodeErrorKind::ChecksumMismatch;
+            return Err(DecodeError::new(kind, input.len()));
+        }
+        Ok(Self::from_raw(&raw))
+    }
+
+    #[allow(clippy::cast_possible_wrap)] // intentional
+    const fn from_raw(raw: &[u8; 36]) -> Self {
+        let mut account_id = [0_u8; 32];
+        let mut i = 0;
+        while i < 32 {
+            account_id[i] = raw[i + 2];
+            i += 1;
+        }
+        Self {
+            flags: raw[0],
+            workchain: raw[1] as i8,
+            account_id,
+        }
+    }
+
+    /// Selects the base64 variant based on the chars present in `input`.
+    const fn decoder(input: &[u8]) -> Decoder {
+        let mut i = 0;
+        while i < input.len() {
+            if input[i] == b'+' || input[i] == b'/' {
+                return Decoder::Base64;
+            }
+            i += 1;
+        }
+        Decoder::Base64Url
+    }
+
+    /// Returns the workchain ID, e.g. 0 for the basechain or -1 for the masterchain.
+    pub const fn workchain(&self) -> i8 {
+        self.workchain
+    }
+
+    /// Returns the 32-byte account ID.
+    pub const fn account_id(&self) -> [u8; 32] {
+        self.account_id
+    }
+
+    /// Checks whether the address is bounceable.
+    pub const fn is_bounceable(&self) -> bool {
+        self.flags & !Self::TESTNET_FLAG == Self::BOUNCEABLE_TAG
+    }
+
+    /// Checks whether the address is intended for the testnet only.
+    pub const fn is_testnet_only(&self) -> bool {
+        self.flags & Self::TESTNET_FLAG != 0
+    }
+}
@@ -0,0 +1,96 @@
+//! ULID decoder.
+
+use crate::panics::{compile_assert, compile_panic, fmt};
+
+/// Decoder for [ULIDs] (Universally Unique Lexicographically Sortable Identifiers)
+/// in their canonical text form: 26 chars of Crockford's Base32 encoding a 128-bit big-endian
+/// number. Decoding is case-insensitive; as per Crockford's Base32, `I` and `L` are decoded as `1`,
+/// and `O` is decoded as `0`.
+///
+/// Since 26 Base32 chars encode 130 bits, the first char must be in the `0..=7` range;
+/// otherwise, the ULID overflows 128 bits and is rejected.
+///
+/// [ULIDs]: https://github.com/ulid/spec
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Ulid};
+/// const ID: [u8; 16] = Ulid::decode(b"01ARZ3NDEKTSV4RRFFQ69G5FAV");
+/// assert_eq!(ID[..6], [0x01, 0x56, 0x3e, 0x3a, 0xb5, 0xd3]); // timestamp
+/// const SAME: &[u8] = &decode!(Ulid, b"01arz3ndektsv4rrffq69g5fav");
+/// assert_eq!(SAME, ID);
+/// ```
+///
+/// Overflowing ULIDs are caught in compile time:
+///
+/// ```compile_fail
+/// # use const_decoder::Ulid;
+/// const ID: [u8; 16] = Ulid::decode(b"81ARZ3NDEKTSV4RRFFQ69G5FAV");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ulid;
+
+impl Ulid {
+    /// Length of a ULID in its text form.
+    pub const ENCODED_LEN: usize = 26;
+    /// Length of a ULID in bytes.
+    pub const DECODED_LEN: usize = 16;
+
+    /// Decodes `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `input` does not have 26 chars.
+    /// - Panics if `input` contains chars outside Crockford's Base32 alphabet.
+    /// - Panics if the encoded value overflows 128 bits.
+    pub const fn decode(input: &[u8]) -> [u8; 16] {
+        compile_assert!(
+            input.len() == Self::ENCODED_LEN,
+            "ULID must have 26 chars, got ", input.len() => fmt::<usize>()
+        );
+        let first_digit = Self::digit(input, 0);
+        compile_assert!(
+            first_digit < 8,
+            "ULID overflows 128 bits: first char '", input[0] as char => fmt::<char>(),
+            "' must be in the 0..=7 range"
+        );
+
+        let mut bytes = [0_u8; 16];
+        // The remaining 2 bits of the first digit are zero, so we start at bit offset -2.
+        let mut buffer = first_digit as u16;
+        let mut buffer_bits = 3;
+        let mut in_index = 1;
+        let mut out_index = 0;
+        while in_index < input.len() {
+            buffer = (buffer << 5) | Self::digit(input, in_index) as u16;
+            buffer_bits += 5;
+            if buffer_bits >= 8 {
+                buffer_bits -= 8;
+                bytes[out_index] = (buffer >> buffer_bits).to_le_bytes()[0];
+                buffer &= (1 << buffer_bits) - 1;
+                out_index += 1;
+            }
+            in_index += 1;
+        }
+        bytes
+    }
+
+    const fn digit(input: &[u8], pos: usize) -> u8 {
+        let ch = input[pos];
+        match ch.to_ascii_uppercase() {
+            ch @ b'0'..=b'9' => ch - b'0',
+            b'O' => 0,
+            b'I' | b'L' => 1,
+            ch @ b'A'..=b'H' => ch - b'A' + 10,
+            ch @ (b'J' | b'K') => ch - b'J' + 18,
+            ch @ (b'M' | b'N') => ch - b'M' + 20,
+            ch @ b'P'..=b'T' => ch - b'P' + 22,
+            ch @ b'V'..=b'Z' => ch - b'V' + 27,
+            _ => compile_panic!(
+                "Character '", ch as char => fmt::<char>(), "' at position ", pos => fmt::<usize>(),
+                " is not a part of Crockford's Base32 alphabet"
+            ),
+        }
+    }
+}
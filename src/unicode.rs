@@ -0,0 +1,352 @@
+//! Decoders for binary-to-text encodings with alphabets of Unicode chars.
+
+use crate::{
+    decoder::check_output_len,
+    panics::{clip, compile_assert, compile_panic, fmt},
+};
+
+/// Returns the length of a UTF-8 encoded char by its first byte.
+const fn utf8_char_len(first_byte: u8) -> usize {
+    match first_byte {
+        0x00..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        _ => 4,
+    }
+}
+
+/// Counts chars in a UTF-8 string.
+const fn char_count(s: &[u8]) -> usize {
+    let mut count = 0;
+    let mut pos = 0;
+    while pos < s.len() {
+        pos += utf8_char_len(s[pos]);
+        count += 1;
+    }
+    count
+}
+
+/// Finds the index of the char at `input[pos..pos + len]` in `alphabet`.
+const fn find_char(alphabet: &[u8], input: &[u8], pos: usize, len: usize) -> Option<usize> {
+    let mut alphabet_pos = 0;
+    let mut index = 0;
+    while alphabet_pos < alphabet.len() {
+        let char_len = utf8_char_len(alphabet[alphabet_pos]);
+        if char_len == len {
+            let mut i = 0;
+            while i < len && alphabet[alphabet_pos + i] == input[pos + i] {
+                i += 1;
+            }
+            if i == len {
+                return Some(index);
+            }
+        }
+        alphabet_pos += char_len;
+        index += 1;
+    }
+    None
+}
+
+/// Reads the char at `input[pos..]` and resolves its index in `alphabet`. Returns the index
+/// and the char length in bytes.
+const fn read_char(alphabet: &[u8], input: &[u8], pos: usize, encoding: &str) -> (usize, usize) {
+    let len = utf8_char_len(input[pos]);
+    compile_assert!(
+        pos + len <= input.len(),
+        "Truncated UTF-8 char at position ", pos => fmt::<usize>()
+    );
+    let Some(index) = find_char(alphabet, input, pos, len) else {
+        compile_panic!(
+            "Char at position ", pos => fmt::<usize>(), " is not in the ",
+            encoding => clip(8, ""), " alphabet"
+        );
+    };
+    (index, len)
+}
+
+/// Decoder for [Ecoji] (version 1), an encoding representing each 10 bits of data
+/// with an emoji. Each 5 bytes of data are encoded as 4 emojis; the last group of emojis
+/// may be padded.
+///
+/// The decoder is parameterized by the alphabet, which must consist of 1029 chars in the following
+/// order: 1024 emojis encoding 10-bit values, the emoji used as filler padding, and 4 emojis
+/// encoding the last 2 bits of a 4-byte final group. The alphabet is not bundled with the crate;
+/// it can be taken from the reference implementation (e.g., via [`include_str!`]). Alphabet chars
+/// are not checked for uniqueness. Line breaks in the input are skipped.
+///
+/// [Ecoji]: https://github.com/keith-turner/ecoji
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Ecoji};
+/// # const fn cjk_alphabet() -> [u8; 3 * 1029] {
+/// #     let mut bytes = [0_u8; 3 * 1029];
+/// #     let mut i = 0;
+/// #     while i < 1029 {
+/// #         let code_point = 0x4e00 + i as u32;
+/// #         bytes[3 * i] = 0xe0 | (code_point >> 12) as u8;
+/// #         bytes[3 * i + 1] = 0x80 | ((code_point >> 6) & 0x3f) as u8;
+/// #         bytes[3 * i + 2] = 0x80 | (code_point & 0x3f) as u8;
+/// #         i += 1;
+/// #     }
+/// #     bytes
+/// # }
+/// # const ALPHABET_BYTES: [u8; 3 * 1029] = cjk_alphabet();
+/// // In real code, the alphabet would be read from the Ecoji emoji list. Here, we use
+/// // a synthetic alphabet consisting of CJK ideographs starting from U+4E00.
+/// const ALPHABET: &str = match core::str::from_utf8(&ALPHABET_BYTES) {
+///     Ok(s) => s,
+///     Err(_) => panic!("invalid alphabet"),
+/// };
+/// const ECOJI: Ecoji = Ecoji::new(ALPHABET);
+/// const TEXT: &[u8] = &decode!(ECOJI, "価傒乀刀".as_bytes());
+/// assert_eq!(TEXT, b"hi!");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ecoji {
+    alphabet: &'static str,
+}
+
+impl Ecoji {
+    const PADDING: usize = 1024;
+
+    /// Creates a decoder with the specified alphabet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alphabet` does not consist of 1029 chars.
+    pub const fn new(alphabet: &'static str) -> Self {
+        let count = char_count(alphabet.as_bytes());
+        compile_assert!(
+            count == 1029,
+            "Ecoji alphabet must consist of 1029 chars; got ", count => fmt::<usize>()
+        );
+        Self { alphabet }
+    }
+
+    /// Decodes `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` contains chars not in the alphabet, is not split into groups
+    ///   of 4 chars, or is incorrectly padded.
+    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = self.do_decode::<N>(input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    pub(crate) const fn decode_len(self, input: &[u8]) -> usize {
+        self.do_decode::<0>(input).1
+    }
+
+    const fn do_decode<const N: usize>(self, input: &[u8]) -> ([u8; N], usize) {
+        let alphabet = self.alphabet.as_bytes();
+        let mut bytes = [0_u8; N];
+        let mut out_index = 0;
+        let mut in_index = 0;
+        let mut group = [0_usize; 4];
+        let mut group_len = 0;
+        let mut group_pos = 0;
+        let mut is_finished = false;
+
+        while in_index < input.len() {
+            if input[in_index] == b'\n' || input[in_index] == b'\r' {
+                in_index += 1;
+                continue;
+            }
+            compile_assert!(
+                !is_finished,
+                "Ecoji data after padding at position ", in_index => fmt::<usize>()
+            );
+            if group_len == 0 {
+                group_pos = in_index;
+            }
+            let (index, char_len) = read_char(alphabet, input, in_index, "Ecoji");
+            group[group_len] = index;
+            group_len += 1;
+            in_index += char_len;
+            if group_len < 4 {
+                continue;
+            }
+
+            let (group_bytes, len) = Self::decode_group(group, group_pos);
+            let mut i = 0;
+            while i < len {
+                if out_index < N {
+                    bytes[out_index] = group_bytes[i];
+                }
+                out_index += 1;
+                i += 1;
+            }
+            is_finished = len < 5;
+            group_len = 0;
+        }
+
+        compile_assert!(
+            group_len == 0,
+            "Incomplete Ecoji group at position ", group_pos => fmt::<usize>()
+        );
+        (bytes, out_index)
+    }
+
+    /// Decodes a group of 4 alphabet indices into up to 5 bytes.
+    #[allow(clippy::cast_possible_truncation)] // intended
+    const fn decode_group(group: [usize; 4], pos: usize) -> ([u8; 5], usize) {
+        let [v0, v1, v2, v3] = group;
+        compile_assert!(
+            v0 < Self::PADDING && v1 <= Self::PADDING && v2 <= Self::PADDING,
+            "Invalid Ecoji padding in group at position ", pos => fmt::<usize>()
+        );
+        let mut bytes = [0_u8; 5];
+        bytes[0] = (v0 >> 2) as u8;
+        if v1 == Self::PADDING {
+            compile_assert!(
+                v2 == Self::PADDING && v3 == Self::PADDING,
+                "Invalid Ecoji padding in group at position ", pos => fmt::<usize>()
+            );
+            return (bytes, 1);
+        }
+        bytes[1] = (((v0 & 0x03) << 6) | (v1 >> 4)) as u8;
+        if v2 == Self::PADDING {
+            compile_assert!(
+                v3 == Self::PADDING,
+                "Invalid Ecoji padding in group at position ", pos => fmt::<usize>()
+            );
+            return (bytes, 2);
+        }
+        bytes[2] = (((v1 & 0x0f) << 4) | (v2 >> 6)) as u8;
+        if v3 == Self::PADDING {
+            return (bytes, 3);
+        }
+        if v3 > Self::PADDING {
+            bytes[3] = (((v2 & 0x3f) << 2) | (v3 - Self::PADDING - 1)) as u8;
+            return (bytes, 4);
+        }
+        bytes[3] = (((v2 & 0x3f) << 2) | (v3 >> 8)) as u8;
+        bytes[4] = v3 as u8;
+        (bytes, 5)
+    }
+}
+
+/// Decoder for [Base2048], an encoding designed to pack as much data as possible into a tweet.
+/// Each 11 bits of data are encoded with a char from a 2048-char repertoire; the final
+/// 1 to 3 bits may be encoded with one of 8 "tail" chars. Incomplete trailing bits are padded
+/// with 1s.
+///
+/// The decoder is parameterized by the alphabet, which must consist of 2056 chars: 2048 chars
+/// encoding 11-bit values followed by 8 tail chars encoding 3-bit values. The alphabet
+/// is not bundled with the crate; it can be taken from the reference implementation
+/// (e.g., via [`include_str!`]). Alphabet chars are not checked for uniqueness.
+///
+/// [Base2048]: https://github.com/qntm/base2048
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Base2048};
+/// # const fn cjk_alphabet() -> [u8; 3 * 2056] {
+/// #     let mut bytes = [0_u8; 3 * 2056];
+/// #     let mut i = 0;
+/// #     while i < 2056 {
+/// #         let code_point = 0x4e00 + i as u32;
+/// #         bytes[3 * i] = 0xe0 | (code_point >> 12) as u8;
+/// #         bytes[3 * i + 1] = 0x80 | ((code_point >> 6) & 0x3f) as u8;
+/// #         bytes[3 * i + 2] = 0x80 | (code_point & 0x3f) as u8;
+/// #         i += 1;
+/// #     }
+/// #     bytes
+/// # }
+/// # const ALPHABET_BYTES: [u8; 3 * 2056] = cjk_alphabet();
+/// // In real code, the alphabet would be read from the Base2048 repertoire. Here, we use
+/// // a synthetic alphabet consisting of CJK ideographs starting from U+4E00.
+/// const ALPHABET: &str = match core::str::from_utf8(&ALPHABET_BYTES) {
+///     Ok(s) => s,
+///     Err(_) => panic!("invalid alphabet"),
+/// };
+/// const BASE2048: Base2048 = Base2048::new(ALPHABET);
+/// const TEXT: &[u8] = &decode!(BASE2048, "元偈嘃".as_bytes());
+/// assert_eq!(TEXT, b"hi!");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Base2048 {
+    alphabet: &'static str,
+}
+
+impl Base2048 {
+    const TAIL_START: usize = 2048;
+
+    /// Creates a decoder with the specified alphabet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alphabet` does not consist of 2056 chars.
+    pub const fn new(alphabet: &'static str) -> Self {
+        let count = char_count(alphabet.as_bytes());
+        compile_assert!(
+            count == 2056,
+            "Base2048 alphabet must consist of 2056 chars; got ", count => fmt::<usize>()
+        );
+        Self { alphabet }
+    }
+
+    /// Decodes `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` contains chars not in the alphabet, a tail char not at the end
+    ///   of the input, or padding bits other than 1s.
+    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        let (bytes, out_len) = self.do_decode::<N>(input);
+        check_output_len(out_len, N);
+        bytes
+    }
+
+    pub(crate) const fn decode_len(self, input: &[u8]) -> usize {
+        self.do_decode::<0>(input).1
+    }
+
+    #[allow(clippy::cast_possible_truncation)] // intended
+    const fn do_decode<const N: usize>(self, input: &[u8]) -> ([u8; N], usize) {
+        let alphabet = self.alphabet.as_bytes();
+        let mut bytes = [0_u8; N];
+        let mut out_index = 0;
+        let mut in_index = 0;
+        let mut acc = 0_u32;
+        let mut filled_bits = 0;
+
+        while in_index < input.len() {
+            let (index, char_len) = read_char(alphabet, input, in_index, "Base2048");
+            let (value, bit_count) = if index < Self::TAIL_START {
+                (index as u32, 11)
+            } else {
+                compile_assert!(
+                    in_index + char_len == input.len(),
+                    "Base2048 tail char at position ", in_index => fmt::<usize>(),
+                    " is not at the end of input"
+                );
+                ((index - Self::TAIL_START) as u32, 3)
+            };
+            acc = ((acc << bit_count) | value) & 0x7ffff;
+            filled_bits += bit_count;
+            while filled_bits >= 8 {
+                filled_bits -= 8;
+                if out_index < N {
+                    bytes[out_index] = (acc >> filled_bits) as u8;
+                }
+                out_index += 1;
+            }
+            in_index += char_len;
+        }
+
+        let padding_mask = (1 << filled_bits) - 1;
+        compile_assert!(
+            acc & padding_mask == padding_mask,
+            "Invalid padding of Base2048 data: padding bits must be 1s"
+        );
+        (bytes, out_index)
+    }
+}
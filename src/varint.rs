@@ -0,0 +1,98 @@
+//! LEB128 / protobuf varints.
+
+use crate::panics::{compile_assert, fmt};
+
+/// Helpers for decoding unsigned [LEB128] varints, as used e.g. in Protocol Buffers,
+/// WebAssembly and multiformats.
+///
+/// All methods take a byte slice and the position to start reading from, and return
+/// the decoded value together with the number of consumed bytes.
+///
+/// [LEB128]: https://en.wikipedia.org/wiki/LEB128
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::Varint;
+/// // Protobuf message with 2 fields: `int32 a = 1` set to 150, `sint32 b = 2` set to -2
+/// const MESSAGE: &[u8] = &[0x08, 0x96, 0x01, 0x10, 0x03];
+/// const A: (u32, usize) = Varint::read_u32(MESSAGE, 1);
+/// assert_eq!(A, (150, 2));
+/// const B: (i32, usize) = Varint::read_zigzag_i32(MESSAGE, 4);
+/// assert_eq!(B, (-2, 1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Varint;
+
+impl Varint {
+    /// Maximum length of a varint encoding a `u64`.
+    pub const MAX_LEN: usize = 10;
+
+    /// Reads a `u64` varint starting from `pos`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input ends before the varint, or the varint does not fit into `u64`.
+    pub const fn read_u64(bytes: &[u8], pos: usize) -> (u64, usize) {
+        let mut value = 0_u64;
+        let mut len = 0;
+        loop {
+            compile_assert!(
+                pos + len < bytes.len(),
+                "Unexpected end of input while reading varint at position ", pos => fmt::<usize>()
+            );
+            let byte = bytes[pos + len];
+            compile_assert!(
+                len + 1 < Self::MAX_LEN || (len + 1 == Self::MAX_LEN && byte <= 1),
+                "Varint at position ", pos => fmt::<usize>(), " overflows u64"
+            );
+            value |= ((byte & 0x7f) as u64) << (7 * len);
+            len += 1;
+            if byte & 0x80 == 0 {
+                return (value, len);
+            }
+        }
+    }
+
+    /// Reads a `u32` varint starting from `pos`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input ends before the varint, or the varint does not fit into `u32`.
+    #[allow(clippy::cast_possible_truncation)] // checked
+    pub const fn read_u32(bytes: &[u8], pos: usize) -> (u32, usize) {
+        let (value, len) = Self::read_u64(bytes, pos);
+        compile_assert!(
+            value <= u32::MAX as u64,
+            "Varint at position ", pos => fmt::<usize>(), " overflows u32"
+        );
+        (value as u32, len)
+    }
+
+    /// Reads a zigzag-encoded `i64` varint (e.g., protobuf `sint64`) starting from `pos`.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Self::read_u64()`].
+    pub const fn read_zigzag_i64(bytes: &[u8], pos: usize) -> (i64, usize) {
+        let (value, len) = Self::read_u64(bytes, pos);
+        (Self::zigzag_decode(value), len)
+    }
+
+    /// Reads a zigzag-encoded `i32` varint (e.g., protobuf `sint32`) starting from `pos`.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`Self::read_u32()`].
+    #[allow(clippy::cast_possible_truncation)] // intentional
+    pub const fn read_zigzag_i32(bytes: &[u8], pos: usize) -> (i32, usize) {
+        let (value, len) = Self::read_u32(bytes, pos);
+        (Self::zigzag_decode(value as u64) as i32, len)
+    }
+
+    /// Decodes a zigzag-encoded integer: 0 → 0, 1 → -1, 2 → 1, 3 → -2, etc.
+    #[allow(clippy::cast_possible_wrap)] // intentional
+    pub const fn zigzag_decode(value: u64) -> i64 {
+        (value >> 1) as i64 ^ -((value & 1) as i64)
+    }
+}
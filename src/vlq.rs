@@ -0,0 +1,145 @@
+//! Base64 VLQ decoder.
+
+use crate::panics::{compile_assert, compile_panic, fmt};
+
+/// Decoder for Base64 VLQ sequences, as used in the `mappings` field of [JavaScript source maps].
+///
+/// Each value is encoded with one or more chars of the standard base64 alphabet. The 6 bits
+/// of each char contain a continuation bit (the most significant one) and 5 bits of the value,
+/// with the least significant groups going first. The least significant bit of the first group
+/// is the sign of the value.
+///
+/// [`Self::decode()`] decodes a contiguous sequence of values, such as a single segment
+/// in source map mappings. Use the [`decode_vlq!`](crate::decode_vlq) macro to avoid specifying
+/// the number of values.
+///
+/// [JavaScript source maps]: https://tc39.es/ecma426/
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::Base64Vlq;
+/// const SEGMENT: [i32; 4] = Base64Vlq::decode(b"AAgBC");
+/// assert_eq!(SEGMENT, [0, 0, 16, 1]);
+/// const VALUE: (i32, usize) = Base64Vlq::read_i32(b"AAgBC", 2);
+/// assert_eq!(VALUE, (16, 2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Base64Vlq;
+
+impl Base64Vlq {
+    const CONTINUATION_BIT: u8 = 0x20;
+    /// Maximum number of chars in a single value fitting into `i32`.
+    const MAX_VALUE_LEN: usize = 7;
+
+    /// Reads an `i32` value starting from `pos`. Returns the value together with the number
+    /// of consumed chars.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the input ends before the value, or contains invalid chars.
+    /// - Panics if the value does not fit into `i32`.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)] // checked
+    pub const fn read_i32(input: &[u8], pos: usize) -> (i32, usize) {
+        let mut magnitude = 0_u64;
+        let mut shift = 0;
+        let mut len = 0;
+        loop {
+            compile_assert!(
+                pos + len < input.len(),
+                "Unexpected end of input while reading VLQ value at position ", pos => fmt::<usize>()
+            );
+            compile_assert!(
+                len < Self::MAX_VALUE_LEN,
+                "VLQ value at position ", pos => fmt::<usize>(), " overflows i32"
+            );
+            let digit = Self::digit(input, pos + len);
+            magnitude |= ((digit & !Self::CONTINUATION_BIT) as u64) << shift;
+            shift += 5;
+            len += 1;
+            if digit & Self::CONTINUATION_BIT == 0 {
+                break;
+            }
+        }
+
+        let is_negative = magnitude & 1 != 0;
+        magnitude >>= 1;
+        let max_magnitude = if is_negative {
+            1 << 31
+        } else {
+            i32::MAX as u64
+        };
+        compile_assert!(
+            magnitude <= max_magnitude,
+            "VLQ value at position ", pos => fmt::<usize>(), " overflows i32"
+        );
+        let value = if is_negative {
+            (magnitude as i64).wrapping_neg() as i32
+        } else {
+            magnitude as i32
+        };
+        (value, len)
+    }
+
+    /// Decodes a sequence of values from `input`.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the provided length is insufficient or too large for `input`.
+    /// - Panics if `input` contains invalid chars, ends in the middle of a value, or contains
+    ///   values not fitting into `i32`.
+    pub const fn decode<const N: usize>(input: &[u8]) -> [i32; N] {
+        let len = Self::value_count(input);
+        compile_assert!(
+            len == N,
+            "Input contains ", len => fmt::<usize>(), " VLQ values, while the output has ",
+            N => fmt::<usize>(), " values"
+        );
+
+        let mut values = [0_i32; N];
+        let mut pos = 0;
+        let mut i = 0;
+        while i < N {
+            let (value, value_len) = Self::read_i32(input, pos);
+            values[i] = value;
+            pos += value_len;
+            i += 1;
+        }
+        values
+    }
+
+    /// Returns the number of values in `input`. An incomplete trailing value is counted as well.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` contains invalid chars.
+    pub const fn value_count(input: &[u8]) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+        while i < input.len() {
+            if Self::digit(input, i) & Self::CONTINUATION_BIT == 0 {
+                count += 1;
+            }
+            i += 1;
+        }
+        if !input.is_empty() && Self::digit(input, input.len() - 1) & Self::CONTINUATION_BIT != 0 {
+            count += 1; // accounts for the incomplete value, which will lead to a panic
+        }
+        count
+    }
+
+    const fn digit(input: &[u8], pos: usize) -> u8 {
+        let ch = input[pos];
+        match ch {
+            b'A'..=b'Z' => ch - b'A',
+            b'a'..=b'z' => ch - b'a' + 26,
+            b'0'..=b'9' => ch - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => compile_panic!(
+                "Character '", ch as char => fmt::<char>(), "' at position ", pos => fmt::<usize>(),
+                " is not a part of the base64 alphabet"
+            ),
+        }
+    }
+}
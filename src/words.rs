@@ -0,0 +1,70 @@
+//! Shared helpers for decoders of word-based encodings.
+
+use core::cmp::Ordering;
+
+/// Returns the start and end positions of the next word in `input` starting from `pos`.
+/// Words are separated by ASCII whitespace. If there are no more words, both positions
+/// are equal to `input.len()`.
+pub(crate) const fn next_word(input: &[u8], mut pos: usize) -> (usize, usize) {
+    while pos < input.len() && input[pos].is_ascii_whitespace() {
+        pos += 1;
+    }
+    let start = pos;
+    while pos < input.len() && !input[pos].is_ascii_whitespace() {
+        pos += 1;
+    }
+    (start, pos)
+}
+
+/// Compares `input[start..end]` with `word` in the ASCII case-insensitive manner.
+/// `word` is assumed to be uppercase if `uppercase` is set, and lowercase otherwise.
+pub(crate) const fn compare_word(
+    input: &[u8],
+    start: usize,
+    end: usize,
+    word: &[u8],
+    uppercase: bool,
+) -> Ordering {
+    let mut i = 0;
+    while start + i < end && i < word.len() {
+        let ch = if uppercase {
+            input[start + i].to_ascii_uppercase()
+        } else {
+            input[start + i].to_ascii_lowercase()
+        };
+        if ch < word[i] {
+            return Ordering::Less;
+        } else if ch > word[i] {
+            return Ordering::Greater;
+        }
+        i += 1;
+    }
+
+    let len = end - start;
+    if len < word.len() {
+        Ordering::Less
+    } else if len > word.len() {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Finds `input[start..end]` in the sorted `words[range_start..range_end]` using binary search.
+pub(crate) const fn find_word(
+    input: &[u8],
+    (start, end): (usize, usize),
+    words: &[&[u8]],
+    (mut range_start, mut range_end): (usize, usize),
+    uppercase: bool,
+) -> Option<usize> {
+    while range_start < range_end {
+        let mid = (range_start + range_end) / 2;
+        match compare_word(input, start, end, words[mid], uppercase) {
+            Ordering::Less => range_end = mid,
+            Ordering::Greater => range_start = mid + 1,
+            Ordering::Equal => return Some(mid),
+        }
+    }
+    None
+}
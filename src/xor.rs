@@ -0,0 +1,84 @@
+//! XOR de-obfuscation.
+
+use crate::{
+    decoder::{check_output_len, try_check_output_len},
+    error::DecodeError,
+    panics::compile_assert,
+};
+
+/// Decoder applying XOR to the input bytes with a repeating key. This allows to keep embedded
+/// strings or blobs obfuscated in the source code or in `include_bytes!` files (so that they
+/// cannot be found by a simple search), while having them de-obfuscated in compile time.
+///
+/// Keep in mind that this is obfuscation, not encryption: the key is stored in the source code
+/// alongside the data, and the de-obfuscated bytes are present in the compiled binary as is.
+/// Since XOR is its own inverse, the obfuscated data can be produced by applying
+/// the decoder to the plaintext.
+///
+/// The input is raw bytes, so the decoder can be nested with other decoders, e.g. to
+/// de-obfuscate a hex-encoded value.
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::{decode, Decoder, Xored};
+/// const SECRET: &[u8] = &decode!(Xored::new(b"k3y"), &decode!(Decoder::Hex, b"18561a19560d"));
+/// assert_eq!(SECRET, b"secret");
+///
+/// const GREETING: &[u8] = &decode!(
+///     Xored::new(&[0x5a, 0xa5]),
+///     &decode!(Decoder::Base64, b"EsA2yTWJetI11zbBew=="),
+/// );
+/// assert_eq!(GREETING, b"Hello, world!");
+///
+/// const RAW: [u8; 2] = Xored::new(b"\xff").decode(b"\x0f\xf0");
+/// assert_eq!(RAW, [0xf0, 0x0f]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Xored {
+    key: &'static [u8],
+}
+
+impl Xored {
+    /// Creates a decoder applying XOR to the input with the repeating `key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is empty.
+    pub const fn new(key: &'static [u8]) -> Self {
+        compile_assert!(!key.is_empty(), "XOR key must not be empty");
+        Self { key }
+    }
+
+    /// De-obfuscates `input` into a byte array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the provided length is insufficient or too large for `input`.
+    pub const fn decode<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        check_output_len(input.len(), N);
+        self.apply(input)
+    }
+
+    /// De-obfuscates `input` into a byte array, returning an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidLength`](crate::DecodeErrorKind::InvalidLength) error if `input`
+    /// has a length other than `N`.
+    pub const fn try_decode<const N: usize>(self, input: &[u8]) -> Result<[u8; N], DecodeError> {
+        const_try!(try_check_output_len(input, input.len(), N));
+        Ok(self.apply(input))
+    }
+
+    /// Applies XOR to the first `N` bytes of `input`.
+    const fn apply<const N: usize>(self, input: &[u8]) -> [u8; N] {
+        let mut bytes = [0_u8; N];
+        let mut i = 0;
+        while i < N {
+            bytes[i] = input[i] ^ self.key[i % self.key.len()];
+            i += 1;
+        }
+        bytes
+    }
+}
@@ -0,0 +1,80 @@
+//! Yubico OTP decoder.
+
+use crate::{
+    decoder::Decoder,
+    panics::{compile_assert, fmt},
+};
+
+/// Parsed [Yubico one-time password][OTP] in the default 44-char format: a 12-char public ID
+/// (identifying the security key) followed by a 32-char encrypted token, both encoded with
+/// the [modhex encoding](Decoder::MODHEX). The token is not decrypted or otherwise validated,
+/// since this requires the AES key stored on the security key.
+///
+/// [OTP]: https://developers.yubico.com/OTP/OTPs_Explained.html
+///
+/// # Examples
+///
+/// ```
+/// # use const_decoder::YubikeyOtp;
+/// const OTP: YubikeyOtp = YubikeyOtp::parse(b"ccccccjlkgjhcccbcdcecfcgchcicjckclcncrctcucv");
+/// assert_eq!(OTP.public_id(), [0, 0, 0, 0x8a, 0x95, 0x86]);
+/// assert_eq!(OTP.encrypted_token()[..4], [0, 1, 2, 3]);
+/// ```
+///
+/// OTPs with an unexpected length are caught in compile time:
+///
+/// ```compile_fail
+/// # use const_decoder::YubikeyOtp;
+/// const OTP: YubikeyOtp = YubikeyOtp::parse(b"ccccccjlkgjhcccbcdcecfcgchcicjckclcncrctcu");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct YubikeyOtp {
+    public_id: [u8; 6],
+    encrypted_token: [u8; 16],
+}
+
+impl YubikeyOtp {
+    /// Length of an OTP in its text form.
+    pub const ENCODED_LEN: usize = 44;
+
+    /// Parses an OTP from its modhex string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` does not have 44 chars, or contains chars outside the modhex alphabet.
+    pub const fn parse(input: &[u8]) -> Self {
+        compile_assert!(
+            input.len() == Self::ENCODED_LEN,
+            "YubiKey OTP must have 44 chars (12 chars of public ID and 32 chars of encrypted \\
+             token), got ", input.len() => fmt::<usize>()
+        );
+        let bytes: [u8; 22] = Decoder::MODHEX.decode(input);
+
+        let mut public_id = [0_u8; 6];
+        let mut i = 0;
+        while i < 6 {
+            public_id[i] = bytes[i];
+            i += 1;
+        }
+        let mut encrypted_token = [0_u8; 16];
+        i = 0;
+        while i < 16 {
+            encrypted_token[i] = bytes[6 + i];
+            i += 1;
+        }
+        Self {
+            public_id,
+            encrypted_token,
+        }
+    }
+
+    /// Returns the 6-byte public ID of the security key.
+    pub const fn public_id(&self) -> [u8; 6] {
+        self.public_id
+    }
+
+    /// Returns the 16-byte AES-encrypted token.
+    pub const fn encrypted_token(&self) -> [u8; 16] {
+        self.encrypted_token
+    }
+}
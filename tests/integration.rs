@@ -3,7 +3,7 @@ use base64::{
     Engine as _,
 };
 use bech32::{Bech32, Hrp};
-use const_decoder::{decode, Decoder, Pem};
+use const_decoder::{decode, Base64Bytes, Decoder, DigestChecked, HexBytes, Pem};
 use rand::{thread_rng, RngCore};
 
 #[test]
@@ -15,6 +15,43 @@ fn reading_from_file_works() {
     assert_eq!(CERT, parsed.contents());
 }
 
+#[test]
+fn checking_digest_of_file() {
+    const DIGEST: [u8; 32] = decode!(
+        Decoder::Hex,
+        b"7e9e0479cbc78088503b8fbdd08e1de2198f4c89b31361f29acbca3094ac10b8",
+    );
+    const RAW_INPUT: &[u8] = &decode!(
+        DigestChecked::new(DIGEST),
+        include_bytes!("certificate.crt"),
+    );
+    const CERT: &[u8] = &decode!(Pem, RAW_INPUT);
+
+    assert_eq!(RAW_INPUT, include_bytes!("certificate.crt"));
+    let parsed = pem::parse(RAW_INPUT).unwrap();
+    assert_eq!(CERT, parsed.contents());
+}
+
+#[test]
+fn reading_from_file_as_string_works() {
+    const CERT: &[u8] = &decode!(Pem, include_str!("certificate.crt").as_bytes());
+
+    let parsed = pem::parse(include_bytes!("certificate.crt")).unwrap();
+    assert_eq!(CERT, parsed.contents());
+}
+
+#[test]
+fn decoding_env_vars() {
+    // Cargo sets `CARGO_PKG_NAME` to `const-decoder2`.
+    const NAME: &[u8] =
+        &const_decoder::decode_env!(Decoder::Base64, "CARGO_PKG_NAME", skip = custom(b"-2"));
+    const NAME_ARRAY: [u8; 9] =
+        const_decoder::decode_env!(Decoder::Base64.skip_chars(b"-2"), "CARGO_PKG_NAME");
+
+    assert_eq!(NAME, Decoder::Base64.decode::<9>(b"constdecoder"));
+    assert_eq!(NAME_ARRAY, NAME);
+}
+
 fn fuzz_hex_decoder<const N: usize>(samples: usize) {
     const CUSTOM_HEX: Decoder = Decoder::custom("0123456789abcdef");
 
@@ -61,6 +98,72 @@ fn fuzz_base64_decoder<const N: usize>(samples: usize) {
     }
 }
 
+fn fuzz_encoders<const N: usize, const BASE64_LEN: usize, const HEX_LEN: usize>(samples: usize) {
+    let mut rng = thread_rng();
+    for _ in 0..samples {
+        let mut bytes = [0_u8; N];
+        rng.fill_bytes(&mut bytes);
+
+        let encoded = Decoder::Base64.encode::<BASE64_LEN>(&bytes);
+        assert_eq!(encoded, STANDARD.encode(bytes).as_bytes());
+        let encoded_url = Decoder::Base64Url.encode::<BASE64_LEN>(&bytes);
+        assert_eq!(encoded_url, URL_SAFE.encode(bytes).as_bytes());
+        let encoded_hex = Decoder::Hex.encode::<HEX_LEN>(&bytes);
+        assert_eq!(encoded_hex, hex::encode(bytes).as_bytes());
+    }
+}
+
+#[test]
+fn encoders_mini_fuzz() {
+    fuzz_encoders::<1, 4, 2>(50);
+    fuzz_encoders::<8, 12, 16>(10_000);
+    fuzz_encoders::<16, 24, 32>(10_000);
+    fuzz_encoders::<24, 32, 48>(10_000);
+    fuzz_encoders::<64, 88, 128>(10_000);
+}
+
+fn fuzz_bytes_newtypes<const N: usize>(samples: usize) {
+    let mut rng = thread_rng();
+    for _ in 0..samples {
+        let mut bytes = [0_u8; N];
+        rng.fill_bytes(&mut bytes);
+
+        let hex = HexBytes(bytes).to_string();
+        assert_eq!(hex, hex::encode(bytes));
+        assert_eq!(hex.parse::<HexBytes<N>>().unwrap().0, bytes);
+        let base64 = Base64Bytes(bytes).to_string();
+        assert_eq!(base64, STANDARD.encode(bytes));
+        assert_eq!(
+            Base64Bytes::<N>::try_from(base64.as_str()).unwrap().0,
+            bytes
+        );
+    }
+}
+
+#[test]
+fn bytes_newtypes_mini_fuzz() {
+    fuzz_bytes_newtypes::<1>(50);
+    fuzz_bytes_newtypes::<2>(50);
+    fuzz_bytes_newtypes::<16>(1_000);
+    fuzz_bytes_newtypes::<32>(1_000);
+    fuzz_bytes_newtypes::<65>(1_000);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserializing_bytes_newtypes() {
+    use serde::{
+        de::{value::Error, IntoDeserializer},
+        Deserialize,
+    };
+
+    let input = IntoDeserializer::<Error>::into_deserializer("c0ff");
+    assert_eq!(HexBytes::<2>::deserialize(input).unwrap().0, [0xc0, 0xff]);
+    let input = IntoDeserializer::<Error>::into_deserializer("VGVzdA?");
+    let err = Base64Bytes::<4>::deserialize(input).unwrap_err();
+    assert_eq!(err.to_string(), "invalid symbol '?' at position 6");
+}
+
 #[test]
 fn base64_decoder_mini_fuzz() {
     fuzz_base64_decoder::<1>(50);
@@ -121,3 +224,227 @@ fn bech32_decoder_mini_fuzz() {
     fuzz_bech32_decoder::<24>(10_000);
     fuzz_bech32_decoder::<64>(10_000);
 }
+
+#[test]
+fn decoding_without_alloc_mini_fuzz() {
+    let mut rng = thread_rng();
+    for len in 0..=64 {
+        let mut bytes = vec![0_u8; len];
+        rng.fill_bytes(&mut bytes);
+
+        #[cfg(feature = "heapless")]
+        {
+            let encoded = hex::encode(&bytes);
+            let buf = Decoder::Hex
+                .try_decode_to_heapless::<64>(encoded.as_bytes())
+                .unwrap();
+            assert_eq!(*buf, bytes);
+            let encoded = STANDARD.encode(&bytes);
+            let buf = Decoder::Base64
+                .skip_whitespace()
+                .try_decode_to_heapless::<64>(encoded.as_bytes())
+                .unwrap();
+            assert_eq!(*buf, bytes);
+            if len > 0 {
+                let err = Decoder::Base64
+                    .try_decode_to_heapless::<0>(encoded.as_bytes())
+                    .unwrap_err();
+                assert_eq!(err.kind(), const_decoder::DecodeErrorKind::Overflow);
+            }
+        }
+
+        let encoded = STANDARD.encode(&bytes);
+
+        let iter = Decoder::Base64.decode_iter(encoded.as_bytes());
+        assert_eq!(iter.collect::<Result<Vec<_>, _>>().unwrap(), bytes);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decoding_to_vec_mini_fuzz() {
+    let mut rng = thread_rng();
+    for len in 0..256 {
+        let mut bytes = vec![0_u8; len];
+        rng.fill_bytes(&mut bytes);
+
+        let encoded = hex::encode(&bytes);
+        assert_eq!(Decoder::Hex.decode_to_vec(encoded.as_bytes()), bytes);
+        let encoded = STANDARD.encode(&bytes);
+        assert_eq!(Decoder::Base64.decode_to_vec(encoded.as_bytes()), bytes);
+        let encoded = URL_SAFE_NO_PAD.encode(&bytes);
+        assert_eq!(Decoder::Base64Url.decode_to_vec(encoded.as_bytes()), bytes);
+    }
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn decoding_to_bytes_mini_fuzz() {
+    use bytes::{Buf, BytesMut};
+    use rand::Rng;
+
+    let mut rng = thread_rng();
+    for len in 0..256 {
+        let mut bytes = vec![0_u8; len];
+        rng.fill_bytes(&mut bytes);
+
+        let encoded = STANDARD.encode(&bytes);
+        let decoded = Decoder::Base64.decode_to_bytes(encoded.as_bytes()).unwrap();
+        assert_eq!(decoded, bytes);
+
+        // Non-contiguous input
+        let (head, tail) = encoded
+            .as_bytes()
+            .split_at(rng.gen_range(0..=encoded.len()));
+        let decoded = Decoder::Base64.decode_to_bytes(head.chain(tail)).unwrap();
+        assert_eq!(decoded, bytes);
+
+        let encoded = hex::encode(&bytes);
+        let mut output = BytesMut::from(&b"prefix"[..]);
+        Decoder::Hex
+            .skip_whitespace()
+            .decode_into_bytes_mut(encoded.as_bytes(), &mut output)
+            .unwrap();
+        assert_eq!(output[..6], *b"prefix");
+        assert_eq!(output[6..], bytes);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decoding_pem_to_vec() {
+    const RAW_INPUT: &[u8] = include_bytes!("certificate.crt");
+    const CERT: &[u8] = &decode!(Pem, RAW_INPUT);
+
+    assert_eq!(Pem::decode_to_vec(RAW_INPUT), CERT);
+    assert_eq!(Pem.reject_trailing_data().decode_to_vec(RAW_INPUT), CERT);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn generating_consts_for_build_scripts() {
+    use const_decoder::build::generate_const;
+
+    let bytes: Vec<u8> = (0..20).collect();
+    let encoded = STANDARD.encode(&bytes);
+    let bytes = Decoder::Base64.decode_to_vec(encoded.as_bytes());
+    let code = generate_const("_ASSET_1", "/out/asset.bin", bytes.len()).unwrap();
+    assert_eq!(
+        code,
+        "pub static _ASSET_1: &[u8; 20] = include_bytes!(\"/out/asset.bin\");\n"
+    );
+    assert_eq!(
+        generate_const("EMPTY", r"C:\out\empty.bin", 0).unwrap(),
+        "pub static EMPTY: &[u8; 0] = include_bytes!(\"C:\\\\out\\\\empty.bin\");\n"
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn generating_const_with_invalid_name() {
+    use const_decoder::build::generate_const;
+
+    // The last name contains a Cyrillic letter.
+    for name in ["", "1ASSET", "_", "fn", "Self", "ASSET-1", "ASSЕT"] {
+        let err = generate_const(name, "/out/asset.bin", 0).unwrap_err();
+        assert_eq!(err.name(), name);
+        assert_eq!(
+            err.to_string(),
+            format!("`{name}` is not a valid Rust identifier")
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decoding_from_reader() {
+    use std::io;
+
+    let reader = io::Cursor::new("c0ff ee\n00");
+    let decoded = Decoder::Hex
+        .skip_whitespace()
+        .decode_reader(reader)
+        .unwrap();
+    assert_eq!(decoded, [0xc0, 0xff, 0xee, 0]);
+
+    let reader = io::Cursor::new("c0ff ee\n00");
+    let err = Decoder::Hex.decode_reader(reader).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    let err = err.into_inner().unwrap();
+    let err = err.downcast_ref::<const_decoder::DecodeError>().unwrap();
+    assert_eq!(
+        err.kind(),
+        const_decoder::DecodeErrorKind::InvalidSymbol { symbol: b' ' }
+    );
+    assert_eq!(err.position(), 4);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn decoding_to_zeroizing_mini_fuzz() {
+    let mut rng = thread_rng();
+    for len in 0..256 {
+        let mut bytes = vec![0_u8; len];
+        rng.fill_bytes(&mut bytes);
+
+        let encoded = hex::encode(&bytes);
+        let decoded = Decoder::Hex.decode_to_zeroizing(encoded.as_bytes());
+        assert_eq!(*decoded, bytes);
+        let encoded = STANDARD.encode(&bytes);
+        let decoded = Decoder::Base64.try_decode_to_zeroizing(encoded.as_bytes());
+        assert_eq!(*decoded.unwrap(), bytes);
+    }
+
+    const RAW_INPUT: &[u8] = include_bytes!("certificate.crt");
+    const CERT: &[u8] = &decode!(Pem, RAW_INPUT);
+    assert_eq!(*Pem::decode_to_zeroizing(RAW_INPUT), *CERT);
+    assert_eq!(*Pem::try_decode_to_zeroizing(RAW_INPUT).unwrap(), *CERT);
+    let err = Pem
+        .skip_preamble()
+        .try_decode_to_zeroizing(&RAW_INPUT[1..])
+        .unwrap_err();
+    assert_eq!(err.kind(), const_decoder::DecodeErrorKind::MissingHeader);
+
+    #[cfg(feature = "std")]
+    {
+        // Large enough for the input buffer to be reallocated multiple times.
+        let mut bytes = vec![0_u8; 100_000];
+        rng.fill_bytes(&mut bytes);
+        let encoded = STANDARD.encode(&bytes);
+        let decoded = Decoder::Base64.decode_reader(encoded.as_bytes()).unwrap();
+        assert_eq!(decoded, bytes);
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn decoding_in_parallel() {
+    let mut bytes = vec![0_u8; 1 << 20];
+    thread_rng().fill_bytes(&mut bytes);
+
+    let encoded = hex::encode(&bytes);
+    assert_eq!(
+        Decoder::Hex
+            .try_decode_parallel(encoded.as_bytes())
+            .unwrap(),
+        bytes
+    );
+    let encoded = STANDARD.encode(&bytes[1..]);
+    let decoded = Decoder::Base64.try_decode_parallel(encoded.as_bytes());
+    assert_eq!(decoded.unwrap(), bytes[1..]);
+    let encoded = URL_SAFE.encode(&bytes[2..]);
+    let decoded = Decoder::Base64Url.try_decode_parallel(encoded.as_bytes());
+    assert_eq!(decoded.unwrap(), bytes[2..]);
+
+    let mut encoded = STANDARD.encode(&bytes).into_bytes();
+    encoded[1_000_001] = b'?';
+    let err = Decoder::Base64.try_decode_parallel(&encoded).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        const_decoder::DecodeErrorKind::InvalidSymbol { symbol: b'?' }
+    );
+    assert_eq!(err.position(), 1_000_001);
+    encoded[100] = b'=';
+    let err = Decoder::Base64.try_decode_parallel(&encoded).unwrap_err();
+    assert_eq!(err.position(), 1_000_001);
+}